@@ -0,0 +1,349 @@
+use async_trait::async_trait;
+use scylla::authentication::{AuthError, AuthenticatorProvider, AuthenticatorSession};
+
+use crate::ffi::{BoxFFI, BridgedBorrowedExclusivePtr, FFI, FFIByteSlice, FFIStr, FromBox};
+use crate::pre_serialized_values::csharp_memory::{CsharpSerializedValue, CsharpValuePtr};
+use crate::{CSharpStr, FfiPtr};
+
+/// Opaque type representing a C# `IAuthProvider`.
+enum AuthProvider {}
+
+/// A pointer to a GCHandle referencing a C# `IAuthProvider`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct AuthProviderPtr(FfiPtr<'static, AuthProvider>);
+
+// SAFETY: The pointer is an opaque GCHandle, which may be used from any thread.
+unsafe impl Send for AuthProviderPtr {}
+unsafe impl Sync for AuthProviderPtr {}
+
+/// Opaque type representing a C# `IAuthenticator`.
+enum Authenticator {}
+
+/// A pointer to a GCHandle referencing a C# `IAuthenticator`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct AuthenticatorPtr(FfiPtr<'static, Authenticator>);
+
+// SAFETY: The pointer is an opaque GCHandle, which may be used from any thread.
+unsafe impl Send for AuthenticatorPtr {}
+unsafe impl Sync for AuthenticatorPtr {}
+
+/// Response of a single step of the C# SASL exchange.
+///
+/// Rust passes it to C# callbacks, and C# fills it using `auth_response_set_token`
+/// or `auth_response_set_error` before returning.
+/// If neither is called, the response is an empty (null) token.
+#[derive(Debug, Default)]
+pub struct BridgedAuthResponse {
+    token: Option<Vec<u8>>,
+    error: Option<AuthError>,
+}
+
+impl FFI for BridgedAuthResponse {
+    type Origin = FromBox;
+}
+
+impl BridgedAuthResponse {
+    /// Calls `fill` with a pointer to a fresh response and returns what C# has written into it.
+    fn collect(
+        fill: impl FnOnce(BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>),
+    ) -> Result<Option<Vec<u8>>, AuthError> {
+        let mut response_ptr = BoxFFI::into_ptr(Box::new(BridgedAuthResponse::default()));
+        fill(response_ptr.borrow_mut());
+        let response = BoxFFI::from_ptr(response_ptr).unwrap();
+        match response.error {
+            Some(error) => Err(error),
+            None => Ok(response.token),
+        }
+    }
+}
+
+/// Function pointer type to create a C# `IAuthenticator` for a new connection
+/// and write its initial response into `response`.
+/// On failure, C# sets an error on `response` and returns a null authenticator.
+type StartAuthSession = unsafe extern "C" fn(
+    provider: AuthProviderPtr,
+    authenticator_name: FFIStr<'_>,
+    response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+) -> AuthenticatorPtr;
+
+/// Function pointer type to evaluate a SASL challenge sent by the server
+/// and write the answer into `response`.
+type EvaluateChallenge = unsafe extern "C" fn(
+    authenticator: AuthenticatorPtr,
+    challenge: FFIByteSlice<'_>,
+    response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+);
+
+/// Function pointer type to free a GCHandle to a C# `IAuthenticator`.
+type FreeAuthenticator = unsafe extern "C" fn(authenticator: AuthenticatorPtr);
+
+/// Function pointer type to free a GCHandle to a C# `IAuthProvider`.
+type FreeAuthProvider = unsafe extern "C" fn(provider: AuthProviderPtr);
+
+/// Callbacks allowing a C# `IAuthProvider` to answer SASL challenges.
+///
+/// Ownership of the `provider` handle is transferred to Rust, which frees it
+/// using `free_provider` once the session no longer needs it.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BridgedAuthenticatorCallbacks {
+    provider: AuthProviderPtr,
+    start_session: StartAuthSession,
+    evaluate_challenge: EvaluateChallenge,
+    free_authenticator: FreeAuthenticator,
+    free_provider: FreeAuthProvider,
+}
+
+/// `AuthenticatorProvider` forwarding authentication to a C# `IAuthProvider`.
+#[derive(Debug)]
+pub(crate) struct CSharpAuthenticatorProvider {
+    callbacks: BridgedAuthenticatorCallbacks,
+}
+
+impl CSharpAuthenticatorProvider {
+    pub(crate) fn new(callbacks: BridgedAuthenticatorCallbacks) -> Self {
+        Self { callbacks }
+    }
+}
+
+impl Drop for CSharpAuthenticatorProvider {
+    fn drop(&mut self) {
+        unsafe { (self.callbacks.free_provider)(self.callbacks.provider) };
+    }
+}
+
+#[async_trait]
+impl AuthenticatorProvider for CSharpAuthenticatorProvider {
+    async fn start_authentication_session(
+        &self,
+        authenticator_name: &str,
+    ) -> Result<(Option<Vec<u8>>, Box<dyn AuthenticatorSession>), AuthError> {
+        tracing::trace!(
+            "[FFI] Starting C# authentication session with {}",
+            authenticator_name
+        );
+
+        let mut authenticator = None;
+        let initial_response = BridgedAuthResponse::collect(|response| {
+            authenticator = Some(unsafe {
+                (self.callbacks.start_session)(
+                    self.callbacks.provider,
+                    FFIStr::new(authenticator_name),
+                    response,
+                )
+            });
+        });
+        let authenticator = authenticator.unwrap();
+
+        let session = CSharpAuthenticatorSession {
+            authenticator,
+            evaluate_challenge: self.callbacks.evaluate_challenge,
+            free_authenticator: self.callbacks.free_authenticator,
+        };
+
+        // On error, the session is dropped here, freeing the authenticator handle (if any).
+        let initial_response = initial_response?;
+        if session.authenticator.0.ptr.is_none() {
+            return Err(format!(
+                "C# auth provider returned no authenticator for {authenticator_name}"
+            ));
+        }
+
+        Ok((initial_response, Box::new(session)))
+    }
+}
+
+/// `AuthenticatorSession` forwarding SASL challenges to a C# `IAuthenticator`.
+struct CSharpAuthenticatorSession {
+    authenticator: AuthenticatorPtr,
+    evaluate_challenge: EvaluateChallenge,
+    free_authenticator: FreeAuthenticator,
+}
+
+impl Drop for CSharpAuthenticatorSession {
+    fn drop(&mut self) {
+        if self.authenticator.0.ptr.is_some() {
+            unsafe { (self.free_authenticator)(self.authenticator) };
+        }
+    }
+}
+
+#[async_trait]
+impl AuthenticatorSession for CSharpAuthenticatorSession {
+    async fn evaluate_challenge(
+        &mut self,
+        token: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, AuthError> {
+        let challenge = FFIByteSlice::new(token.unwrap_or_default());
+        BridgedAuthResponse::collect(|response| unsafe {
+            (self.evaluate_challenge)(self.authenticator, challenge, response)
+        })
+    }
+
+    async fn success(&mut self, _token: Option<&[u8]>) -> Result<(), AuthError> {
+        // C# `IAuthenticator` has no notion of a successful authentication.
+        Ok(())
+    }
+}
+
+/// Sets the token of the response. The data is copied immediately.
+///
+/// # Safety
+/// `token_ptr` and the data it points to must remain valid for the duration of this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn auth_response_set_token(
+    response_ptr: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    token_ptr: CsharpValuePtr,
+    token_len: usize,
+) {
+    let Some(response) = BoxFFI::as_mut_ref(response_ptr) else {
+        panic!("Null pointer passed to auth_response_set_token");
+    };
+    let token = CsharpSerializedValue::new(token_ptr, token_len);
+    response.token = Some(unsafe { token.as_slice() }.to_vec());
+}
+
+/// Marks the response as failed with the given message.
+#[unsafe(no_mangle)]
+pub extern "C" fn auth_response_set_error(
+    response_ptr: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    message: CSharpStr<'_>,
+) {
+    let Some(response) = BoxFFI::as_mut_ref(response_ptr) else {
+        panic!("Null pointer passed to auth_response_set_error");
+    };
+    let message = message.as_cstr().unwrap().to_string_lossy().into_owned();
+    response.error = Some(message);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    // Counts freed authenticator handles across all tests, so only checked as a difference.
+    static FREED_AUTHENTICATORS: AtomicUsize = AtomicUsize::new(0);
+
+    fn authenticator_ptr() -> AuthenticatorPtr {
+        // Any non-null value works, as the handle is only passed back to the callbacks below.
+        static AUTHENTICATOR: u8 = 0;
+        AuthenticatorPtr(FfiPtr {
+            ptr: std::ptr::NonNull::new((&raw const AUTHENTICATOR).cast_mut().cast()),
+            _phantom: std::marker::PhantomData,
+        })
+    }
+
+    unsafe extern "C" fn rejecting_start_session(
+        _provider: AuthProviderPtr,
+        _authenticator_name: FFIStr<'_>,
+        response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    ) -> AuthenticatorPtr {
+        auth_response_set_error(response, CSharpStr::from_cstr(c"Bad credentials"));
+        AuthenticatorPtr(FfiPtr {
+            ptr: None,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+
+    unsafe extern "C" fn silent_start_session(
+        _provider: AuthProviderPtr,
+        _authenticator_name: FFIStr<'_>,
+        _response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    ) -> AuthenticatorPtr {
+        AuthenticatorPtr(FfiPtr {
+            ptr: None,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+
+    unsafe extern "C" fn accepting_start_session(
+        _provider: AuthProviderPtr,
+        _authenticator_name: FFIStr<'_>,
+        response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    ) -> AuthenticatorPtr {
+        let token = b"\0user\0pass";
+        unsafe {
+            auth_response_set_token(response, CsharpValuePtr::from_slice(token), token.len())
+        };
+        authenticator_ptr()
+    }
+
+    unsafe extern "C" fn rejecting_evaluate_challenge(
+        _authenticator: AuthenticatorPtr,
+        _challenge: FFIByteSlice<'_>,
+        response: BridgedBorrowedExclusivePtr<'_, BridgedAuthResponse>,
+    ) {
+        auth_response_set_error(response, CSharpStr::from_cstr(c"Challenge rejected"));
+    }
+
+    unsafe extern "C" fn free_authenticator(_authenticator: AuthenticatorPtr) {
+        FREED_AUTHENTICATORS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe extern "C" fn free_provider(_provider: AuthProviderPtr) {}
+
+    fn provider(start_session: StartAuthSession) -> CSharpAuthenticatorProvider {
+        CSharpAuthenticatorProvider::new(BridgedAuthenticatorCallbacks {
+            provider: AuthProviderPtr(FfiPtr {
+                ptr: None,
+                _phantom: std::marker::PhantomData,
+            }),
+            start_session,
+            evaluate_challenge: rejecting_evaluate_challenge,
+            free_authenticator,
+            free_provider,
+        })
+    }
+
+    #[tokio::test]
+    async fn rejecting_authenticator_fails_authentication() {
+        let provider = provider(rejecting_start_session);
+        let Err(error) = provider
+            .start_authentication_session("PasswordAuthenticator")
+            .await
+        else {
+            panic!("Authentication should have failed");
+        };
+        assert_eq!(error, "Bad credentials");
+    }
+
+    #[tokio::test]
+    async fn missing_authenticator_fails_authentication() {
+        let provider = provider(silent_start_session);
+        let Err(error) = provider
+            .start_authentication_session("PasswordAuthenticator")
+            .await
+        else {
+            panic!("Authentication should have failed");
+        };
+        assert!(error.contains("no authenticator"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn rejected_challenge_fails_and_frees_authenticator() {
+        let provider = provider(accepting_start_session);
+        let freed_before = FREED_AUTHENTICATORS.load(Ordering::SeqCst);
+
+        let (initial_response, mut session) = provider
+            .start_authentication_session("PasswordAuthenticator")
+            .await
+            .unwrap();
+        assert_eq!(initial_response.as_deref(), Some(&b"\0user\0pass"[..]));
+
+        let error = session
+            .evaluate_challenge(Some(b"challenge"))
+            .await
+            .unwrap_err();
+        assert_eq!(error, "Challenge rejected");
+
+        drop(session);
+        assert_eq!(
+            FREED_AUTHENTICATORS.load(Ordering::SeqCst),
+            freed_before + 1
+        );
+    }
+}
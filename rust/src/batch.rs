@@ -0,0 +1,124 @@
+use scylla::statement::batch::{Batch, BatchType};
+use scylla::statement::unprepared::Statement;
+use thiserror::Error;
+
+use crate::CSharpStr;
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FfiException};
+use crate::ffi::{
+    ArcFFI, BoxFFI, BridgedBorrowedExclusivePtr, BridgedBorrowedSharedPtr,
+    BridgedOwnedExclusivePtr, FFI, FromBox,
+};
+use crate::pre_serialized_values::pre_serialized_values::PreSerializedValues;
+use crate::prepared_statement::BridgedPreparedStatement;
+use crate::task::ExceptionConstructors;
+
+/// Batch statement gathered from C# before it is executed.
+///
+/// C# creates the batch with `batch_new`, adds statements with their values
+/// and then passes its ownership to `session_batch`.
+pub struct BridgedBatch {
+    batch: Batch,
+    // Values of the statements, in the same order as the statements in `batch`.
+    values: Vec<PreSerializedValues>,
+}
+
+impl FFI for BridgedBatch {
+    type Origin = FromBox;
+}
+
+impl BridgedBatch {
+    /// Consumes the batch, returning the statements and their values.
+    pub(crate) fn into_parts(self) -> (Batch, Vec<PreSerializedValues>) {
+        (self.batch, self.values)
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Unknown batch type {0}")]
+pub(crate) struct UnknownBatchTypeError(u8);
+
+impl ErrorToException for UnknownBatchTypeError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+/// Creates an empty batch and writes it to `out_batch`.
+/// Types: 0 - logged, 1 - unlogged, 2 - counter, as in the C# `BatchType`.
+///
+/// Returns an exception for an unknown type, in which case nothing is written.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_new(
+    batch_type: u8,
+    out_batch: *mut BridgedOwnedExclusivePtr<BridgedBatch>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    if out_batch.is_null() {
+        panic!("Null pointer passed to batch_new");
+    }
+    let batch_type = match batch_type {
+        0 => BatchType::Logged,
+        1 => BatchType::Unlogged,
+        2 => BatchType::Counter,
+        _ => return FfiException::from_error(UnknownBatchTypeError(batch_type), constructors),
+    };
+    let batch = BoxFFI::into_ptr(Box::new(BridgedBatch {
+        batch: Batch::new(batch_type),
+        values: Vec::new(),
+    }));
+    unsafe { out_batch.write(batch) };
+    FfiException::ok()
+}
+
+/// Frees the batch if it was not consumed by `session_batch`.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_free(batch_ptr: BridgedOwnedExclusivePtr<BridgedBatch>) {
+    BoxFFI::free(batch_ptr);
+    tracing::trace!("[FFI] Batch freed");
+}
+
+/// Adds an unprepared statement with its values to the batch.
+///
+/// Note: this transfers ownership of `values_ptr`, so the C# side must not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_add_statement(
+    batch_ptr: BridgedBorrowedExclusivePtr<'_, BridgedBatch>,
+    statement: CSharpStr<'_>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+) {
+    // Take ownership of the values first, so that they are not leaked if we panic below.
+    let values = PreSerializedValues::from_nullable_ptr(values_ptr);
+    let Some(bridged_batch) = BoxFFI::as_mut_ref(batch_ptr) else {
+        panic!("Null pointer passed to batch_add_statement");
+    };
+    let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
+
+    bridged_batch
+        .batch
+        .append_statement(Statement::new(statement));
+    bridged_batch.values.push(values);
+}
+
+/// Adds a prepared statement with its values to the batch.
+///
+/// Note: this transfers ownership of `values_ptr`, so the C# side must not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_add_prepared(
+    batch_ptr: BridgedBorrowedExclusivePtr<'_, BridgedBatch>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+) {
+    // Take ownership of the values first, so that they are not leaked if we panic below.
+    let values = PreSerializedValues::from_nullable_ptr(values_ptr);
+    let Some(bridged_batch) = BoxFFI::as_mut_ref(batch_ptr) else {
+        panic!("Null pointer passed to batch_add_prepared");
+    };
+    let Some(bridged_prepared) = ArcFFI::as_ref(prepared_statement_ptr) else {
+        panic!("Null pointer passed to batch_add_prepared");
+    };
+
+    bridged_batch
+        .batch
+        .append_statement(bridged_prepared.inner.clone());
+    bridged_batch.values.push(values);
+}
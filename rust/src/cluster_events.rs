@@ -0,0 +1,482 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use futures::future::AbortHandle;
+use scylla::cluster::{ClusterState, Node};
+use scylla::frame::response::event::{Event, SchemaChangeEvent, SchemaChangeType};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::FfiPtr;
+use crate::cluster_state::{BridgedNode, ReleaseVersions};
+use crate::ffi::{BoxFFI, BridgedOwnedExclusivePtr, FFI, FFIStr, FromBox};
+use crate::session::BridgedSession;
+
+/// Delays after a node event at which the connection pools are checked again,
+/// as they connect to or disconnect from the node in the background.
+const POOL_CHECK_DELAYS: [Duration; 4] = [
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(15),
+];
+
+/// Opaque type representing a C# listener of cluster events.
+enum ClusterEventListener {}
+
+/// A pointer to a GCHandle referencing a C# listener of cluster events.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct ClusterEventListenerPtr(FfiPtr<'static, ClusterEventListener>);
+
+// SAFETY: The pointer is an opaque GCHandle, which may be used from any thread.
+unsafe impl Send for ClusterEventListenerPtr {}
+unsafe impl Sync for ClusterEventListenerPtr {}
+
+/// Function pointer type to notify C# about a change of a node.
+/// The strings of `node` are only valid for the duration of the call.
+type NodeEventCallback =
+    unsafe extern "C" fn(listener: ClusterEventListenerPtr, node: BridgedNode<'_>);
+
+/// Function pointer type to notify C# about a schema change.
+///
+/// `change` is one of: 0 - created, 1 - dropped, 2 - updated,
+/// matching the C# `SchemaChangedEventArgs.Kind` values.
+/// `target` is one of: 0 - keyspace, 1 - table, 2 - user defined type, 3 - materialized view.
+/// `name` is empty for keyspace changes.
+/// The strings are only valid for the duration of the call.
+type SchemaChangeCallback = unsafe extern "C" fn(
+    listener: ClusterEventListenerPtr,
+    change: u8,
+    target: u8,
+    keyspace: FFIStr<'_>,
+    name: FFIStr<'_>,
+);
+
+/// Function pointer type to free a GCHandle to a C# listener of cluster events.
+type FreeClusterEventListener = unsafe extern "C" fn(listener: ClusterEventListenerPtr);
+
+/// Callbacks notifying a C# listener about topology, node state and schema changes.
+///
+/// Ownership of the `listener` handle is transferred to Rust, which frees it
+/// using `free_listener` once the subscription ends.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BridgedClusterEventCallbacks {
+    listener: ClusterEventListenerPtr,
+    node_added: NodeEventCallback,
+    node_removed: NodeEventCallback,
+    node_up: NodeEventCallback,
+    node_down: NodeEventCallback,
+    schema_changed: SchemaChangeCallback,
+    free_listener: FreeClusterEventListener,
+}
+
+/// Owner of the C# listener, which frees it when the subscription ends.
+#[derive(Debug)]
+pub(crate) struct CSharpClusterEventListener {
+    callbacks: BridgedClusterEventCallbacks,
+}
+
+impl Drop for CSharpClusterEventListener {
+    fn drop(&mut self) {
+        unsafe { (self.callbacks.free_listener)(self.callbacks.listener) };
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SchemaChange {
+    Created = 0,
+    Dropped = 1,
+    Updated = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SchemaTarget {
+    Keyspace = 0,
+    Table = 1,
+    UserDefinedType = 2,
+    MaterializedView = 3,
+}
+
+impl CSharpClusterEventListener {
+    /// Takes ownership of the listener handle, which is freed when the returned value is dropped.
+    pub(crate) fn new(callbacks: BridgedClusterEventCallbacks) -> Self {
+        Self { callbacks }
+    }
+
+    fn node_event(&self, callback: NodeEventCallback, node: &Node, versions: &ReleaseVersions) {
+        let address = node.address.ip().to_string();
+        let version = versions.get(&node.host_id).unwrap_or_default();
+        unsafe {
+            callback(
+                self.callbacks.listener,
+                BridgedNode::new(node, &address, &version),
+            )
+        };
+    }
+
+    fn schema_event(&self, change: SchemaChange, target: SchemaTarget, keyspace: &str, name: &str) {
+        tracing::debug!(
+            "[FFI] Schema change: {:?} {:?} {}.{}",
+            change,
+            target,
+            keyspace,
+            name
+        );
+        unsafe {
+            (self.callbacks.schema_changed)(
+                self.callbacks.listener,
+                change as u8,
+                target as u8,
+                FFIStr::new(keyspace),
+                FFIStr::new(name),
+            )
+        };
+    }
+
+    fn report_node_changes(
+        &self,
+        previous: &ClusterSnapshot,
+        current: &ClusterSnapshot,
+        versions: &ReleaseVersions,
+    ) {
+        for node in current.state.get_nodes_info() {
+            let is_up = current.nodes_up[&node.host_id];
+            match previous.nodes_up.get(&node.host_id) {
+                None => self.node_event(self.callbacks.node_added, node, versions),
+                Some(&was_up) if was_up != is_up => {
+                    tracing::debug!(
+                        "[FFI] Node {} is now {}",
+                        node.address,
+                        if is_up { "up" } else { "down" }
+                    );
+                    let callback = if is_up {
+                        self.callbacks.node_up
+                    } else {
+                        self.callbacks.node_down
+                    };
+                    self.node_event(callback, node, versions);
+                }
+                Some(_) => {}
+            }
+        }
+        for node in previous.state.get_nodes_info() {
+            if !current.nodes_up.contains_key(&node.host_id) {
+                self.node_event(self.callbacks.node_removed, node, versions);
+            }
+        }
+    }
+
+    fn report_schema_changes(&self, previous: &ClusterState, current: &ClusterState) {
+        for (keyspace_name, keyspace) in current.keyspaces_iter() {
+            let Some(previous_keyspace) = previous.get_keyspace(keyspace_name) else {
+                self.schema_event(
+                    SchemaChange::Created,
+                    SchemaTarget::Keyspace,
+                    keyspace_name,
+                    "",
+                );
+                continue;
+            };
+            if previous_keyspace.strategy != keyspace.strategy {
+                self.schema_event(
+                    SchemaChange::Updated,
+                    SchemaTarget::Keyspace,
+                    keyspace_name,
+                    "",
+                );
+            }
+            let mut report =
+                |change, target, name: &str| self.schema_event(change, target, keyspace_name, name);
+            diff_entries(
+                &previous_keyspace.tables,
+                &keyspace.tables,
+                SchemaTarget::Table,
+                &mut report,
+            );
+            diff_entries(
+                &previous_keyspace.views,
+                &keyspace.views,
+                SchemaTarget::MaterializedView,
+                &mut report,
+            );
+            diff_entries(
+                &previous_keyspace.user_defined_types,
+                &keyspace.user_defined_types,
+                SchemaTarget::UserDefinedType,
+                &mut report,
+            );
+        }
+        // Entries of dropped keyspaces are not reported separately.
+        for (keyspace_name, _) in previous.keyspaces_iter() {
+            if current.get_keyspace(keyspace_name).is_none() {
+                self.schema_event(
+                    SchemaChange::Dropped,
+                    SchemaTarget::Keyspace,
+                    keyspace_name,
+                    "",
+                );
+            }
+        }
+    }
+}
+
+/// Reports entries of a keyspace which were created, dropped or changed between two snapshots.
+fn diff_entries<V: PartialEq>(
+    previous: &HashMap<String, V>,
+    current: &HashMap<String, V>,
+    target: SchemaTarget,
+    report: &mut impl FnMut(SchemaChange, SchemaTarget, &str),
+) {
+    for (name, entry) in current {
+        match previous.get(name) {
+            None => report(SchemaChange::Created, target, name),
+            Some(previous_entry) if previous_entry != entry => {
+                report(SchemaChange::Updated, target, name)
+            }
+            Some(_) => {}
+        }
+    }
+    for name in previous.keys() {
+        if !current.contains_key(name) {
+            report(SchemaChange::Dropped, target, name);
+        }
+    }
+}
+
+/// Cluster state together with the node states observed when it was taken.
+/// A node is up if the driver has an open connection to it, so node states are tracked
+/// separately, as they change without the cluster state being replaced.
+struct ClusterSnapshot {
+    state: Arc<ClusterState>,
+    nodes_up: HashMap<Uuid, bool>,
+}
+
+impl ClusterSnapshot {
+    fn new(state: Arc<ClusterState>) -> Self {
+        let nodes_up = state
+            .get_nodes_info()
+            .iter()
+            .map(|node| (node.host_id, node.is_connected()))
+            .collect();
+        ClusterSnapshot { state, nodes_up }
+    }
+
+    fn is_view(&self, keyspace: &str, name: &str) -> bool {
+        self.state
+            .get_keyspace(keyspace)
+            .is_some_and(|keyspace| keyspace.views.contains_key(name))
+    }
+}
+
+/// Reports the events received on the driver's control connection to the C# listener,
+/// until the session is shut down or freed.
+///
+/// The driver passes each event on (with the `unstable-csharp-rs` feature) once it has refreshed
+/// its cluster state after it, so the snapshot taken for an event already reflects it.
+/// Schema changes are reported as the server sends them. Node events only trigger a check
+/// of the cluster state, as nodes are reported added, removed, up or down as seen by the
+/// driver: a node is up when the driver's connection pool to it is connected.
+pub(crate) struct ClusterWatcher {
+    session: Weak<BridgedSession>,
+    listener: CSharpClusterEventListener,
+    events: broadcast::Receiver<Event>,
+    versions: Arc<ReleaseVersions>,
+    previous: ClusterSnapshot,
+}
+
+impl ClusterWatcher {
+    /// Starts watching from the current cluster state of the session, so that changes
+    /// already present when subscribing are not reported.
+    /// Returns None if the session has been shut down.
+    pub(crate) async fn new(
+        session: Weak<BridgedSession>,
+        listener: CSharpClusterEventListener,
+    ) -> Option<Self> {
+        let session_lock = session.upgrade()?;
+        let session_guard = session_lock.read().await;
+        // Subscribe before taking the snapshot, so that no change falls in between.
+        let events = session_guard.subscribe_server_events()?;
+        let previous = ClusterSnapshot::new(session_guard.cluster_state()?);
+        Some(ClusterWatcher {
+            events,
+            versions: Arc::clone(session_guard.release_versions()),
+            session,
+            listener,
+            previous,
+        })
+    }
+
+    pub(crate) async fn run(mut self) {
+        let _ = self.receive_events().await;
+        tracing::debug!("[FFI] Session shut down or freed, cluster events are no longer reported");
+    }
+
+    /// Reports the events of the driver. Returns only once the session is shut down or freed.
+    async fn receive_events(&mut self) -> Option<()> {
+        // Nothing to check until the first node event.
+        let mut pool_check_delays: std::slice::Iter<'static, Duration> = [].iter();
+        let mut next_pool_check: Option<Instant> = None;
+
+        loop {
+            let pool_check = async move {
+                match next_pool_check {
+                    Some(deadline) => tokio::time::sleep_until(deadline).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                event = self.events.recv() => match event {
+                    Ok(Event::SchemaChange(event)) => {
+                        if let Some((change, target, keyspace, name)) = schema_change(&event) {
+                            self.schema_changed(change, target, keyspace, name)?;
+                        }
+                    }
+                    Ok(Event::TopologyChange(_) | Event::StatusChange(_)) => {
+                        tracing::debug!("[FFI] Node event: {:?}", event);
+                        self.report_node_changes()?;
+                        pool_check_delays = POOL_CHECK_DELAYS.iter();
+                        next_pool_check = pool_check_delays.next().map(|delay| Instant::now() + *delay);
+                    }
+                    // The skipped events are lost, so report what changed meanwhile.
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::debug!("[FFI] Skipped {} cluster events", skipped);
+                        self.resync()?;
+                    }
+                    // The driver's session was dropped.
+                    Err(RecvError::Closed) => return None,
+                },
+                () = pool_check => {
+                    self.report_node_changes()?;
+                    next_pool_check = pool_check_delays.next().map(|delay| Instant::now() + *delay);
+                }
+            }
+        }
+    }
+
+    /// Takes a snapshot of the current cluster state.
+    /// Returns None if the session is shut down or freed.
+    fn take_snapshot(&self) -> Option<ClusterSnapshot> {
+        let session_lock = self.session.upgrade()?;
+        // The session is being shut down if the lock cannot be acquired right away.
+        let session_guard = session_lock.try_read().ok()?;
+        session_guard.cluster_state().map(ClusterSnapshot::new)
+    }
+
+    fn report_node_changes(&mut self) -> Option<()> {
+        let current = self.take_snapshot()?;
+        self.listener
+            .report_node_changes(&self.previous, &current, &self.versions);
+        self.previous = current;
+        Some(())
+    }
+
+    fn resync(&mut self) -> Option<()> {
+        let current = self.take_snapshot()?;
+        self.listener
+            .report_node_changes(&self.previous, &current, &self.versions);
+        if !Arc::ptr_eq(&self.previous.state, &current.state) {
+            self.listener
+                .report_schema_changes(&self.previous.state, &current.state);
+        }
+        self.previous = current;
+        Some(())
+    }
+
+    fn schema_changed(
+        &mut self,
+        change: SchemaChange,
+        mut target: SchemaTarget,
+        keyspace: &str,
+        name: &str,
+    ) -> Option<()> {
+        if target == SchemaTarget::Table {
+            // The server reports views as tables; the cluster state tells them apart.
+            let current = self.take_snapshot()?;
+            if self.previous.is_view(keyspace, name) || current.is_view(keyspace, name) {
+                target = SchemaTarget::MaterializedView;
+            }
+            self.listener
+                .report_node_changes(&self.previous, &current, &self.versions);
+            self.previous = current;
+        }
+        self.listener.schema_event(change, target, keyspace, name);
+        Some(())
+    }
+}
+
+/// Maps a schema change event onto the change reported to C#.
+/// Returns None for changes C# has no listeners for, i.e. of functions and aggregates.
+fn schema_change(event: &SchemaChangeEvent) -> Option<(SchemaChange, SchemaTarget, &str, &str)> {
+    let (change_type, target, keyspace, name) = match event {
+        SchemaChangeEvent::KeyspaceChange {
+            change_type,
+            keyspace_name,
+        } => (change_type, SchemaTarget::Keyspace, keyspace_name, ""),
+        SchemaChangeEvent::TableChange {
+            change_type,
+            keyspace_name,
+            object_name,
+        } => (
+            change_type,
+            SchemaTarget::Table,
+            keyspace_name,
+            object_name.as_str(),
+        ),
+        SchemaChangeEvent::TypeChange {
+            change_type,
+            keyspace_name,
+            type_name,
+        } => (
+            change_type,
+            SchemaTarget::UserDefinedType,
+            keyspace_name,
+            type_name.as_str(),
+        ),
+        _ => return None,
+    };
+    let change = match change_type {
+        SchemaChangeType::Created => SchemaChange::Created,
+        SchemaChangeType::Dropped => SchemaChange::Dropped,
+        SchemaChangeType::Updated => SchemaChange::Updated,
+        _ => return None,
+    };
+    Some((change, target, keyspace.as_str(), name))
+}
+
+/// Subscription to cluster events, created by `session_subscribe_cluster_events`.
+///
+/// Freeing it ends the subscription.
+#[derive(Debug)]
+pub struct BridgedClusterEventSubscription {
+    abort_handle: AbortHandle,
+}
+
+impl FFI for BridgedClusterEventSubscription {
+    type Origin = FromBox;
+}
+
+impl BridgedClusterEventSubscription {
+    pub(crate) fn new(abort_handle: AbortHandle) -> Self {
+        Self { abort_handle }
+    }
+}
+
+impl Drop for BridgedClusterEventSubscription {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_event_subscription_free(
+    subscription_ptr: BridgedOwnedExclusivePtr<BridgedClusterEventSubscription>,
+) {
+    BoxFFI::free(subscription_ptr);
+    tracing::trace!("[FFI] Cluster event subscription freed");
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use scylla::client::session::Session;
+use scylla::cluster::{ClusterState, Node};
+use scylla::errors::{DeserializationError, ExecutionError, IntoRowsResultError, RowsError};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIStr, FromArc};
+
+/// Scylla/Cassandra versions of the nodes, by host id.
+///
+/// The driver does not track them, so they are queried from `system.local` and `system.peers`
+/// when the session is created, and again when a snapshot contains nodes of unknown version,
+/// at most once per `MIN_REFRESH_INTERVAL`.
+#[derive(Debug, Default)]
+pub(crate) struct ReleaseVersions {
+    versions: RwLock<HashMap<Uuid, String>>,
+    // Whether a refresh is in progress, so that snapshots taken meanwhile do not start another one.
+    refreshing: AtomicBool,
+    // When the last refresh was started by a snapshot.
+    last_refresh: Mutex<Option<Instant>>,
+}
+
+/// Minimum time between refreshes started by snapshots. The version of a node which is down
+/// stays unknown, and every snapshot of the cluster would query the versions again otherwise.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Error, Debug)]
+enum ReleaseVersionsError {
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
+
+    #[error(transparent)]
+    IntoRowsResult(#[from] IntoRowsResultError),
+
+    #[error(transparent)]
+    Rows(#[from] RowsError),
+
+    #[error(transparent)]
+    Deserialization(#[from] DeserializationError),
+}
+
+impl ReleaseVersions {
+    pub(crate) fn get(&self, host_id: &Uuid) -> Option<String> {
+        self.versions.read().unwrap().get(host_id).cloned()
+    }
+
+    /// Starts a refresh, unless one is already in progress or the last one started
+    /// less than `MIN_REFRESH_INTERVAL` ago.
+    /// Returns false if the caller must not refresh.
+    pub(crate) fn start_refresh(&self) -> bool {
+        self.start_refresh_at(Instant::now())
+    }
+
+    fn start_refresh_at(&self, now: Instant) -> bool {
+        let mut last_refresh = self.last_refresh.lock().unwrap();
+        if last_refresh.is_some_and(|last| now.duration_since(last) < MIN_REFRESH_INTERVAL) {
+            return false;
+        }
+        if self.refreshing.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+        *last_refresh = Some(now);
+        true
+    }
+
+    /// Queries the versions of the nodes known to the node serving the queries.
+    /// Failures are only logged, as versions are informational.
+    pub(crate) async fn refresh(&self, session: &Session) {
+        self.refreshing.store(true, Ordering::Release);
+        for table in ["system.local", "system.peers"] {
+            match query_release_versions(session, table).await {
+                Ok(versions) => self.versions.write().unwrap().extend(versions),
+                Err(err) => {
+                    tracing::debug!("[FFI] Failed to query release versions from {table}: {err}")
+                }
+            }
+        }
+        self.refreshing.store(false, Ordering::Release);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn insert(&self, host_id: Uuid, version: &str) {
+        self.versions
+            .write()
+            .unwrap()
+            .insert(host_id, version.to_owned());
+    }
+}
+
+async fn query_release_versions(
+    session: &Session,
+    table: &str,
+) -> Result<Vec<(Uuid, String)>, ReleaseVersionsError> {
+    let result = session
+        .query_unpaged(format!("SELECT host_id, release_version FROM {table}"), ())
+        .await?
+        .into_rows_result()?;
+    let mut versions = Vec::new();
+    for row in result.rows::<(Option<Uuid>, Option<String>)>()? {
+        if let (Some(host_id), Some(version)) = row? {
+            versions.push((host_id, version));
+        }
+    }
+    Ok(versions)
+}
+
+/// Snapshot of the cluster topology, as known by the session when it was taken.
+///
+/// The session replaces its cluster state on every metadata refresh instead of modifying it,
+/// so the snapshot stays consistent while C# reads it.
+#[derive(Debug)]
+pub struct BridgedClusterState {
+    state: Arc<ClusterState>,
+    // IP addresses of the nodes, in the order of `ClusterState::get_nodes_info`.
+    node_addresses: Vec<String>,
+    // Versions of the nodes, in the same order; empty if not known.
+    node_versions: Vec<String>,
+}
+
+impl FFI for BridgedClusterState {
+    type Origin = FromArc;
+}
+
+impl BridgedClusterState {
+    pub(crate) fn new(state: Arc<ClusterState>, release_versions: &ReleaseVersions) -> Self {
+        let nodes = state.get_nodes_info();
+        let node_addresses = nodes
+            .iter()
+            .map(|node| node.address.ip().to_string())
+            .collect();
+        let node_versions = nodes
+            .iter()
+            .map(|node| release_versions.get(&node.host_id).unwrap_or_default())
+            .collect();
+        BridgedClusterState {
+            state,
+            node_addresses,
+            node_versions,
+        }
+    }
+
+    /// Whether the version of some node is not known yet, e.g. because it joined the cluster
+    /// after the versions were queried.
+    pub(crate) fn has_unknown_versions(&self) -> bool {
+        self.node_versions.iter().any(String::is_empty)
+    }
+
+    pub(crate) fn cluster_state(&self) -> &ClusterState {
+        &self.state
+    }
+}
+
+/// A single node of the cluster.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedNode<'state> {
+    /// IP address of the node.
+    address: FFIStr<'state>,
+    port: u16,
+    /// The 16 bytes of the host id (a UUID, in network byte order).
+    host_id: [u8; 16],
+    /// Datacenter of the node, or empty if not known.
+    datacenter: FFIStr<'state>,
+    /// Rack of the node, or empty if not known.
+    rack: FFIStr<'state>,
+    /// Number of shards of the node, or 0 if it is not a sharded (Scylla) node
+    /// or the driver has not connected to it yet.
+    shard_count: u16,
+    /// Whether the driver considers the node up, i.e. the last attempt to connect to it succeeded.
+    is_up: bool,
+    /// Whether the load balancing policy may route requests to the node.
+    is_enabled: bool,
+    /// Scylla/Cassandra version of the node (`release_version`), or empty if not known.
+    release_version: FFIStr<'state>,
+}
+
+impl<'state> BridgedNode<'state> {
+    /// `address` is the IP address of `node` and `release_version` its version, or empty,
+    /// both owned by the caller so that they outlive the call.
+    pub(crate) fn new(
+        node: &'state Node,
+        address: &'state str,
+        release_version: &'state str,
+    ) -> Self {
+        BridgedNode {
+            address: FFIStr::new(address),
+            port: node.address.port(),
+            host_id: *node.host_id.as_bytes(),
+            datacenter: FFIStr::new(node.datacenter.as_deref().unwrap_or_default()),
+            rack: FFIStr::new(node.rack.as_deref().unwrap_or_default()),
+            shard_count: node.sharder().map_or(0, |sharder| sharder.nr_shards.get()),
+            is_up: !node.is_down(),
+            is_enabled: node.is_enabled(),
+            release_version: FFIStr::new(release_version),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_free(state_ptr: BridgedOwnedSharedPtr<BridgedClusterState>) {
+    ArcFFI::free(state_ptr);
+    tracing::trace!("[FFI] Cluster state freed");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_nodes_count(
+    state_ptr: BridgedBorrowedSharedPtr<'_, BridgedClusterState>,
+) -> usize {
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_nodes_count");
+    };
+    state.state.get_nodes_info().len()
+}
+
+/// Returns the node at `index`.
+/// Its strings are valid as long as the cluster state is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_node<'state>(
+    state_ptr: BridgedBorrowedSharedPtr<'state, BridgedClusterState>,
+    index: usize,
+) -> BridgedNode<'state> {
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_node");
+    };
+    let Some(node) = state.state.get_nodes_info().get(index) else {
+        panic!("Node index out of range in cluster_state_get_node");
+    };
+    BridgedNode::new(
+        node,
+        &state.node_addresses[index],
+        &state.node_versions[index],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_versions_are_looked_up_by_host_id() {
+        let versions = ReleaseVersions::default();
+        let host_id = Uuid::from_u128(1);
+        assert_eq!(versions.get(&host_id), None);
+
+        versions.insert(host_id, "2025.1.0");
+        assert_eq!(versions.get(&host_id).as_deref(), Some("2025.1.0"));
+        assert_eq!(versions.get(&Uuid::from_u128(2)), None);
+    }
+
+    #[test]
+    fn only_one_release_versions_refresh_starts_at_a_time() {
+        let versions = ReleaseVersions::default();
+        assert!(versions.start_refresh());
+        assert!(!versions.start_refresh());
+    }
+
+    #[test]
+    fn release_versions_refreshes_are_rate_limited() {
+        let versions = ReleaseVersions::default();
+        let start = Instant::now();
+        assert!(versions.start_refresh_at(start));
+        // As at the end of `refresh`.
+        versions.refreshing.store(false, Ordering::Release);
+
+        assert!(!versions.start_refresh_at(start + Duration::from_secs(1)));
+        assert!(versions.start_refresh_at(start + MIN_REFRESH_INTERVAL));
+    }
+}
@@ -1,14 +1,22 @@
 use crate::ffi::{FFIByteSlice, FFIStr};
 use scylla::errors::{
-    ConnectionError, ConnectionPoolError, DbError, DeserializationError, MetadataError,
+    ClusterStateTokenError, ConnectionError, ConnectionPoolError, ConnectionSetupRequestError,
+    ConnectionSetupRequestErrorKind, DbError, DeserializationError, ExecutionError, MetadataError,
     NewSessionError, NextPageError, NextRowError, PagerExecutionError, PrepareError,
-    RequestAttemptError, RequestError, SerializationError,
+    RequestAttemptError, RequestError, SerializationError, TracingError, UseKeyspaceError,
+    WriteType,
 };
+use scylla::observability::history::{AttemptHistory, AttemptResult, StructuredHistory};
+use scylla::statement::Consistency;
+use scylla::statement::prepared::PartitionKeyError;
 use std::fmt::{Debug, Display};
 use std::mem::size_of;
+use std::net::SocketAddr;
 use std::ptr::NonNull;
+use std::time::Duration;
 use thiserror::Error;
 
+use crate::request_span::attempts;
 use crate::task::ExceptionConstructors;
 
 // Opaque type representing a C# Exception.
@@ -101,16 +109,50 @@ impl InvalidConfigurationInQueryExceptionConstructor {
     }
 }
 
+/// Error of a single node, passed to the C# `NoHostAvailableException` constructor.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedHostError<'a> {
+    /// IP address of the node.
+    address: FFIStr<'a>,
+    port: u16,
+    /// Ownership of the exception is transferred to C#.
+    exception: ExceptionPtr,
+}
+
 /// FFI constructor for C# `NoHostAvailableException`.
 #[repr(transparent)]
 pub struct NoHostAvailableExceptionConstructor(
-    unsafe extern "C" fn(message: FFIStr) -> ExceptionPtr,
+    unsafe extern "C" fn(
+        message: FFIStr<'_>,
+        host_errors: *const BridgedHostError<'_>,
+        host_errors_len: usize,
+    ) -> ExceptionPtr,
 );
 
 impl NoHostAvailableExceptionConstructor {
-    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+    /// `host_errors` holds the error of each node that was tried, and may be empty.
+    /// C# uses `message` only if it is.
+    pub(crate) fn construct_from_rust(
+        &self,
+        message: &str,
+        host_errors: &[(SocketAddr, ExceptionPtr)],
+    ) -> ExceptionPtr {
+        let addresses: Vec<String> = host_errors
+            .iter()
+            .map(|(address, _)| address.ip().to_string())
+            .collect();
+        let host_errors: Vec<BridgedHostError<'_>> = host_errors
+            .iter()
+            .zip(&addresses)
+            .map(|((address, exception), ip)| BridgedHostError {
+                address: FFIStr::new(ip),
+                port: address.port(),
+                exception: *exception,
+            })
+            .collect();
         let message = FFIStr::new(message);
-        unsafe { (self.0)(message) }
+        unsafe { (self.0)(message, host_errors.as_ptr(), host_errors.len()) }
     }
 }
 
@@ -121,9 +163,141 @@ pub struct OperationTimedOutExceptionConstructor(
 );
 
 impl OperationTimedOutExceptionConstructor {
-    pub(crate) fn construct_from_rust(&self, address: &str, timeout_ms: i32) -> ExceptionPtr {
-        let addr = FFIStr::new(address);
-        unsafe { (self.0)(addr, timeout_ms) }
+    /// `address` is the node the request was waiting for, or None if there is no single such node,
+    /// e.g. if the request timed out before being sent to any node.
+    pub(crate) fn construct_from_rust(
+        &self,
+        address: Option<SocketAddr>,
+        timeout: Duration,
+    ) -> ExceptionPtr {
+        let address = address
+            .map(|address| address.to_string())
+            .unwrap_or_default();
+        let addr = FFIStr::new(&address);
+        unsafe { (self.0)(addr, timeout.as_millis() as i32) }
+    }
+}
+
+/// FFI constructor for C# `ReadFailureException`.
+#[repr(transparent)]
+pub struct ReadFailureExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        data_present: u8,
+    ) -> ExceptionPtr,
+);
+
+impl ReadFailureExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        failures: i32,
+        data_present: bool,
+    ) -> ExceptionPtr {
+        unsafe {
+            (self.0)(
+                consistency as i16,
+                received,
+                required,
+                failures,
+                data_present as u8,
+            )
+        }
+    }
+}
+
+/// FFI constructor for C# `ReadTimeoutException`.
+#[repr(transparent)]
+pub struct ReadTimeoutExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        data_present: u8,
+    ) -> ExceptionPtr,
+);
+
+impl ReadTimeoutExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        data_present: bool,
+    ) -> ExceptionPtr {
+        unsafe { (self.0)(consistency as i16, received, required, data_present as u8) }
+    }
+}
+
+/// FFI constructor for C# `UnavailableException`.
+#[repr(transparent)]
+pub struct UnavailableExceptionConstructor(
+    unsafe extern "C" fn(consistency: i16, required: i32, alive: i32) -> ExceptionPtr,
+);
+
+impl UnavailableExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        required: i32,
+        alive: i32,
+    ) -> ExceptionPtr {
+        unsafe { (self.0)(consistency as i16, required, alive) }
+    }
+}
+
+/// FFI constructor for C# `WriteFailureException`.
+#[repr(transparent)]
+pub struct WriteFailureExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        write_type: FFIStr<'_>,
+    ) -> ExceptionPtr,
+);
+
+impl WriteFailureExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        failures: i32,
+        write_type: &WriteType,
+    ) -> ExceptionPtr {
+        let write_type = FFIStr::new(write_type.as_str());
+        unsafe { (self.0)(consistency as i16, received, required, failures, write_type) }
+    }
+}
+
+/// FFI constructor for C# `WriteTimeoutException`.
+#[repr(transparent)]
+pub struct WriteTimeoutExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        write_type: FFIStr<'_>,
+    ) -> ExceptionPtr,
+);
+
+impl WriteTimeoutExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        write_type: &WriteType,
+    ) -> ExceptionPtr {
+        let write_type = FFIStr::new(write_type.as_str());
+        unsafe { (self.0)(consistency as i16, received, required, write_type) }
     }
 }
 
@@ -185,15 +359,13 @@ impl SyntaxErrorExceptionConstructor {
     }
 }
 
-// TODO: Use this constructor for a specific error type.
-/// FFI constructor for C# `TraceRetrievalException` (currently unused).
+/// FFI constructor for C# `TraceRetrievalException`.
 #[repr(transparent)]
 pub struct TraceRetrievalExceptionConstructor(
     unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
 );
 
 impl TraceRetrievalExceptionConstructor {
-    #[expect(dead_code)] // Currently unused
     pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
         let message = FFIStr::new(message);
         unsafe { (self.0)(message) }
@@ -224,6 +396,121 @@ impl UnauthorizedExceptionConstructor {
     }
 }
 
+/// FFI constructor for C# `AuthenticationException`.
+#[repr(transparent)]
+pub struct AuthenticationExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl AuthenticationExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `TlsException`.
+#[repr(transparent)]
+pub struct TlsExceptionConstructor(unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr);
+
+impl TlsExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `ResultMetadataChangedException`.
+#[repr(transparent)]
+pub struct ResultMetadataChangedExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl ResultMetadataChangedExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `OperationCanceledException`.
+#[repr(transparent)]
+pub struct OperationCanceledExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl OperationCanceledExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `IsBootstrappingException`.
+#[repr(transparent)]
+pub struct IsBootstrappingExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl IsBootstrappingExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `OverloadedException`.
+#[repr(transparent)]
+pub struct OverloadedExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl OverloadedExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `ServerErrorException`.
+#[repr(transparent)]
+pub struct ServerErrorExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl ServerErrorExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `InvalidContactPointException`.
+#[repr(transparent)]
+pub struct InvalidContactPointExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl InvalidContactPointExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `InvalidKeyspaceException`.
+#[repr(transparent)]
+pub struct InvalidKeyspaceExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> ExceptionPtr,
+);
+
+impl InvalidKeyspaceExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> ExceptionPtr {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
 /// FFI constructor for C# `AlreadyExistsException`.
 #[repr(transparent)]
 pub struct AlreadyExistsConstructor(
@@ -315,15 +602,166 @@ impl ErrorToException for std::convert::Infallible {
 impl ErrorToException for PagerExecutionError {
     fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
         match self {
-            PagerExecutionError::NextPageError(NextPageError::RequestFailure(
-                RequestError::LastAttemptError(RequestAttemptError::DbError(db_error, message)),
+            PagerExecutionError::NextPageError(page_err) => page_err.to_exception(ctors),
+
+            // Statements executed with values are prepared first.
+            PagerExecutionError::PrepareError(prepare_err) => prepare_err.to_exception(ctors),
+
+            // TODO: Add more specific mappings for other error types as needed.
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
+/// Error of a request, together with its attempts, which tell the nodes it was sent to.
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub(crate) struct FailedRequest<E> {
+    pub(crate) error: E,
+    // Attempts of the last request, in the order they were sent.
+    attempts: Vec<AttemptHistory>,
+}
+
+impl<E> FailedRequest<E> {
+    /// Attaches the attempts of the last request in `history`, e.g. of the page that failed.
+    pub(crate) fn new(error: E, history: &StructuredHistory) -> Self {
+        let mut attempts: Vec<AttemptHistory> = history
+            .requests
+            .last()
+            .map(|request| attempts(request).cloned().collect())
+            .unwrap_or_default();
+        attempts.sort_by_key(|attempt| attempt.send_time);
+        FailedRequest { error, attempts }
+    }
+
+    /// For requests whose history is not recorded, so the nodes they were sent to are unknown.
+    pub(crate) fn without_history(error: E) -> Self {
+        FailedRequest {
+            error,
+            attempts: Vec::new(),
+        }
+    }
+
+    /// The nodes the request failed on, each with the error of its last failed attempt,
+    /// in the order they were first tried.
+    pub(crate) fn node_errors(&self) -> Vec<(SocketAddr, &RequestAttemptError)> {
+        let mut node_errors: Vec<(SocketAddr, &RequestAttemptError)> = Vec::new();
+        for attempt in &self.attempts {
+            let Some(AttemptResult::Error(_, err)) = &attempt.result else {
+                continue;
+            };
+            match node_errors
+                .iter_mut()
+                .find(|(address, _)| *address == attempt.node_addr)
+            {
+                Some(node_error) => node_error.1 = err,
+                None => node_errors.push((attempt.node_addr, err)),
+            }
+        }
+        node_errors
+    }
+
+    /// The node the request was waiting for when it failed: the last one it was sent to
+    /// that did not respond, or the last one it was sent to if all did.
+    pub(crate) fn coordinator(&self) -> Option<SocketAddr> {
+        self.attempts
+            .iter()
+            .rev()
+            .find(|attempt| attempt.result.is_none())
+            .or(self.attempts.last())
+            .map(|attempt| attempt.node_addr)
+    }
+}
+
+// Requests which no node could serve are reported with the errors of the nodes tried.
+impl ErrorToException for FailedRequest<PagerExecutionError> {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match &self.error {
+            PagerExecutionError::NextPageError(page_err) => {
+                self.next_page_exception(page_err, ctors)
+            }
+            err => err.to_exception(ctors),
+        }
+    }
+}
+
+impl<E: Display> FailedRequest<E> {
+    /// Builds a `NoHostAvailableException` holding the error of each node the request failed on.
+    pub(crate) fn no_host_available_exception(
+        &self,
+        ctors: &ExceptionConstructors,
+    ) -> ExceptionPtr {
+        let host_errors: Vec<(SocketAddr, ExceptionPtr)> = self
+            .node_errors()
+            .into_iter()
+            .map(|(address, err)| (address, err.to_exception(ctors)))
+            .collect();
+        ctors
+            .no_host_available_exception_constructor
+            .construct_from_rust(&self.error.to_string(), &host_errors)
+    }
+
+    /// Builds an `OperationTimedOutException` naming the node the request was waiting for
+    /// when the client-side timeout fired.
+    pub(crate) fn operation_timed_out_exception(
+        &self,
+        timeout: Duration,
+        ctors: &ExceptionConstructors,
+    ) -> ExceptionPtr {
+        ctors
+            .operation_timed_out_exception_constructor
+            .construct_from_rust(self.coordinator(), timeout)
+    }
+
+    /// Maps the error of fetching a page of this request, either the first one
+    /// or any of the next ones.
+    pub(crate) fn next_page_exception(
+        &self,
+        page_err: &NextPageError,
+        ctors: &ExceptionConstructors,
+    ) -> ExceptionPtr {
+        match page_err {
+            NextPageError::RequestFailure(
+                RequestError::EmptyPlan
+                | RequestError::ConnectionPoolError(_)
+                | RequestError::LastAttemptError(
+                    RequestAttemptError::BrokenConnectionError(_)
+                    | RequestAttemptError::UnableToAllocStreamId,
+                ),
+            ) => self.no_host_available_exception(ctors),
+            NextPageError::RequestFailure(RequestError::RequestTimeout(timeout)) => {
+                self.operation_timed_out_exception(*timeout, ctors)
+            }
+            err => err.to_exception(ctors),
+        }
+    }
+}
+
+// Specific mapping for errors of fetching a page, either the first one or any of the next ones.
+// Errors which depend on the nodes the page was requested from, e.g. timeouts, are mapped
+// by `FailedRequest::next_page_exception`.
+impl ErrorToException for NextPageError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            NextPageError::RequestFailure(RequestError::LastAttemptError(
+                RequestAttemptError::DbError(db_error, message),
             )) => (db_error, message.as_str()).to_exception(ctors),
 
-            PagerExecutionError::NextPageError(NextPageError::RequestFailure(
-                RequestError::RequestTimeout(duration),
-            )) => ctors
-                .operation_timed_out_exception_constructor
-                .construct_from_rust("0.0.0.0:0", duration.as_millis() as i32), // FIXME: address is unknown here; placeholder used
+            // TODO: Add more specific mappings for other error types as needed.
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
+// Specific mapping for ExecutionError, returned by non-paged requests (e.g. batches).
+// Errors which depend on the nodes the request was sent to, e.g. timeouts, are mapped
+// together with its history, as `UnpagedRequestError`.
+impl ErrorToException for ExecutionError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            ExecutionError::LastAttemptError(RequestAttemptError::DbError(db_error, message)) => {
+                (db_error, message.as_str()).to_exception(ctors)
+            }
 
             // TODO: Add more specific mappings for other error types as needed.
             _ => ctors.rust_exception_constructor.construct_from_rust(self),
@@ -331,10 +769,41 @@ impl ErrorToException for PagerExecutionError {
     }
 }
 
+// Specific mapping for errors of a single attempt of a request,
+// e.g. for the errors of the nodes held by NoHostAvailableException.
+impl ErrorToException for RequestAttemptError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            RequestAttemptError::DbError(db_error, message) => {
+                (db_error, message.as_str()).to_exception(ctors)
+            }
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
 // Specific mapping for PrepareError
 impl ErrorToException for PrepareError {
     fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
-        ctors.rust_exception_constructor.construct_from_rust(self) // TODO: convert errors to specific exceptions
+        match self {
+            // The statement was rejected by the server, e.g. because of a syntax error
+            // or an unknown table. All nodes reject it the same way, so the first error is enough.
+            PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::DbError(db_error, message),
+            } => (db_error, message.as_str()).to_exception(ctors),
+
+            // No node could be reached to prepare the statement on.
+            PrepareError::ConnectionPoolError(pool_err) => pool_err.to_exception(ctors),
+            PrepareError::AllAttemptsFailed {
+                first_attempt:
+                    RequestAttemptError::BrokenConnectionError(_)
+                    | RequestAttemptError::UnableToAllocStreamId,
+            } => ctors
+                .no_host_available_exception_constructor
+                .construct_from_rust(&self.to_string(), &[]),
+
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
     }
 }
 
@@ -342,21 +811,157 @@ impl ErrorToException for PrepareError {
 impl ErrorToException for NewSessionError {
     fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
         match self {
-            NewSessionError::MetadataError(MetadataError::ConnectionPoolError(
+            // The contact points are wrong, as opposed to the nodes being unreachable.
+            NewSessionError::FailedToResolveAnyHostname(_)
+            | NewSessionError::EmptyKnownNodesList => ctors
+                .invalid_contact_point_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            NewSessionError::MetadataError(MetadataError::ConnectionPoolError(pool_err)) => {
+                pool_err.to_exception(ctors)
+            }
+
+            // The keyspace given in the session configuration could not be used.
+            NewSessionError::UseKeyspaceError(use_keyspace_err) => {
+                use_keyspace_err.to_exception(ctors)
+            }
+
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
+/// Error of creating a session, together with the addresses its contact points resolve to.
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub(crate) struct FailedSessionCreation {
+    pub(crate) error: NewSessionError,
+    contact_points: Vec<SocketAddr>,
+}
+
+impl FailedSessionCreation {
+    pub(crate) fn new(error: NewSessionError, contact_points: Vec<SocketAddr>) -> Self {
+        FailedSessionCreation {
+            error,
+            contact_points,
+        }
+    }
+}
+
+// If no contact point could be reached, each of them is reported with the error of the driver,
+// which only keeps the error of the last connection attempt.
+impl ErrorToException for FailedSessionCreation {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match &self.error {
+            NewSessionError::MetadataError(MetadataError::ConnectionPoolError(pool_err)) => {
+                connection_failure_exception(pool_err, ctors).unwrap_or_else(|| {
+                    let host_errors: Vec<(SocketAddr, ExceptionPtr)> = self
+                        .contact_points
+                        .iter()
+                        .map(|address| {
+                            let exception = ctors
+                                .rust_exception_constructor
+                                .construct_from_rust(pool_err);
+                            (*address, exception)
+                        })
+                        .collect();
+                    ctors
+                        .no_host_available_exception_constructor
+                        .construct_from_rust(&pool_err.to_string(), &host_errors)
+                })
+            }
+            err => err.to_exception(ctors),
+        }
+    }
+}
+
+/// Maps connection failures which are not caused by the nodes being unreachable,
+/// e.g. a rejected authentication or TLS handshake. Returns None for other failures.
+fn connection_failure_exception(
+    pool_err: &ConnectionPoolError,
+    ctors: &ExceptionConstructors,
+) -> Option<ExceptionPtr> {
+    match pool_err {
+        ConnectionPoolError::Broken {
+            last_connection_error: ConnectionError::ConnectionSetupRequestError(setup_err),
+        } => Some(setup_err.to_exception(ctors)),
+        ConnectionPoolError::Broken {
+            last_connection_error: ConnectionError::IoError(io_err),
+        } => crate::tls::as_tls_error(io_err).map(|tls_err| {
+            ctors
+                .tls_exception_constructor
+                .construct_from_rust(&format!("TLS handshake failed: {tls_err}"))
+        }),
+        _ => None,
+    }
+}
+
+// Specific mapping for ConnectionPoolError, returned when no connection to any node could be used.
+// Which nodes were tried is not known here, so no host errors are passed.
+impl ErrorToException for ConnectionPoolError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        connection_failure_exception(self, ctors).unwrap_or_else(|| {
+            let message = match self {
                 ConnectionPoolError::Broken {
                     last_connection_error: ConnectionError::IoError(io_err),
-                },
-            )) => {
-                match io_err.kind() {
-                    std::io::ErrorKind::ConnectionRefused
-                    | std::io::ErrorKind::TimedOut
-                    | std::io::ErrorKind::NotConnected => ctors
-                        .no_host_available_exception_constructor
-                        .construct_from_rust(io_err.to_string().as_str()),
-                    _ => ctors.rust_exception_constructor.construct_from_rust(self), // TODO: convert errors to specific exceptions
-                }
+                } => io_err.to_string(),
+                _ => self.to_string(),
+            };
+            ctors
+                .no_host_available_exception_constructor
+                .construct_from_rust(&message, &[])
+        })
+    }
+}
+
+// Specific mapping for UseKeyspaceError, returned by USE requests and by session creation
+// with a keyspace configured.
+impl ErrorToException for UseKeyspaceError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            // Most commonly, the keyspace does not exist.
+            UseKeyspaceError::RequestError(RequestAttemptError::DbError(
+                DbError::Invalid,
+                message,
+            )) => ctors
+                .invalid_keyspace_exception_constructor
+                .construct_from_rust(message),
+            UseKeyspaceError::RequestError(attempt_err) => attempt_err.to_exception(ctors),
+
+            UseKeyspaceError::BadKeyspaceName(_)
+            | UseKeyspaceError::KeyspaceNameMismatch { .. } => ctors
+                .invalid_keyspace_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            // USE is sent on all connections and is not recorded in any history,
+            // so there is no single node to blame.
+            UseKeyspaceError::RequestTimeout(timeout) => ctors
+                .operation_timed_out_exception_constructor
+                .construct_from_rust(None, *timeout),
+
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
+// Specific mapping for errors of requests sent while setting up a connection,
+// e.g. STARTUP or AUTH_RESPONSE.
+impl ErrorToException for ConnectionSetupRequestError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self.get_error() {
+            ConnectionSetupRequestErrorKind::DbError(db_error, message) => {
+                (db_error, message.as_str()).to_exception(ctors)
             }
-            _ => ctors.rust_exception_constructor.construct_from_rust(self), // TODO: convert errors to specific exceptions
+
+            // Failures of the client side of the SASL exchange, e.g. of a C# `IAuthenticator`.
+            ConnectionSetupRequestErrorKind::StartAuthSessionError(_)
+            | ConnectionSetupRequestErrorKind::AuthChallengeEvaluationError(_)
+            | ConnectionSetupRequestErrorKind::AuthFinishError(_)
+            | ConnectionSetupRequestErrorKind::MissingAuthentication => ctors
+                .authentication_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
         }
     }
 }
@@ -376,6 +981,10 @@ impl ErrorToException for (&DbError, &str) {
                 .syntax_error_exception_constructor
                 .construct_from_rust(message),
 
+            DbError::AuthenticationError => ctors
+                .authentication_exception_constructor
+                .construct_from_rust(message),
+
             DbError::Unauthorized => ctors
                 .unauthorized_exception_constructor
                 .construct_from_rust(message),
@@ -396,6 +1005,77 @@ impl ErrorToException for (&DbError, &str) {
                 .invalid_configuration_in_query_constructor
                 .construct_from_rust(message),
 
+            DbError::Unavailable {
+                consistency,
+                required,
+                alive,
+            } => ctors.unavailable_exception_constructor.construct_from_rust(
+                *consistency,
+                *required,
+                *alive,
+            ),
+
+            DbError::ReadTimeout {
+                consistency,
+                received,
+                required,
+                data_present,
+            } => ctors
+                .read_timeout_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, *data_present),
+
+            DbError::WriteTimeout {
+                consistency,
+                received,
+                required,
+                write_type,
+            } => ctors
+                .write_timeout_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, write_type),
+
+            DbError::ReadFailure {
+                consistency,
+                received,
+                required,
+                numfailures,
+                data_present,
+            } => ctors
+                .read_failure_exception_constructor
+                .construct_from_rust(
+                    *consistency,
+                    *received,
+                    *required,
+                    *numfailures,
+                    *data_present,
+                ),
+
+            DbError::WriteFailure {
+                consistency,
+                received,
+                required,
+                numfailures,
+                write_type,
+            } => ctors
+                .write_failure_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, *numfailures, write_type),
+
+            DbError::Overloaded => ctors
+                .overloaded_exception_constructor
+                .construct_from_rust(message),
+
+            // Scylla's per-partition rate limit; like overload, the request may be retried later.
+            DbError::RateLimitReached { .. } => ctors
+                .overloaded_exception_constructor
+                .construct_from_rust(message),
+
+            DbError::IsBootstrapping => ctors
+                .is_bootstrapping_exception_constructor
+                .construct_from_rust(message),
+
+            DbError::ServerError => ctors
+                .server_error_exception_constructor
+                .construct_from_rust(message),
+
             _ => ctors
                 .rust_exception_constructor
                 .construct_from_rust(db_error),
@@ -411,9 +1091,46 @@ impl ErrorToException for DeserializationError {
     }
 }
 
+// Returned while iterating over the rows of a paged result, e.g. when fetching its second page.
 impl ErrorToException for NextRowError {
     fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
-        ctors.rust_exception_constructor.construct_from_rust(self) // TODO: convert errors to specific exceptions
+        match self {
+            NextRowError::NextPageError(page_err) => page_err.to_exception(ctors),
+            NextRowError::RowDeserializationError(deser_err) => deser_err.to_exception(ctors),
+            _ => ctors.rust_exception_constructor.construct_from_rust(self),
+        }
+    }
+}
+
+// Includes the case of the trace not being available yet after all fetch attempts.
+impl ErrorToException for TracingError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors
+            .trace_retrieval_exception_constructor
+            .construct_from_rust(&self.to_string())
+    }
+}
+
+// Errors of computing a token locally are caused by the passed values or names, as with requests.
+impl ErrorToException for ClusterStateTokenError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            ClusterStateTokenError::Serialization(e) => e.to_exception(ctors),
+            _ => ctors
+                .invalid_query_constructor
+                .construct_from_rust(&self.to_string()),
+        }
+    }
+}
+
+impl ErrorToException for PartitionKeyError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            PartitionKeyError::Serialization(e) => e.to_exception(ctors),
+            _ => ctors
+                .invalid_query_constructor
+                .construct_from_rust(&self.to_string()),
+        }
     }
 }
 
@@ -440,3 +1157,583 @@ where
         }
     }
 }
+
+/// Stand-ins for the C# exception constructors, used to check error mapping in unit tests.
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+
+    /// Exception "constructed" by [`CONSTRUCTORS`].
+    /// Records the C# exception type and the arguments Rust passed to its constructor.
+    #[derive(Debug, PartialEq, Eq)]
+    pub(crate) struct TestException {
+        pub(crate) kind: &'static str,
+        pub(crate) args: Vec<String>,
+        /// Exceptions of the individual hosts, passed to `NoHostAvailableException`.
+        pub(crate) host_errors: Vec<TestException>,
+    }
+
+    impl TestException {
+        fn new(kind: &'static str, args: impl IntoIterator<Item = String>) -> ExceptionPtr {
+            TestException {
+                kind,
+                args: args.into_iter().collect(),
+                host_errors: Vec::new(),
+            }
+            .into_ptr()
+        }
+
+        fn into_ptr(self) -> ExceptionPtr {
+            ExceptionPtr(NonNull::from(Box::leak(Box::new(self))).cast())
+        }
+
+        /// Takes back an exception created by one of [`CONSTRUCTORS`].
+        pub(crate) fn from_ptr(exception: ExceptionPtr) -> Self {
+            // SAFETY: All constructors of `CONSTRUCTORS` return a leaked `Box<TestException>`.
+            *unsafe { Box::from_raw(exception.0.cast::<TestException>().as_ptr()) }
+        }
+
+        /// Takes back the exception of `result`, if any.
+        pub(crate) fn from_ffi(result: FfiException) -> Option<Self> {
+            result.exception.map(Self::from_ptr)
+        }
+
+        /// Maps `error` as it would be passed to C#.
+        pub(crate) fn from_error(error: impl ErrorToException) -> Self {
+            Self::from_ptr(error.to_exception(&CONSTRUCTORS))
+        }
+
+        /// The message passed to a constructor that takes only a message.
+        pub(crate) fn message(&self) -> &str {
+            &self.args[0]
+        }
+    }
+
+    fn string(s: &FFIStr<'_>) -> String {
+        // SAFETY: Rust keeps the string alive for the duration of the constructor call.
+        unsafe { s.as_str() }.to_owned()
+    }
+
+    macro_rules! message_constructors {
+        ($($name:ident => $kind:literal),* $(,)?) => {$(
+            unsafe extern "C" fn $name(message: FFIStr<'_>) -> ExceptionPtr {
+                TestException::new($kind, [string(&message)])
+            }
+        )*};
+    }
+
+    message_constructors! {
+        already_shutdown => "AlreadyShutdownException",
+        authentication => "AuthenticationException",
+        deserialization => "DeserializationException",
+        function_failure => "FunctionFailureException",
+        invalid_configuration_in_query => "InvalidConfigurationInQueryException",
+        invalid_contact_point => "InvalidContactPointException",
+        invalid_keyspace => "InvalidKeyspaceException",
+        invalid_query => "InvalidQueryException",
+        is_bootstrapping => "IsBootstrappingException",
+        operation_canceled => "OperationCanceledException",
+        overloaded => "OverloadedException",
+        request_invalid => "RequestInvalidException",
+        result_metadata_changed => "ResultMetadataChangedException",
+        rust => "RustException",
+        serialization => "SerializationException",
+        server_error => "ServerErrorException",
+        syntax_error => "SyntaxErrorException",
+        tls => "TlsException",
+        trace_retrieval => "TraceRetrievalException",
+        truncate => "TruncateException",
+        unauthorized => "UnauthorizedException",
+    }
+
+    unsafe extern "C" fn already_exists(keyspace: FFIStr<'_>, table: FFIStr<'_>) -> ExceptionPtr {
+        TestException::new(
+            "AlreadyExistsException",
+            [string(&keyspace), string(&table)],
+        )
+    }
+
+    unsafe extern "C" fn no_host_available(
+        message: FFIStr<'_>,
+        host_errors: *const BridgedHostError<'_>,
+        host_errors_len: usize,
+    ) -> ExceptionPtr {
+        // SAFETY: Rust passes a valid slice for the duration of the call.
+        let host_errors = unsafe { std::slice::from_raw_parts(host_errors, host_errors_len) };
+        let addresses = host_errors
+            .iter()
+            .map(|e| format!("{}:{}", string(&e.address), e.port));
+        TestException {
+            kind: "NoHostAvailableException",
+            args: std::iter::once(string(&message)).chain(addresses).collect(),
+            host_errors: host_errors
+                .iter()
+                .map(|e| TestException::from_ptr(e.exception))
+                .collect(),
+        }
+        .into_ptr()
+    }
+
+    unsafe extern "C" fn operation_timed_out(address: FFIStr<'_>, timeout_ms: i32) -> ExceptionPtr {
+        TestException::new(
+            "OperationTimedOutException",
+            [string(&address), timeout_ms.to_string()],
+        )
+    }
+
+    unsafe extern "C" fn prepared_query_not_found(
+        message: FFIStr<'_>,
+        unknown_id: FFIByteSlice<'_>,
+    ) -> ExceptionPtr {
+        // SAFETY: Rust keeps the id alive for the duration of the call.
+        let unknown_id = unsafe { unknown_id.as_slice() };
+        TestException::new(
+            "PreparedQueryNotFoundException",
+            [string(&message), format!("{unknown_id:?}")],
+        )
+    }
+
+    unsafe extern "C" fn read_failure(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        data_present: u8,
+    ) -> ExceptionPtr {
+        TestException::new(
+            "ReadFailureException",
+            [
+                consistency as i32,
+                received,
+                required,
+                failures,
+                data_present as i32,
+            ]
+            .map(|arg| arg.to_string()),
+        )
+    }
+
+    unsafe extern "C" fn read_timeout(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        data_present: u8,
+    ) -> ExceptionPtr {
+        TestException::new(
+            "ReadTimeoutException",
+            [consistency as i32, received, required, data_present as i32]
+                .map(|arg| arg.to_string()),
+        )
+    }
+
+    unsafe extern "C" fn unavailable(consistency: i16, required: i32, alive: i32) -> ExceptionPtr {
+        TestException::new(
+            "UnavailableException",
+            [consistency as i32, required, alive].map(|arg| arg.to_string()),
+        )
+    }
+
+    unsafe extern "C" fn write_failure(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        write_type: FFIStr<'_>,
+    ) -> ExceptionPtr {
+        let args = [consistency as i32, received, required, failures].map(|arg| arg.to_string());
+        TestException::new(
+            "WriteFailureException",
+            args.into_iter().chain([string(&write_type)]),
+        )
+    }
+
+    unsafe extern "C" fn write_timeout(
+        consistency: i16,
+        received: i32,
+        required: i32,
+        write_type: FFIStr<'_>,
+    ) -> ExceptionPtr {
+        let args = [consistency as i32, received, required].map(|arg| arg.to_string());
+        TestException::new(
+            "WriteTimeoutException",
+            args.into_iter().chain([string(&write_type)]),
+        )
+    }
+
+    /// Constructors to pass wherever Rust expects the constructors of C#.
+    pub(crate) static CONSTRUCTORS: ExceptionConstructors = ExceptionConstructors {
+        already_exists_constructor: AlreadyExistsConstructor(already_exists),
+        already_shutdown_exception_constructor: AlreadyShutdownExceptionConstructor(
+            already_shutdown,
+        ),
+        authentication_exception_constructor: AuthenticationExceptionConstructor(authentication),
+        deserialization_exception_constructor: DeserializationExceptionConstructor(deserialization),
+        function_failure_exception_constructor: FunctionFailureExceptionConstructor(
+            function_failure,
+        ),
+        invalid_configuration_in_query_constructor: InvalidConfigurationInQueryExceptionConstructor(
+            invalid_configuration_in_query,
+        ),
+        invalid_contact_point_exception_constructor: InvalidContactPointExceptionConstructor(
+            invalid_contact_point,
+        ),
+        invalid_keyspace_exception_constructor: InvalidKeyspaceExceptionConstructor(
+            invalid_keyspace,
+        ),
+        invalid_query_constructor: InvalidQueryConstructor(invalid_query),
+        is_bootstrapping_exception_constructor: IsBootstrappingExceptionConstructor(
+            is_bootstrapping,
+        ),
+        no_host_available_exception_constructor: NoHostAvailableExceptionConstructor(
+            no_host_available,
+        ),
+        operation_canceled_exception_constructor: OperationCanceledExceptionConstructor(
+            operation_canceled,
+        ),
+        operation_timed_out_exception_constructor: OperationTimedOutExceptionConstructor(
+            operation_timed_out,
+        ),
+        overloaded_exception_constructor: OverloadedExceptionConstructor(overloaded),
+        prepared_query_not_found_exception_constructor: PreparedQueryNotFoundExceptionConstructor(
+            prepared_query_not_found,
+        ),
+        read_failure_exception_constructor: ReadFailureExceptionConstructor(read_failure),
+        read_timeout_exception_constructor: ReadTimeoutExceptionConstructor(read_timeout),
+        request_invalid_exception_constructor: RequestInvalidExceptionConstructor(request_invalid),
+        result_metadata_changed_exception_constructor: ResultMetadataChangedExceptionConstructor(
+            result_metadata_changed,
+        ),
+        rust_exception_constructor: RustExceptionConstructor(rust),
+        serialization_exception_constructor: SerializationExceptionConstructor(serialization),
+        server_error_exception_constructor: ServerErrorExceptionConstructor(server_error),
+        syntax_error_exception_constructor: SyntaxErrorExceptionConstructor(syntax_error),
+        tls_exception_constructor: TlsExceptionConstructor(tls),
+        trace_retrieval_exception_constructor: TraceRetrievalExceptionConstructor(trace_retrieval),
+        truncate_exception_constructor: TruncateExceptionConstructor(truncate),
+        unauthorized_exception_constructor: UnauthorizedExceptionConstructor(unauthorized),
+        unavailable_exception_constructor: UnavailableExceptionConstructor(unavailable),
+        write_failure_exception_constructor: WriteFailureExceptionConstructor(write_failure),
+        write_timeout_exception_constructor: WriteTimeoutExceptionConstructor(write_timeout),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::TestException;
+    use super::*;
+    use scylla::errors::{BadKeyspaceName, OperationType};
+    use scylla::observability::history::{HistoryCollector, HistoryListener};
+    use scylla::policies::retry::RetryDecision;
+
+    fn db_exception(db_error: DbError) -> TestException {
+        TestException::from_error((&db_error, "server message"))
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn unavailable_passes_consistency_and_replica_counts() {
+        let exception = db_exception(DbError::Unavailable {
+            consistency: Consistency::Quorum,
+            required: 3,
+            alive: 1,
+        });
+        assert_eq!(exception.kind, "UnavailableException");
+        assert_eq!(exception.args, args(&["4", "3", "1"]));
+    }
+
+    #[test]
+    fn read_timeout_passes_data_present() {
+        let exception = db_exception(DbError::ReadTimeout {
+            consistency: Consistency::LocalQuorum,
+            received: 1,
+            required: 2,
+            data_present: true,
+        });
+        assert_eq!(exception.kind, "ReadTimeoutException");
+        assert_eq!(exception.args, args(&["6", "1", "2", "1"]));
+    }
+
+    #[test]
+    fn write_timeout_passes_write_type() {
+        let exception = db_exception(DbError::WriteTimeout {
+            consistency: Consistency::One,
+            received: 0,
+            required: 1,
+            write_type: WriteType::Simple,
+        });
+        assert_eq!(exception.kind, "WriteTimeoutException");
+        assert_eq!(exception.args, args(&["1", "0", "1", "SIMPLE"]));
+    }
+
+    #[test]
+    fn read_failure_passes_failures() {
+        let exception = db_exception(DbError::ReadFailure {
+            consistency: Consistency::All,
+            received: 1,
+            required: 3,
+            numfailures: 2,
+            data_present: false,
+        });
+        assert_eq!(exception.kind, "ReadFailureException");
+        assert_eq!(exception.args, args(&["5", "1", "3", "2", "0"]));
+    }
+
+    #[test]
+    fn write_failure_passes_failures_and_write_type() {
+        let exception = db_exception(DbError::WriteFailure {
+            consistency: Consistency::Two,
+            received: 1,
+            required: 2,
+            numfailures: 1,
+            write_type: WriteType::Batch,
+        });
+        assert_eq!(exception.kind, "WriteFailureException");
+        assert_eq!(exception.args, args(&["2", "1", "2", "1", "BATCH"]));
+    }
+
+    #[test]
+    fn overload_and_rate_limit_map_to_overloaded() {
+        for db_error in [
+            DbError::Overloaded,
+            DbError::RateLimitReached {
+                op_type: OperationType::Write,
+                rejected_by_coordinator: true,
+            },
+        ] {
+            let exception = db_exception(db_error);
+            assert_eq!(exception.kind, "OverloadedException");
+            assert_eq!(exception.message(), "server message");
+        }
+    }
+
+    #[test]
+    fn node_state_errors_pass_server_message() {
+        let exception = db_exception(DbError::IsBootstrapping);
+        assert_eq!(exception.kind, "IsBootstrappingException");
+        assert_eq!(exception.message(), "server message");
+
+        let exception = db_exception(DbError::ServerError);
+        assert_eq!(exception.kind, "ServerErrorException");
+        assert_eq!(exception.message(), "server message");
+    }
+
+    #[test]
+    fn unmapped_db_error_maps_to_rust_exception() {
+        let exception = db_exception(DbError::ProtocolError);
+        assert_eq!(exception.kind, "RustException");
+        assert!(exception.message().starts_with("Rust exception:"));
+    }
+
+    #[test]
+    fn attempt_error_maps_its_db_error() {
+        let exception = TestException::from_error(RequestAttemptError::DbError(
+            DbError::IsBootstrapping,
+            "bootstrapping".to_owned(),
+        ));
+        assert_eq!(exception.kind, "IsBootstrappingException");
+        assert_eq!(exception.message(), "bootstrapping");
+    }
+
+    #[test]
+    fn prepare_db_errors_map_like_request_errors() {
+        let exception = TestException::from_error(PrepareError::AllAttemptsFailed {
+            first_attempt: RequestAttemptError::DbError(
+                DbError::SyntaxError,
+                "line 1:0".to_owned(),
+            ),
+        });
+        assert_eq!(exception.kind, "SyntaxErrorException");
+        assert_eq!(exception.message(), "line 1:0");
+
+        let exception = TestException::from_error(PrepareError::AllAttemptsFailed {
+            first_attempt: RequestAttemptError::DbError(
+                DbError::Invalid,
+                "unconfigured table".to_owned(),
+            ),
+        });
+        assert_eq!(exception.kind, "InvalidQueryException");
+        assert_eq!(exception.message(), "unconfigured table");
+    }
+
+    #[test]
+    fn prepare_without_usable_connection_maps_to_no_host_available() {
+        for error in [
+            PrepareError::ConnectionPoolError(ConnectionPoolError::Initializing),
+            PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::UnableToAllocStreamId,
+            },
+        ] {
+            let exception = TestException::from_error(error);
+            assert_eq!(exception.kind, "NoHostAvailableException");
+        }
+    }
+
+    #[test]
+    fn bad_contact_points_map_to_invalid_contact_point() {
+        for error in [
+            NewSessionError::FailedToResolveAnyHostname(vec!["host.invalid:9042".to_owned()]),
+            NewSessionError::EmptyKnownNodesList,
+        ] {
+            let message = error.to_string();
+            let exception = TestException::from_error(error);
+            assert_eq!(exception.kind, "InvalidContactPointException");
+            assert_eq!(exception.message(), message);
+        }
+    }
+
+    #[test]
+    fn unreachable_cluster_maps_to_no_host_available() {
+        let exception = TestException::from_error(NewSessionError::MetadataError(
+            MetadataError::ConnectionPoolError(ConnectionPoolError::Initializing),
+        ));
+        assert_eq!(exception.kind, "NoHostAvailableException");
+    }
+
+    #[test]
+    fn unknown_keyspace_maps_to_invalid_keyspace() {
+        let exception = TestException::from_error(UseKeyspaceError::RequestError(
+            RequestAttemptError::DbError(
+                DbError::Invalid,
+                "Keyspace 'ks' does not exist".to_owned(),
+            ),
+        ));
+        assert_eq!(exception.kind, "InvalidKeyspaceException");
+        assert_eq!(exception.message(), "Keyspace 'ks' does not exist");
+
+        // Other server errors keep their own mapping.
+        let exception = TestException::from_error(UseKeyspaceError::RequestError(
+            RequestAttemptError::DbError(DbError::Unauthorized, "no access".to_owned()),
+        ));
+        assert_eq!(exception.kind, "UnauthorizedException");
+    }
+
+    #[test]
+    fn bad_keyspace_names_map_to_invalid_keyspace() {
+        for error in [
+            UseKeyspaceError::BadKeyspaceName(BadKeyspaceName::Empty),
+            UseKeyspaceError::KeyspaceNameMismatch {
+                expected_keyspace_name_lowercase: "ks1".to_owned(),
+                result_keyspace_name_lowercase: "ks2".to_owned(),
+            },
+        ] {
+            let message = error.to_string();
+            let exception = TestException::from_error(error);
+            assert_eq!(exception.kind, "InvalidKeyspaceException");
+            assert_eq!(exception.message(), message);
+        }
+    }
+
+    #[test]
+    fn use_keyspace_timeout_has_no_address() {
+        let exception = TestException::from_error(UseKeyspaceError::RequestTimeout(
+            Duration::from_millis(1500),
+        ));
+        assert_eq!(exception.kind, "OperationTimedOutException");
+        assert_eq!(exception.args, args(&["", "1500"]));
+    }
+
+    #[test]
+    fn unreachable_contact_points_are_reported_as_host_errors() {
+        let contact_points: Vec<SocketAddr> = vec![
+            "127.0.0.1:9042".parse().unwrap(),
+            "127.0.0.2:19042".parse().unwrap(),
+        ];
+        let exception = TestException::from_error(FailedSessionCreation::new(
+            NewSessionError::MetadataError(MetadataError::ConnectionPoolError(
+                ConnectionPoolError::Initializing,
+            )),
+            contact_points,
+        ));
+        assert_eq!(exception.kind, "NoHostAvailableException");
+        assert_eq!(
+            exception.args[1..].to_vec(),
+            args(&["127.0.0.1:9042", "127.0.0.2:19042"])
+        );
+        assert_eq!(exception.host_errors.len(), 2);
+        assert!(
+            exception
+                .host_errors
+                .iter()
+                .all(|host_error| host_error.kind == "RustException")
+        );
+    }
+
+    #[test]
+    fn other_session_creation_errors_have_no_host_errors() {
+        let exception = TestException::from_error(FailedSessionCreation::new(
+            NewSessionError::EmptyKnownNodesList,
+            vec!["127.0.0.1:9042".parse().unwrap()],
+        ));
+        assert_eq!(exception.kind, "InvalidContactPointException");
+        assert!(exception.host_errors.is_empty());
+    }
+
+    #[test]
+    fn timeout_of_page_names_the_node_without_response() {
+        let node: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let collector = HistoryCollector::new();
+        let request_id = collector.log_request_start();
+        collector.log_attempt_start(request_id, None, node);
+
+        let exception = TestException::from_error(FailedRequest::new(
+            PagerExecutionError::NextPageError(NextPageError::RequestFailure(
+                RequestError::RequestTimeout(Duration::from_secs(2)),
+            )),
+            &collector.clone_structured_history(),
+        ));
+        assert_eq!(exception.kind, "OperationTimedOutException");
+        assert_eq!(exception.args, args(&["127.0.0.2:9042", "2000"]));
+    }
+
+    #[test]
+    fn coordinator_is_the_last_node_without_response() {
+        let node_a: SocketAddr = "127.0.0.1:9042".parse().unwrap();
+        let node_b: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let collector = HistoryCollector::new();
+        let request_id = collector.log_request_start();
+        let attempt_a = collector.log_attempt_start(request_id, None, node_a);
+        collector.log_attempt_error(
+            attempt_a,
+            &RequestAttemptError::UnableToAllocStreamId,
+            &RetryDecision::RetryNextTarget(None),
+        );
+        collector.log_attempt_start(request_id, None, node_b);
+
+        let failed = FailedRequest::new((), &collector.clone_structured_history());
+        assert_eq!(failed.coordinator(), Some(node_b));
+        let failed_nodes: Vec<SocketAddr> = failed
+            .node_errors()
+            .into_iter()
+            .map(|(address, _)| address)
+            .collect();
+        assert_eq!(failed_nodes, vec![node_a]);
+    }
+
+    #[test]
+    fn coordinator_is_the_last_node_tried_when_all_responded() {
+        let node_a: SocketAddr = "127.0.0.1:9042".parse().unwrap();
+        let node_b: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let collector = HistoryCollector::new();
+        let request_id = collector.log_request_start();
+        for node in [node_a, node_b] {
+            let attempt_id = collector.log_attempt_start(request_id, None, node);
+            collector.log_attempt_error(
+                attempt_id,
+                &RequestAttemptError::UnableToAllocStreamId,
+                &RetryDecision::RetryNextTarget(None),
+            );
+        }
+
+        let failed = FailedRequest::new((), &collector.clone_structured_history());
+        assert_eq!(failed.coordinator(), Some(node_b));
+    }
+
+    #[test]
+    fn coordinator_is_unknown_without_attempts() {
+        let failed = FailedRequest::new((), &HistoryCollector::new().clone_structured_history());
+        assert_eq!(failed.coordinator(), None);
+    }
+}
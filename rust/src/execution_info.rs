@@ -0,0 +1,363 @@
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use scylla::client::pager::QueryPager;
+use scylla::observability::history::{
+    AttemptHistory, AttemptResult, HistoryCollector, RequestHistoryResult, StructuredHistory,
+};
+use scylla::response::query_result::QueryResult;
+use uuid::Uuid;
+
+use crate::ffi::{
+    BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, FFI, FFIStr, FromBox,
+};
+use crate::request_span::{attempts, coordinator};
+
+/// Details of the request that produced a RowSet, from which its execution info is built.
+///
+/// Request history is kept for all requests. For results fetched page by page, the coordinator
+/// is taken from the history and the tracing ids are recorded as the pages arrive;
+/// the warnings and the shard are only known for results of a single page,
+/// as `QueryPager` does not expose them.
+#[derive(Default)]
+pub(crate) struct RequestDetails {
+    history: Option<Arc<HistoryCollector>>,
+    // Connection the response came from, with its shard if known.
+    coordinator: Option<(SocketAddr, Option<u32>)>,
+    warnings: Vec<String>,
+    tracing_id: Option<[u8; 16]>,
+    // Tracing ids of the pages fetched so far by a pager, in the order the pages arrived.
+    // Behind a Mutex, as pages are fetched through a shared RowSet.
+    paged_tracing_ids: Mutex<Vec<[u8; 16]>>,
+}
+
+impl RequestDetails {
+    /// Takes the details of the response, before the result is consumed.
+    pub(crate) fn from_result(result: &QueryResult) -> Self {
+        let coordinator = result.request_coordinator();
+        RequestDetails {
+            history: None,
+            coordinator: Some((
+                coordinator.connection_address(),
+                coordinator.connection_shard(),
+            )),
+            warnings: result.warnings().map(str::to_owned).collect(),
+            tracing_id: result.tracing_id().map(|id| *id.as_bytes()),
+            paged_tracing_ids: Mutex::default(),
+        }
+    }
+
+    /// Records the tracing ids of the pages fetched by the pager so far.
+    pub(crate) fn record_pager(&self, pager: &QueryPager) {
+        self.record_tracing_ids(pager.tracing_ids());
+    }
+
+    fn record_tracing_ids(&self, tracing_ids: &[Uuid]) {
+        let mut paged_tracing_ids = self.paged_tracing_ids.lock().unwrap();
+        paged_tracing_ids.clear();
+        paged_tracing_ids.extend(tracing_ids.iter().map(|id| *id.as_bytes()));
+    }
+
+    pub(crate) fn set_history(&mut self, history: Arc<HistoryCollector>) {
+        self.history = Some(history);
+    }
+
+    /// Builds a snapshot of the execution info. For paged results, it covers the pages fetched so far.
+    pub(crate) fn execution_info(&self) -> BridgedExecutionInfo {
+        let history = self
+            .history
+            .as_ref()
+            .map(|history| history.clone_structured_history())
+            .unwrap_or_default();
+
+        let coordinator = self.coordinator.map(|(address, _)| address).or_else(|| {
+            history
+                .requests
+                .last()
+                .and_then(|request| coordinator(attempts(request)))
+        });
+
+        BridgedExecutionInfo {
+            coordinator: coordinator.map(|address| (address.ip().to_string(), address.port())),
+            shard: self.coordinator.and_then(|(_, shard)| shard),
+            attempts: attempt_infos(&history),
+            latency_us: latency_us(&history),
+            warnings: self.warnings.clone(),
+            // Paged results are traced page by page; the latest page's trace is the one reported.
+            tracing_id: self
+                .tracing_id
+                .or_else(|| self.paged_tracing_ids.lock().unwrap().last().copied()),
+        }
+    }
+}
+
+impl Debug for RequestDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestDetails")
+            .field("coordinator", &self.coordinator)
+            .field("warnings", &self.warnings)
+            .field("tracing_id", &self.tracing_id)
+            .field("paged_tracing_ids", &self.paged_tracing_ids)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Attempts of all requests, e.g. one request per page, in the order they were sent.
+fn attempt_infos(history: &StructuredHistory) -> Vec<AttemptInfo> {
+    let mut attempts: Vec<(&AttemptHistory, bool)> = history
+        .requests
+        .iter()
+        .flat_map(|request| {
+            let non_speculative = request
+                .non_speculative_fiber
+                .attempts
+                .iter()
+                .map(|attempt| (attempt, false));
+            let speculative = request
+                .speculative_fibers
+                .iter()
+                .flat_map(|fiber| &fiber.attempts)
+                .map(|attempt| (attempt, true));
+            non_speculative.chain(speculative)
+        })
+        .collect();
+    attempts.sort_by_key(|(attempt, _)| attempt.send_time);
+
+    attempts
+        .into_iter()
+        .map(|(attempt, is_speculative)| {
+            let (finish_time, error) = match &attempt.result {
+                Some(AttemptResult::Success(time)) => (Some(time), None),
+                Some(AttemptResult::Error(time, err)) => (Some(time), Some(err.to_string())),
+                None => (None, None),
+            };
+            AttemptInfo {
+                address: attempt.node_addr.ip().to_string(),
+                port: attempt.node_addr.port(),
+                is_speculative,
+                latency_us: finish_time
+                    .and_then(|time| (*time - attempt.send_time).num_microseconds())
+                    .unwrap_or(-1),
+                succeeded: matches!(attempt.result, Some(AttemptResult::Success(_))),
+                error,
+            }
+        })
+        .collect()
+}
+
+/// Time from sending the first request to receiving the result of the last one.
+fn latency_us(history: &StructuredHistory) -> Option<i64> {
+    let start = history.requests.first()?.start_time;
+    let end = match history.requests.last()?.result.as_ref()? {
+        RequestHistoryResult::Success(time) => *time,
+        RequestHistoryResult::Error(time, _) => *time,
+    };
+    (end - start).num_microseconds()
+}
+
+/// Execution info of the request that produced a RowSet, as seen when it was taken.
+#[derive(Debug)]
+pub struct BridgedExecutionInfo {
+    coordinator: Option<(String, u16)>,
+    shard: Option<u32>,
+    attempts: Vec<AttemptInfo>,
+    latency_us: Option<i64>,
+    warnings: Vec<String>,
+    tracing_id: Option<[u8; 16]>,
+}
+
+impl FFI for BridgedExecutionInfo {
+    type Origin = FromBox;
+}
+
+#[derive(Debug)]
+struct AttemptInfo {
+    address: String,
+    port: u16,
+    is_speculative: bool,
+    latency_us: i64,
+    succeeded: bool,
+    error: Option<String>,
+}
+
+/// A single attempt to send a request to a node.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedAttempt<'info> {
+    /// IP address of the node.
+    address: FFIStr<'info>,
+    port: u16,
+    /// Whether the attempt was sent by speculative execution.
+    is_speculative: bool,
+    /// Whether the node answered successfully. False also if no answer was awaited,
+    /// e.g. when another speculative attempt succeeded first.
+    succeeded: bool,
+    /// Time until the node answered, in microseconds, or -1 if it did not.
+    latency_us: i64,
+    /// Error returned by the attempt, or empty.
+    error: FFIStr<'info>,
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_free(info_ptr: BridgedOwnedExclusivePtr<BridgedExecutionInfo>) {
+    BoxFFI::free(info_ptr);
+    tracing::trace!("[FFI] Execution info freed");
+}
+
+/// Writes the address of the node which served the request.
+/// Returns false if it is not known, e.g. if no request has been sent.
+/// The address is valid as long as the execution info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_coordinator<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedExecutionInfo>,
+    out_address: *mut FFIStr<'info>,
+    out_port: *mut u16,
+) -> bool {
+    if out_address.is_null() || out_port.is_null() {
+        panic!("Null pointer passed to execution_info_get_coordinator");
+    }
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_coordinator");
+    };
+    let Some((address, port)) = info.coordinator.as_ref() else {
+        return false;
+    };
+    unsafe {
+        out_address.write(FFIStr::new(address));
+        out_port.write(*port);
+    }
+    true
+}
+
+/// Returns the shard of the coordinator which served the request, or -1 if it is not known.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_shard(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionInfo>,
+) -> i64 {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_shard");
+    };
+    info.shard.map_or(-1, i64::from)
+}
+
+/// Returns the total latency of the request in microseconds, or -1 if it has not completed.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_latency_us(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionInfo>,
+) -> i64 {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_latency_us");
+    };
+    info.latency_us.unwrap_or(-1)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_attempts_count(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionInfo>,
+) -> usize {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_attempts_count");
+    };
+    info.attempts.len()
+}
+
+/// Returns the attempt at `index`, in the order the attempts were sent.
+/// Its strings are valid as long as the execution info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_attempt<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedExecutionInfo>,
+    index: usize,
+) -> BridgedAttempt<'info> {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_attempt");
+    };
+    let Some(attempt) = info.attempts.get(index) else {
+        panic!("Attempt index out of range in execution_info_get_attempt");
+    };
+    BridgedAttempt {
+        address: FFIStr::new(&attempt.address),
+        port: attempt.port,
+        is_speculative: attempt.is_speculative,
+        succeeded: attempt.succeeded,
+        latency_us: attempt.latency_us,
+        error: FFIStr::new(attempt.error.as_deref().unwrap_or_default()),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_warnings_count(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionInfo>,
+) -> usize {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_warnings_count");
+    };
+    info.warnings.len()
+}
+
+/// Returns the server-side warning at `index`.
+/// The warning is valid as long as the execution info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_warning<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedExecutionInfo>,
+    index: usize,
+) -> FFIStr<'info> {
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_warning");
+    };
+    let Some(warning) = info.warnings.get(index) else {
+        panic!("Warning index out of range in execution_info_get_warning");
+    };
+    FFIStr::new(warning)
+}
+
+/// Writes the 16 bytes of the tracing session id (a UUID, in network byte order),
+/// if tracing was enabled for the request.
+/// Returns false otherwise.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_info_get_tracing_id(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionInfo>,
+    out_tracing_id: *mut [u8; 16],
+) -> bool {
+    if out_tracing_id.is_null() {
+        panic!("Null pointer passed to execution_info_get_tracing_id");
+    }
+    let Some(info) = BoxFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to execution_info_get_tracing_id");
+    };
+    let Some(tracing_id) = info.tracing_id else {
+        return false;
+    };
+    unsafe { out_tracing_id.write(tracing_id) };
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paged_results_report_the_latest_trace() {
+        let details = RequestDetails::default();
+        assert_eq!(details.execution_info().tracing_id, None);
+
+        let first = Uuid::from_u128(1);
+        let second = Uuid::from_u128(2);
+        details.record_tracing_ids(&[first]);
+        assert_eq!(details.execution_info().tracing_id, Some(*first.as_bytes()));
+
+        details.record_tracing_ids(&[first, second]);
+        assert_eq!(
+            details.execution_info().tracing_id,
+            Some(*second.as_bytes())
+        );
+    }
+
+    #[test]
+    fn paged_results_without_history_have_no_coordinator() {
+        let info = RequestDetails::default().execution_info();
+        assert!(info.coordinator.is_none());
+        assert!(info.attempts.is_empty());
+        assert_eq!(info.latency_us, None);
+    }
+}
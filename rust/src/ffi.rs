@@ -592,7 +592,7 @@ mod tests {
  * Compound FFI types with length - byte slices and strings.
  */
 
-/// Represents a byte slice passed over FFI from Rust to C#.
+/// Represents a byte slice passed over FFI between Rust and C#.
 /// SAFETY: `ptr` must be a valid pointer to a byte array of length `len`.
 #[repr(C)]
 pub struct FFIByteSlice<'a> {
@@ -611,6 +611,18 @@ impl<'a> FFIByteSlice<'a> {
         };
         FFIByteSlice { ptr, len: s.len() }
     }
+
+    /// Returns the bytes of a slice passed from C#.
+    /// A null pointer is treated as an empty slice.
+    ///
+    /// # Safety
+    /// The C# side must keep the memory valid and pinned for the lifetime `'a`.
+    pub(crate) unsafe fn as_slice(&self) -> &'a [u8] {
+        match self.ptr.to_raw() {
+            Some(ptr) if self.len > 0 => unsafe { std::slice::from_raw_parts(ptr, self.len) },
+            _ => &[],
+        }
+    }
 }
 
 /// Represents a string passed over FFI from Rust to C#.
@@ -626,4 +638,13 @@ impl<'a> FFIStr<'a> {
             slice: FFIByteSlice::new(s.as_ref().as_bytes()),
         }
     }
+
+    /// Returns the string, e.g. to inspect what Rust passes to C# in tests.
+    ///
+    /// # Safety
+    /// The string must still be alive.
+    #[cfg(test)]
+    pub(crate) unsafe fn as_str(&self) -> &'a str {
+        std::str::from_utf8(unsafe { self.slice.as_slice() }).unwrap()
+    }
 }
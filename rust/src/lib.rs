@@ -1,11 +1,25 @@
+mod authentication;
+mod batch;
+mod cluster_events;
+mod cluster_state;
 mod error_conversion;
+mod execution_info;
 pub mod ffi;
 mod logging;
 mod pre_serialized_values;
 mod prepared_statement;
+mod replicas;
+mod request_span;
 mod row_set;
+mod runtime;
+mod schema_metadata;
 mod session;
+mod session_config;
+mod span_export;
+mod statement_options;
 mod task;
+mod tls;
+mod tracing_info;
 
 use std::ffi::{CStr, c_char};
 use std::fmt::Debug;
@@ -39,3 +53,22 @@ impl<'a> CSharpStr<'a> {
         self.ptr.map(|ptr| unsafe { CStr::from_ptr(ptr.as_ptr()) })
     }
 }
+
+#[cfg(test)]
+impl<'a> CSharpStr<'a> {
+    /// Borrows a Rust string the way C# passes strings to FFI functions.
+    fn from_cstr(s: &'a CStr) -> Self {
+        FfiPtr {
+            ptr: NonNull::new(s.as_ptr().cast_mut()),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// A null string, as passed by C# for a `null` string.
+    fn null() -> Self {
+        FfiPtr {
+            ptr: None,
+            _phantom: PhantomData,
+        }
+    }
+}
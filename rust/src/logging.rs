@@ -1,14 +1,377 @@
+use std::fmt::Debug;
+use std::str::Utf8Error;
+use std::sync::OnceLock;
+
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, Layered, SubscriberExt};
+use tracing_subscriber::util::{SubscriberInitExt, TryInitError};
+use tracing_subscriber::{EnvFilter, Registry, reload};
+
+use crate::CSharpStr;
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FfiException};
+use crate::ffi::FFIStr;
+use crate::span_export;
+use crate::task::ExceptionConstructors;
+
+/// Handle to change the filter of the installed subscriber at runtime.
+/// Set once logging is initialized.
+static FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Builds the subscriber installed by `install`, with a reloadable `filter` in front of
+/// the `output` layer and of the OpenTelemetry layer, which passes spans to C#.
+fn subscriber<L>(
+    filter: EnvFilter,
+    output: L,
+) -> (
+    impl Subscriber + Send + Sync + 'static,
+    reload::Handle<EnvFilter, Registry>,
+)
+where
+    L: Layer<Layered<reload::Layer<EnvFilter, Registry>, Registry>> + Send + Sync + 'static,
+{
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    let spans = tracing_opentelemetry::layer()
+        .with_location(false)
+        .with_threads(false)
+        .with_target(false)
+        .with_tracked_inactivity(false)
+        .with_tracer(span_export::tracer());
+    let subscriber = tracing_subscriber::registry()
+        .with(filter)
+        .with(output)
+        .with(spans);
+    (subscriber, filter_handle)
+}
+
+/// Installs the global subscriber built by `subscriber`.
+fn install<L>(filter: EnvFilter, output: L) -> Result<(), LoggingError>
+where
+    L: Layer<Layered<reload::Layer<EnvFilter, Registry>, Registry>> + Send + Sync + 'static,
+{
+    let (subscriber, filter_handle) = subscriber(filter, output);
+    subscriber.try_init()?;
+    // Only the first successful `try_init` gets here, so the handle is never set twice.
+    let _ = FILTER.set(filter_handle);
+    Ok(())
+}
+
 /// Initialize logging for the Rust components.
 ///
+/// Unless C# initialized logging with `logging_init` before, logs are written to stdout,
+/// filtered by the `RUST_LOG` environment variable.
 /// Must be called at least once before any logging is performed;
 /// otherwise, no log output will be produced.
 /// Subsequent calls are no-ops.
 pub(crate) fn init_logging() {
-    static INIT: std::sync::Once = std::sync::Once::new();
-    INIT.call_once(|| {
-        tracing_subscriber::fmt::fmt()
-            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-            .without_time()
-            .init();
+    if FILTER.get().is_some() {
+        return;
+    }
+    let output = tracing_subscriber::fmt::layer().without_time();
+    // Fails only if another thread has just initialized logging.
+    let _ = install(EnvFilter::from_default_env(), output);
+}
+
+#[derive(Error, Debug)]
+pub(crate) enum LoggingError {
+    #[error("Logging is already initialized")]
+    AlreadyInitialized(#[from] TryInitError),
+
+    #[error("Logging is not initialized")]
+    NotInitialized,
+
+    #[error("Log filter must not be null")]
+    NullFilter,
+
+    #[error("Log filter is not valid UTF-8: {0}")]
+    NonUtf8Filter(#[from] Utf8Error),
+
+    #[error("Invalid log filter: {0}")]
+    InvalidFilter(#[from] tracing_subscriber::filter::ParseError),
+
+    #[error("Failed to change log filter: {0}")]
+    Reload(#[from] reload::Error),
+}
+
+impl ErrorToException for LoggingError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+/// Parses a filter passed from C#, or returns None if it is null.
+fn parse_filter(filter: CSharpStr<'_>) -> Result<Option<EnvFilter>, LoggingError> {
+    let Some(filter) = filter.as_cstr() else {
+        return Ok(None);
+    };
+    Ok(Some(EnvFilter::try_new(filter.to_str()?)?))
+}
+
+/// A structured field of a log event, other than its message, or an attribute of a span.
+#[repr(C)]
+pub struct BridgedLogField<'a> {
+    name: FFIStr<'a>,
+    value: FFIStr<'a>,
+}
+
+impl<'a> BridgedLogField<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a str) -> Self {
+        BridgedLogField {
+            name: FFIStr::new(name),
+            value: FFIStr::new(value),
+        }
+    }
+}
+
+/// Function pointer type to pass a log event to C#.
+///
+/// `level` is one of: 0 - trace, 1 - debug, 2 - info, 3 - warn, 4 - error,
+/// matching the C# `LogLevel` values.
+/// All strings and `fields` are only valid for the duration of the call.
+type LogCallback = unsafe extern "C" fn(
+    level: u8,
+    target: FFIStr<'_>,
+    message: FFIStr<'_>,
+    fields: *const BridgedLogField<'_>,
+    fields_count: usize,
+);
+
+/// Layer passing every event to C#.
+struct CSharpLogLayer {
+    callback: LogCallback,
+}
+
+impl<S: Subscriber> Layer<S> for CSharpLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut recorded = RecordedFields::default();
+        event.record(&mut recorded);
+
+        let fields: Vec<BridgedLogField> = recorded
+            .fields
+            .iter()
+            .map(|(name, value)| BridgedLogField::new(name, value))
+            .collect();
+        let metadata = event.metadata();
+
+        unsafe {
+            (self.callback)(
+                level_to_code(metadata.level()),
+                FFIStr::new(metadata.target()),
+                FFIStr::new(&recorded.message),
+                fields.as_ptr(),
+                fields.len(),
+            )
+        }
+    }
+}
+
+fn level_to_code(level: &Level) -> u8 {
+    match *level {
+        Level::TRACE => 0,
+        Level::DEBUG => 1,
+        Level::INFO => 2,
+        Level::WARN => 3,
+        // Level::ERROR
+        _ => 4,
+    }
+}
+
+/// Collects the message and the other fields of an event as strings.
+#[derive(Default)]
+struct RecordedFields {
+    message: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl RecordedFields {
+    fn record(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = value;
+        } else {
+            self.fields.push((field.name(), value));
+        }
+    }
+}
+
+impl Visit for RecordedFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+}
+
+/// Initializes logging, so that all Rust log events are passed to C# through `callback`.
+///
+/// Should be called before any other function, as events emitted before logging
+/// is initialized are lost. Once the Tokio runtime has started, logging is already
+/// initialized with the default stdout output and this returns an exception.
+/// `filter` uses the `RUST_LOG` syntax (e.g. `info,scylla=debug`); if null, `RUST_LOG` is used.
+///
+/// `callback` may be called from any thread, also concurrently, and must not throw.
+#[unsafe(no_mangle)]
+pub extern "C" fn logging_init(
+    callback: LogCallback,
+    filter: CSharpStr<'_>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let result = parse_filter(filter).and_then(|filter| {
+        let filter = filter.unwrap_or_else(EnvFilter::from_default_env);
+        install(filter, CSharpLogLayer { callback })
     });
+
+    match result {
+        Ok(()) => FfiException::ok(),
+        Err(err) => FfiException::from_error(err, constructors),
+    }
+}
+
+/// Changes the filter of log events, e.g. when the C# log level is reconfigured.
+/// `filter` uses the `RUST_LOG` syntax (e.g. `info,scylla=debug`).
+#[unsafe(no_mangle)]
+pub extern "C" fn logging_set_filter(
+    filter: CSharpStr<'_>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let result = parse_filter(filter).and_then(|filter| {
+        let filter = filter.ok_or(LoggingError::NullFilter)?;
+        let handle = FILTER.get().ok_or(LoggingError::NotInitialized)?;
+        let description = filter.to_string();
+        handle.reload(filter)?;
+        Ok(description)
+    });
+
+    match result {
+        Ok(filter) => {
+            tracing::debug!("[FFI] Log filter changed to {}", filter);
+            FfiException::ok()
+        }
+        Err(err) => FfiException::from_error(err, constructors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::error_conversion::testing::{CONSTRUCTORS, TestException};
+    use crate::request_span::{RequestSpan, TraceParent, session_operation_span};
+    use crate::span_export::span_export_init;
+
+    unsafe extern "C" fn ignore_event(
+        _level: u8,
+        _target: FFIStr<'_>,
+        _message: FFIStr<'_>,
+        _fields: *const BridgedLogField<'_>,
+        _fields_count: usize,
+    ) {
+    }
+
+    fn error_message(result: FfiException) -> String {
+        let exception = TestException::from_ffi(result).expect("Expected an exception");
+        assert_eq!(exception.kind, "RustException");
+        exception.message().to_owned()
+    }
+
+    // Invalid filters are rejected before the global subscriber is touched,
+    // so these tests do not depend on whether logging is initialized.
+    const NON_UTF8_FILTER: &CStr = c"scylla=\xff";
+
+    #[test]
+    fn invalid_filters_are_rejected_on_init() {
+        let message = error_message(logging_init(
+            ignore_event,
+            CSharpStr::from_cstr(NON_UTF8_FILTER),
+            &CONSTRUCTORS,
+        ));
+        assert!(message.contains("not valid UTF-8"), "{message}");
+
+        let message = error_message(logging_init(
+            ignore_event,
+            CSharpStr::from_cstr(c"scylla=notalevel"),
+            &CONSTRUCTORS,
+        ));
+        assert!(message.contains("Invalid log filter"), "{message}");
+    }
+
+    #[test]
+    fn invalid_filters_are_rejected_on_change() {
+        let message = error_message(logging_set_filter(CSharpStr::null(), &CONSTRUCTORS));
+        assert!(message.contains("must not be null"), "{message}");
+
+        let message = error_message(logging_set_filter(
+            CSharpStr::from_cstr(NON_UTF8_FILTER),
+            &CONSTRUCTORS,
+        ));
+        assert!(message.contains("not valid UTF-8"), "{message}");
+    }
+
+    /// Name, parent and attributes of the spans passed to `record_span`.
+    type RecordedSpan = (String, String, Vec<(String, String)>);
+    static SPANS: Mutex<Vec<RecordedSpan>> = Mutex::new(Vec::new());
+
+    unsafe extern "C" fn record_span(
+        name: FFIStr<'_>,
+        parent: FFIStr<'_>,
+        _start_us: i64,
+        _end_us: i64,
+        _is_error: bool,
+        attributes: *const BridgedLogField<'_>,
+        attributes_count: usize,
+    ) {
+        // SAFETY: Rust passes a valid array of fields, which outlive the call like the strings.
+        let span = unsafe {
+            let attributes = std::slice::from_raw_parts(attributes, attributes_count)
+                .iter()
+                .map(|field| {
+                    (
+                        field.name.as_str().to_owned(),
+                        field.value.as_str().to_owned(),
+                    )
+                })
+                .collect();
+            (
+                name.as_str().to_owned(),
+                parent.as_str().to_owned(),
+                attributes,
+            )
+        };
+        SPANS.lock().unwrap().push(span);
+    }
+
+    // Uses the subscriber installed by `logging_init` without installing it globally,
+    // which can be done only once per process.
+    #[test]
+    fn spans_with_csharp_parent_are_passed_to_csharp() {
+        const TRACEPARENT: &CStr = c"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        span_export_init(record_span);
+        let (subscriber, _) = subscriber(
+            EnvFilter::new("info"),
+            CSharpLogLayer {
+                callback: ignore_event,
+            },
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            let parent = TraceParent::from_csharp(CSharpStr::from_cstr(TRACEPARENT));
+            drop(RequestSpan::new("query", None, false, None, None, parent));
+            // Spans without a C# parent are not sampled.
+            drop(session_operation_span("prepare", None));
+        });
+
+        let spans = SPANS.lock().unwrap();
+        let passed: Vec<&RecordedSpan> = spans
+            .iter()
+            .filter(|(_, parent, _)| parent.as_str() == TRACEPARENT.to_str().unwrap())
+            .collect();
+        assert_eq!(passed.len(), 1, "{spans:?}");
+        let (name, _, attributes) = passed[0];
+        assert_eq!(name, "query");
+        assert!(attributes.contains(&("db.operation".to_owned(), "query".to_owned())));
+    }
 }
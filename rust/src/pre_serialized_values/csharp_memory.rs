@@ -21,6 +21,19 @@ impl CsharpValuePtr {
     }
 }
 
+#[cfg(test)]
+impl CsharpValuePtr {
+    /// Points at a Rust buffer, the way C# passes a pinned buffer.
+    pub(crate) fn from_slice(slice: &'static [u8]) -> Self {
+        Self {
+            inner: FfiPtr {
+                ptr: std::ptr::NonNull::new(slice.as_ptr().cast_mut().cast()),
+                _phantom: std::marker::PhantomData,
+            },
+        }
+    }
+}
+
 /// A pre-serialized value from C#, holding the buffer pointer and length.
 /// The C# side is responsible for keeping the memory valid.
 pub struct CsharpSerializedValue {
@@ -1,10 +1,11 @@
 use super::csharp_memory::CsharpSerializedValue;
-use crate::ffi::{FFI, FromBox};
+use crate::ffi::{BoxFFI, BridgedOwnedExclusivePtr, FFI, FromBox};
 use scylla_cql::frame::response::result::{ColumnType, NativeType};
+use scylla_cql::frame::types::RawValue;
 use scylla_cql::serialize::SerializationError;
-use scylla_cql::serialize::row::SerializedValues;
+use scylla_cql::serialize::row::{RowSerializationContext, SerializeRow, SerializedValues};
 use scylla_cql::serialize::value::SerializeValue;
-use scylla_cql::serialize::writers::CellWriter;
+use scylla_cql::serialize::writers::{CellWriter, RowWriter};
 
 /// A single pre-serialized cell: either a C#-backed value, or a
 /// logical null/unset marker.
@@ -46,6 +47,16 @@ impl PreSerializedValues {
         }
     }
 
+    /// Takes ownership of values passed from C#.
+    /// A null pointer means that the statement has no values.
+    pub(crate) fn from_nullable_ptr(
+        values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    ) -> Self {
+        BoxFFI::from_ptr(values_ptr)
+            .map(|values| *values)
+            .unwrap_or_else(Self::new)
+    }
+
     /// Consume and return the inner SerializedValues.
     pub fn into_serialized_values(self) -> SerializedValues {
         self.serialized_values
@@ -81,6 +92,40 @@ impl FFI for PreSerializedValues {
     type Origin = FromBox;
 }
 
+/// Writes the already serialized cells as they are.
+/// This allows passing pre-serialized values where the Rust driver expects
+/// `SerializeRow`, e.g. as values of batch statements.
+/// Column types are not checked - C# serializes values according to the statement metadata.
+impl SerializeRow for PreSerializedValues {
+    fn serialize(
+        &self,
+        _ctx: &RowSerializationContext<'_>,
+        writer: &mut RowWriter,
+    ) -> Result<(), SerializationError> {
+        for value in self.serialized_values.iter() {
+            let cell_writer = writer.make_cell_writer();
+            match value {
+                RawValue::Value(bytes) => {
+                    cell_writer
+                        .set_value(bytes)
+                        .map_err(SerializationError::new)?;
+                }
+                RawValue::Null => {
+                    cell_writer.set_null();
+                }
+                RawValue::Unset => {
+                    cell_writer.set_unset();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.serialized_values.is_empty()
+    }
+}
+
 // Single dummy ColumnType value.
 static DUMMY_COLUMN_TYPE: ColumnType<'static> = ColumnType::Native(NativeType::Blob);
 
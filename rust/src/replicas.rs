@@ -0,0 +1,346 @@
+use std::str::Utf8Error;
+use std::sync::Arc;
+
+use scylla::cluster::Node;
+use scylla::routing::partitioner::{Murmur3Partitioner, Partitioner};
+use scylla::routing::{Shard, Token};
+use thiserror::Error;
+
+use crate::CSharpStr;
+use crate::cluster_state::BridgedClusterState;
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FfiException};
+use crate::ffi::{
+    ArcFFI, BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, FFI, FFIStr, FromBox,
+};
+use crate::pre_serialized_values::pre_serialized_values::PreSerializedValues;
+use crate::prepared_statement::BridgedPreparedStatement;
+use crate::task::ExceptionConstructors;
+
+/// Replicas owning a partition, as computed from a cluster state snapshot.
+#[derive(Debug)]
+pub struct BridgedReplicas {
+    token: Option<Token>,
+    replicas: Vec<ReplicaInfo>,
+}
+
+impl FFI for BridgedReplicas {
+    type Origin = FromBox;
+}
+
+#[derive(Debug)]
+struct ReplicaInfo {
+    address: String,
+    port: u16,
+    host_id: [u8; 16],
+    shard: Option<Shard>,
+}
+
+impl BridgedReplicas {
+    fn new(token: Option<Token>, replicas: Vec<(Arc<Node>, Shard)>) -> Self {
+        let replicas = replicas
+            .into_iter()
+            .map(|(node, shard)| ReplicaInfo {
+                address: node.address.ip().to_string(),
+                port: node.address.port(),
+                host_id: *node.host_id.as_bytes(),
+                // Only sharded (Scylla) nodes have a meaningful shard.
+                shard: node.sharder().map(|_| shard),
+            })
+            .collect();
+        BridgedReplicas { token, replicas }
+    }
+}
+
+/// A single replica of a partition.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedReplica<'replicas> {
+    /// IP address of the node.
+    address: FFIStr<'replicas>,
+    port: u16,
+    /// The 16 bytes of the host id (a UUID, in network byte order).
+    host_id: [u8; 16],
+    /// Shard owning the partition on the node, or -1 if the node is not sharded.
+    shard: i64,
+}
+
+/// Errors of the keyspace and table names passed from C#.
+#[derive(Error, Debug)]
+pub(crate) enum ReplicasNameError {
+    #[error("The {0} name is null")]
+    Null(&'static str),
+
+    #[error("The {0} name is not valid UTF-8: {1}")]
+    InvalidUtf8(&'static str, Utf8Error),
+}
+
+impl ErrorToException for ReplicasNameError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+/// Reads the name of a keyspace or table (`what`), or None if it is null.
+fn optional_name<'a>(
+    name: &'a CSharpStr<'_>,
+    what: &'static str,
+) -> Result<Option<&'a str>, ReplicasNameError> {
+    name.as_cstr()
+        .map(|name| {
+            name.to_str()
+                .map_err(|err| ReplicasNameError::InvalidUtf8(what, err))
+        })
+        .transpose()
+}
+
+/// Reads the name of a keyspace or table (`what`), which must not be null.
+fn required_name<'a>(
+    name: &'a CSharpStr<'_>,
+    what: &'static str,
+) -> Result<&'a str, ReplicasNameError> {
+    optional_name(name, what)?.ok_or(ReplicasNameError::Null(what))
+}
+
+fn write_replicas(
+    out_replicas: *mut BridgedOwnedExclusivePtr<BridgedReplicas>,
+    replicas: BridgedReplicas,
+) -> FfiException {
+    tracing::trace!("[FFI] Found {} replicas", replicas.replicas.len());
+    unsafe { out_replicas.write(BoxFFI::into_ptr(Box::new(replicas))) };
+    FfiException::ok()
+}
+
+/// Computes the token of a partition key with the partitioner of `keyspace.table`,
+/// and writes the replicas owning it, in the order preferred by the replication strategy.
+///
+/// `partition_key_ptr` holds the values of the partition key columns, in their order in the key.
+/// Takes ownership of the values, so the C# side must not free them afterwards.
+/// The replicas must be freed with `replicas_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_replicas(
+    state_ptr: BridgedBorrowedSharedPtr<'_, BridgedClusterState>,
+    keyspace: CSharpStr<'_>,
+    table: CSharpStr<'_>,
+    partition_key_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    out_replicas: *mut BridgedOwnedExclusivePtr<BridgedReplicas>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    // Take ownership of the values first, so that they are not leaked if anything else panics.
+    let partition_key =
+        PreSerializedValues::from_nullable_ptr(partition_key_ptr).into_serialized_values();
+    if out_replicas.is_null() {
+        panic!("Null pointer passed to cluster_state_get_replicas");
+    }
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_replicas");
+    };
+    let (keyspace, table) = match (
+        required_name(&keyspace, "keyspace"),
+        required_name(&table, "table"),
+    ) {
+        (Ok(keyspace), Ok(table)) => (keyspace, table),
+        (Err(err), _) | (_, Err(err)) => return FfiException::from_error(err, constructors),
+    };
+    let cluster_state = state.cluster_state();
+
+    let token = match cluster_state.compute_token(keyspace, table, &partition_key) {
+        Ok(token) => token,
+        Err(err) => return FfiException::from_error(err, constructors),
+    };
+    let replicas = cluster_state.get_token_endpoints(keyspace, table, token);
+    write_replicas(out_replicas, BridgedReplicas::new(Some(token), replicas))
+}
+
+/// Writes the replicas owning the partition which the prepared statement would access
+/// when executed with `values_ptr`, in the order preferred by the replication strategy.
+///
+/// If the token cannot be computed, because the statement does not have its partition key
+/// bound, there are no replicas.
+/// Takes ownership of the values, so the C# side must not free them afterwards.
+/// The replicas must be freed with `replicas_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_replicas_for_bound(
+    state_ptr: BridgedBorrowedSharedPtr<'_, BridgedClusterState>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    out_replicas: *mut BridgedOwnedExclusivePtr<BridgedReplicas>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let values = PreSerializedValues::from_nullable_ptr(values_ptr);
+    if out_replicas.is_null() {
+        panic!("Null pointer passed to cluster_state_get_replicas_for_bound");
+    }
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_replicas_for_bound");
+    };
+    let Some(prepared) = ArcFFI::as_ref(prepared_statement_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_replicas_for_bound");
+    };
+    let prepared = &prepared.inner;
+
+    let token = match prepared.calculate_token(&values) {
+        Ok(token) => token,
+        Err(err) => return FfiException::from_error(err, constructors),
+    };
+    let replicas = match (
+        token,
+        prepared.get_keyspace_name(),
+        prepared.get_table_name(),
+    ) {
+        (Some(token), Some(keyspace), Some(table)) => state
+            .cluster_state()
+            .get_token_endpoints(keyspace, table, token),
+        _ => Vec::new(),
+    };
+    write_replicas(out_replicas, BridgedReplicas::new(token, replicas))
+}
+
+/// Hashes an already serialized routing key with the Murmur3 partitioner, and writes the replicas
+/// owning the token in `keyspace.table`, in the order preferred by the replication strategy.
+///
+/// The routing key is the value of the partition key if it has a single column, or the composite
+/// encoding of its columns otherwise, as built by the C# driver for statements.
+/// If `keyspace` is null, only the node owning the token on the ring is written.
+/// `table` only matters for keyspaces using tablets, whose replicas are placed per table.
+/// If it is null, the replicas are computed from the token ring, as for other keyspaces,
+/// which for tablet keyspaces may not be the nodes holding the partition.
+/// The replicas must be freed with `replicas_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_replicas_for_routing_key(
+    state_ptr: BridgedBorrowedSharedPtr<'_, BridgedClusterState>,
+    keyspace: CSharpStr<'_>,
+    table: CSharpStr<'_>,
+    routing_key: *const u8,
+    routing_key_len: usize,
+    out_replicas: *mut BridgedOwnedExclusivePtr<BridgedReplicas>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    if out_replicas.is_null() || (routing_key.is_null() && routing_key_len > 0) {
+        panic!("Null pointer passed to cluster_state_get_replicas_for_routing_key");
+    }
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_replicas_for_routing_key");
+    };
+    let routing_key = if routing_key_len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(routing_key, routing_key_len) }
+    };
+    let (keyspace, table) = match (
+        optional_name(&keyspace, "keyspace"),
+        optional_name(&table, "table"),
+    ) {
+        (Ok(keyspace), Ok(table)) => (keyspace, table),
+        (Err(err), _) | (_, Err(err)) => return FfiException::from_error(err, constructors),
+    };
+    let token = Murmur3Partitioner.hash_one(routing_key);
+    let cluster_state = state.cluster_state();
+
+    let replicas = match keyspace {
+        // No table has tablets under an empty name, so the token ring decides.
+        Some(keyspace) => {
+            cluster_state.get_token_endpoints(keyspace, table.unwrap_or_default(), token)
+        }
+        None => cluster_state
+            .replica_locator()
+            .ring()
+            .ring_range(token)
+            .next()
+            .map(|node| {
+                let shard = node.sharder().map_or(0, |sharder| sharder.shard_of(token));
+                (Arc::clone(node), shard)
+            })
+            .into_iter()
+            .collect(),
+    };
+    write_replicas(out_replicas, BridgedReplicas::new(Some(token), replicas))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn replicas_free(replicas_ptr: BridgedOwnedExclusivePtr<BridgedReplicas>) {
+    BoxFFI::free(replicas_ptr);
+    tracing::trace!("[FFI] Replicas freed");
+}
+
+/// Writes the token of the partition.
+/// Returns false if it could not be computed.
+#[unsafe(no_mangle)]
+pub extern "C" fn replicas_get_token(
+    replicas_ptr: BridgedBorrowedSharedPtr<'_, BridgedReplicas>,
+    out_token: *mut i64,
+) -> bool {
+    if out_token.is_null() {
+        panic!("Null pointer passed to replicas_get_token");
+    }
+    let Some(replicas) = BoxFFI::as_ref(replicas_ptr) else {
+        panic!("Null pointer passed to replicas_get_token");
+    };
+    let Some(token) = replicas.token else {
+        return false;
+    };
+    unsafe { out_token.write(token.value()) };
+    true
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn replicas_get_count(
+    replicas_ptr: BridgedBorrowedSharedPtr<'_, BridgedReplicas>,
+) -> usize {
+    let Some(replicas) = BoxFFI::as_ref(replicas_ptr) else {
+        panic!("Null pointer passed to replicas_get_count");
+    };
+    replicas.replicas.len()
+}
+
+/// Returns the replica at `index`.
+/// Its address is valid as long as the replicas are alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn replicas_get<'replicas>(
+    replicas_ptr: BridgedBorrowedSharedPtr<'replicas, BridgedReplicas>,
+    index: usize,
+) -> BridgedReplica<'replicas> {
+    let Some(replicas) = BoxFFI::as_ref(replicas_ptr) else {
+        panic!("Null pointer passed to replicas_get");
+    };
+    let Some(replica) = replicas.replicas.get(index) else {
+        panic!("Replica index out of range in replicas_get");
+    };
+    BridgedReplica {
+        address: FFIStr::new(&replica.address),
+        port: replica.port,
+        host_id: replica.host_id,
+        shard: replica.shard.map_or(-1, i64::from),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    #[test]
+    fn null_names_are_optional_or_rejected() {
+        let null = CSharpStr::null();
+        assert!(optional_name(&null, "table").unwrap().is_none());
+        assert!(matches!(
+            required_name(&null, "keyspace"),
+            Err(ReplicasNameError::Null("keyspace"))
+        ));
+    }
+
+    #[test]
+    fn names_which_are_not_utf8_are_rejected() {
+        let invalid = CStr::from_bytes_with_nul(b"ks\xff\0").unwrap();
+        assert!(matches!(
+            required_name(&CSharpStr::from_cstr(invalid), "keyspace"),
+            Err(ReplicasNameError::InvalidUtf8("keyspace", _))
+        ));
+
+        let valid = CStr::from_bytes_with_nul(b"ks\0").unwrap();
+        assert_eq!(
+            required_name(&CSharpStr::from_cstr(valid), "keyspace").unwrap(),
+            "ks"
+        );
+    }
+}
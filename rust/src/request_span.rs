@@ -0,0 +1,295 @@
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use opentelemetry::Context;
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use scylla::client::session::Session;
+use scylla::observability::history::{
+    AttemptHistory, AttemptResult, HistoryCollector, HistoryListener, RequestHistory,
+    RequestHistoryResult, StructuredHistory,
+};
+use scylla::statement::Consistency;
+use tracing::Span;
+use tracing::field::Empty;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::CSharpStr;
+use crate::error_conversion::FailedRequest;
+
+/// W3C trace context of the C# `Activity` that issued a request,
+/// parsed from its `traceparent` (`00-<trace-id>-<parent-id>-<trace-flags>`).
+#[derive(Debug, Clone)]
+pub(crate) struct TraceParent {
+    span_context: SpanContext,
+}
+
+impl TraceParent {
+    /// Parses the `traceparent` passed from C#.
+    /// Returns None if it is null or malformed, e.g. if the C# `Activity` does not use W3C ids.
+    pub(crate) fn from_csharp(traceparent: CSharpStr<'_>) -> Option<Self> {
+        let traceparent = traceparent.as_cstr()?.to_str().ok()?;
+        Self::parse(traceparent)
+    }
+
+    fn parse(traceparent: &str) -> Option<Self> {
+        let mut parts = traceparent.split('-');
+        let (version, trace_id, parent_span_id, flags) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || version != "00" {
+            return None;
+        }
+
+        let is_hex_id = |id: &str, len: usize| {
+            id.len() == len
+                && id.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+                && id.bytes().any(|b| b != b'0')
+        };
+        if !is_hex_id(trace_id, 32) || !is_hex_id(parent_span_id, 16) || flags.len() != 2 {
+            return None;
+        }
+        let flags = u8::from_str_radix(flags, 16).ok()?;
+
+        Some(TraceParent {
+            span_context: SpanContext::new(
+                TraceId::from_hex(trace_id).ok()?,
+                SpanId::from_hex(parent_span_id).ok()?,
+                TraceFlags::new(flags) & TraceFlags::SAMPLED,
+                true,
+                TraceState::default(),
+            ),
+        })
+    }
+
+    /// Makes the C# `Activity` the parent of `span`, so that the exported span
+    /// belongs to the trace of the C# application.
+    fn set_as_parent_of(&self, span: &Span) {
+        let parent = Context::new().with_remote_span_context(self.span_context.clone());
+        // The OpenTelemetry layer is installed with logging, so this fails only before logging
+        // is initialized, when spans are not recorded at all.
+        let _ = span.set_parent(parent);
+    }
+}
+
+/// Creates the span of a session operation that is not a request, e.g. connecting or preparing.
+pub(crate) fn session_operation_span(
+    operation: &'static str,
+    trace_parent: Option<TraceParent>,
+) -> Span {
+    let span = tracing::info_span!(
+        "cassandra_session",
+        otel.name = operation,
+        otel.kind = "client",
+        db.system = "cassandra",
+        db.operation = operation,
+    );
+    if let Some(trace_parent) = trace_parent {
+        trace_parent.set_as_parent_of(&span);
+    }
+    span
+}
+
+/// `tracing` span of a single bridged request.
+///
+/// Fields follow the OpenTelemetry semantic conventions for Cassandra client calls
+/// (see `proposals/open-telemetry/tracing-rfc.md`), so that the span can be exported as is.
+/// The C# trace context is set as the remote parent of the span, so that `span_export`
+/// passes it to C# as a child of the C# `Activity` that issued the request.
+///
+/// Coordinator, attempt count and page count are taken from the request history,
+/// which is recorded by the listener returned from `history_listener`. They are recorded
+/// when this is dropped, so for paged results it should be kept alive until all pages are fetched.
+pub(crate) struct RequestSpan {
+    span: Span,
+    history: Arc<HistoryCollector>,
+}
+
+impl RequestSpan {
+    /// Creates the span of `operation`, e.g. `query` or `batch`.
+    ///
+    /// `statement` is recorded only if the session was configured to include statements in spans,
+    /// as it may contain sensitive data. The keyspace and the default consistency are taken
+    /// from `session`, if given.
+    pub(crate) fn new(
+        operation: &'static str,
+        session: Option<&Session>,
+        include_statement: bool,
+        statement: Option<&str>,
+        consistency: Option<Consistency>,
+        trace_parent: Option<TraceParent>,
+    ) -> Self {
+        let keyspace = session.and_then(|session| session.get_keyspace());
+        let name = match &keyspace {
+            Some(keyspace) => format!("{operation} {keyspace}"),
+            None => operation.to_owned(),
+        };
+
+        let span = tracing::info_span!(
+            "cassandra_request",
+            otel.name = %name,
+            otel.kind = "client",
+            otel.status_code = Empty,
+            db.system = "cassandra",
+            db.operation = operation,
+            db.name = Empty,
+            db.statement = Empty,
+            db.cassandra.consistency_level = Empty,
+            db.cassandra.attempt_count = Empty,
+            db.cassandra.page_count = Empty,
+            server.address = Empty,
+            server.port = Empty,
+        );
+        if let Some(trace_parent) = trace_parent {
+            trace_parent.set_as_parent_of(&span);
+        }
+
+        if let Some(keyspace) = keyspace {
+            span.record("db.name", keyspace.as_str());
+        }
+        if let Some(statement) = statement.filter(|_| include_statement) {
+            span.record("db.statement", statement);
+        }
+        let consistency = consistency.or_else(|| {
+            session.map(|session| {
+                session
+                    .get_default_execution_profile_handle()
+                    .access()
+                    .get_consistency()
+            })
+        });
+        if let Some(consistency) = consistency {
+            span.record(
+                "db.cassandra.consistency_level",
+                tracing::field::debug(consistency),
+            );
+        }
+        RequestSpan {
+            span,
+            history: Arc::new(HistoryCollector::new()),
+        }
+    }
+
+    pub(crate) fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Listener to be set on the executed statement, recording its request history.
+    pub(crate) fn history_listener(&self) -> Arc<dyn HistoryListener> {
+        self.history.clone()
+    }
+
+    /// The request history recorded by `history_listener`.
+    pub(crate) fn history(&self) -> Arc<HistoryCollector> {
+        self.history.clone()
+    }
+
+    /// Attaches the attempts of the last request in the history, e.g. of the page
+    /// that failed, to the error of the request.
+    pub(crate) fn failed<E>(&self, error: E) -> FailedRequest<E> {
+        FailedRequest::new(error, &self.history.clone_structured_history())
+    }
+
+    fn record_history(&self, history: &StructuredHistory) {
+        // Prepare and USE requests are not recorded in the history.
+        let Some(last_request) = history.requests.last() else {
+            return;
+        };
+
+        let attempt_count = history.requests.iter().flat_map(attempts).count();
+        self.span
+            .record("db.cassandra.attempt_count", attempt_count);
+        self.span
+            .record("db.cassandra.page_count", history.requests.len());
+
+        if let Some(coordinator) = coordinator(attempts(last_request)) {
+            self.span
+                .record("server.address", tracing::field::display(coordinator.ip()));
+            self.span.record("server.port", coordinator.port());
+        }
+
+        if let Some(RequestHistoryResult::Error(..)) = last_request.result {
+            self.span.record("otel.status_code", "ERROR");
+        }
+    }
+}
+
+/// All attempts of the request, including speculative ones.
+pub(crate) fn attempts(request: &RequestHistory) -> impl Iterator<Item = &AttemptHistory> {
+    std::iter::once(&request.non_speculative_fiber)
+        .chain(&request.speculative_fibers)
+        .flat_map(|fiber| &fiber.attempts)
+}
+
+/// Address of the node which served the request: the one that answered successfully,
+/// or the last one tried if none did.
+pub(crate) fn coordinator<'a>(
+    attempts: impl Iterator<Item = &'a AttemptHistory>,
+) -> Option<SocketAddr> {
+    let mut last = None;
+    for attempt in attempts {
+        if let Some(AttemptResult::Success(_)) = attempt.result {
+            return Some(attempt.node_addr);
+        }
+        last = Some(attempt.node_addr);
+    }
+    last
+}
+
+impl Drop for RequestSpan {
+    fn drop(&mut self) {
+        self.record_history(&self.history.clone_structured_history());
+    }
+}
+
+impl Debug for RequestSpan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestSpan")
+            .field("span", &self.span)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    #[test]
+    fn traceparent_is_parsed_into_remote_span_context() {
+        let parent = TraceParent::parse(TRACEPARENT).unwrap().span_context;
+        assert_eq!(
+            parent.trace_id(),
+            TraceId::from_hex("0af7651916cd43dd8448eb211c80319c").unwrap()
+        );
+        assert_eq!(
+            parent.span_id(),
+            SpanId::from_hex("b7ad6b7169203331").unwrap()
+        );
+        assert!(parent.is_sampled());
+        assert!(parent.is_remote());
+
+        let unsampled = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-00";
+        assert!(
+            !TraceParent::parse(unsampled)
+                .unwrap()
+                .span_context
+                .is_sampled()
+        );
+    }
+
+    #[test]
+    fn malformed_traceparents_are_ignored() {
+        for traceparent in [
+            "01-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+            "00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-00",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-zz",
+        ] {
+            assert!(TraceParent::parse(traceparent).is_none(), "{traceparent}");
+        }
+    }
+}
@@ -1,37 +1,463 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use futures::FutureExt;
 use scylla::client::pager::QueryPager;
 use scylla::cluster::metadata::CollectionType;
-use scylla::frame::response::result::{ColumnType, NativeType};
+use scylla::deserialize::row::{ColumnIterator, RawColumn};
+use scylla::errors::{DeserializationError, IntoRowsResultError, NextRowError, RowsError};
+use scylla::frame::response::result::{ColumnSpec, ColumnType, NativeType};
+use scylla::observability::history::HistoryCollector;
+use scylla::response::PagingState;
+use scylla::response::query_result::{ColumnSpecs, QueryResult, QueryRowsResult};
+use thiserror::Error;
 
 use crate::FfiPtr;
-use crate::error_conversion::FfiException;
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FailedRequest, FfiException};
+use crate::execution_info::{BridgedExecutionInfo, RequestDetails};
 use crate::ffi::{
-    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIByteSlice, FFIStr, FromArc,
-    FromRef, RefFFI,
+    ArcFFI, BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, BridgedOwnedSharedPtr, FFI,
+    FFIByteSlice, FFIStr, FromArc, FromBox, FromRef, RefFFI,
 };
+use crate::request_span::RequestSpan;
+use crate::runtime::RuntimeShutDownError;
+use crate::session::EmptyBridgedResult;
 use crate::task::BridgedFuture;
-use crate::task::ExceptionConstructors;
+use crate::task::{BridgedTaskHandle, ExceptionConstructors, Tcb};
 
-// TO DO: Don't use mock RowSet - remove Option<> from the pager field
+// TO DO: Don't use mock RowSet - remove Option<> from the source field
 #[derive(Debug)]
 pub(crate) struct RowSet {
     // FIXME: consider if this Mutex is necessary. Perhaps BoxFFI is a better fit?
     //
     // Rust explanation:
     // This Mutex is here because QueryPager's next_column_iterator takes &mut self,
-    // and we need interior mutability to call it from row_set_next_row.
+    // and we need interior mutability to call it from row_set_next_page.
+    // It is an async Mutex, because row_set_fetch_next_page holds it while awaiting the next page.
+    // Synchronous functions must use `blocking_lock`, which is fine as they are only called from C# threads.
     // C# explanation:
-    // This Mutex is here because we need to mutate the pager when fetching the next row,
-    // and it's possible that C# code will call row_set_next_row concurrently,
+    // This Mutex is here because we need to mutate the pager when fetching the next page,
+    // and it's possible that C# code will call row_set_next_page concurrently,
     // because RowSet claims it supports parallel enumeration, and does not enforce any locking
     // on its own.
-    pub(crate) pager: std::sync::Mutex<Option<QueryPager>>,
+    source: tokio::sync::Mutex<Option<RowSource>>,
+    // Paging state to fetch the page following this RowSet's rows.
+    // Set only for RowSets of a single page that was fetched manually.
+    paging_state: Option<PagingState>,
+    // Span of the request that produced the rows. Kept until the RowSet is freed,
+    // so that the span covers fetching all the pages.
+    request_span: Option<RequestSpan>,
+    // Details of the request that produced the rows, backing the execution info.
+    request_details: RequestDetails,
 }
 
 impl RowSet {
     // Creates an empty RowSet with no pager (zero rows, zero columns).
     pub(crate) fn empty() -> Self {
         RowSet {
-            pager: std::sync::Mutex::new(None),
+            source: tokio::sync::Mutex::new(None),
+            paging_state: None,
+            request_span: None,
+            request_details: RequestDetails::default(),
+        }
+    }
+
+    /// Creates a RowSet fetching its rows page by page.
+    pub(crate) fn from_pager(pager: QueryPager) -> Self {
+        let columns = ExpectedColumns::new(pager.column_specs().iter());
+        // The pager has already received the first page.
+        let request_details = RequestDetails::default();
+        request_details.record_pager(&pager);
+        RowSet {
+            source: tokio::sync::Mutex::new(Some(RowSource::Pager {
+                pager,
+                columns,
+                buffered: VecDeque::new(),
+            })),
+            paging_state: None,
+            request_span: None,
+            request_details,
+        }
+    }
+
+    /// Attaches the span of the request, which is closed when the RowSet is freed.
+    /// Its request history also backs the execution info of the RowSet.
+    pub(crate) fn with_request_span(mut self, request_span: RequestSpan) -> Self {
+        self.request_details.set_history(request_span.history());
+        self.request_span = Some(request_span);
+        self
+    }
+
+    /// Attaches the attempts of the page that failed to `error`, as for the first page.
+    fn failed(&self, error: BufferRowsError) -> FailedRequest<BufferRowsError> {
+        match &self.request_span {
+            Some(request_span) => request_span.failed(error),
+            // Only RowSets of a single page, which is never fetched, have no span.
+            None => FailedRequest::without_history(error),
+        }
+    }
+
+    /// Attaches the request history backing the execution info of the RowSet.
+    pub(crate) fn with_history(mut self, history: Arc<HistoryCollector>) -> Self {
+        self.request_details.set_history(history);
+        self
+    }
+
+    /// Creates a RowSet from the result of a request returning a single page, e.g. a batch.
+    /// Results which are not rows (e.g. of an unconditional INSERT) give an empty RowSet.
+    /// `paging_state` is the state to fetch the following page, if there is one.
+    pub(crate) fn from_query_result(
+        result: QueryResult,
+        paging_state: Option<PagingState>,
+    ) -> Result<Self, BufferRowsError> {
+        let request_details = RequestDetails::from_result(&result);
+        let rows_result = match result.into_rows_result() {
+            Ok(rows_result) => rows_result,
+            Err(IntoRowsResultError::ResultNotRows(_)) => {
+                return Ok(RowSet {
+                    request_details,
+                    ..RowSet::empty()
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let page = FetchedPage::new(rows_result)?;
+        Ok(RowSet {
+            source: tokio::sync::Mutex::new(Some(RowSource::Page(page))),
+            paging_state,
+            request_span: None,
+            request_details,
+        })
+    }
+}
+
+/// A row with cell values copied out of the response frame. `None` represents a null value.
+type BufferedRow = Vec<Option<Vec<u8>>>;
+
+fn buffer_row<'frame, 'metadata, E>(
+    columns: impl Iterator<Item = Result<RawColumn<'frame, 'metadata>, E>>,
+) -> Result<BufferedRow, BufferRowsError>
+where
+    BufferRowsError: From<E>,
+{
+    columns
+        .map(|column| Ok(column?.slice.map(|slice| slice.as_slice().to_vec())))
+        .collect()
+}
+
+/// Location of a single value within the data of a `BridgedPage`.
+/// The layout is fixed, as C# reads the cells directly from the memory of the page.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BridgedCell {
+    /// Offset of the value's bytes from the start of the page data.
+    offset: usize,
+    /// Length of the value in bytes, or -1 if the value is null.
+    len: isize,
+}
+
+/// Rows of a page copied into a single buffer, so that C# can deserialize all of them
+/// in a loop, instead of being called back for every single value.
+///
+/// Cells are stored row by row: the value of column `c` in row `r`
+/// is described by `cells[r * columns_count + c]`.
+#[derive(Debug)]
+pub struct BridgedPage {
+    data: Vec<u8>,
+    cells: Vec<BridgedCell>,
+    columns_count: usize,
+}
+
+impl FFI for BridgedPage {
+    type Origin = FromBox;
+}
+
+impl BridgedPage {
+    fn new(columns_count: usize) -> Self {
+        BridgedPage {
+            data: Vec::new(),
+            cells: Vec::new(),
+            columns_count,
+        }
+    }
+
+    fn rows_count(&self) -> usize {
+        self.cells
+            .len()
+            .checked_div(self.columns_count)
+            .unwrap_or(0)
+    }
+
+    fn push_cell(&mut self, value: Option<&[u8]>) {
+        let cell = match value {
+            Some(value) => {
+                let cell = BridgedCell {
+                    offset: self.data.len(),
+                    len: value.len() as isize,
+                };
+                self.data.extend_from_slice(value);
+                cell
+            }
+            None => BridgedCell {
+                offset: self.data.len(),
+                len: -1,
+            },
+        };
+        self.cells.push(cell);
+    }
+
+    fn push_row(&mut self, columns: CheckedColumns<'_, '_, '_>) -> Result<(), BufferRowsError> {
+        for column in columns {
+            self.push_cell(column?.slice.map(|slice| slice.as_slice()));
+        }
+        Ok(())
+    }
+
+    fn push_buffered_row(&mut self, row: &BufferedRow) {
+        for value in row {
+            self.push_cell(value.as_deref());
+        }
+    }
+}
+
+/// Columns of the first page of a paged result.
+///
+/// C# reads the columns once, when the RowSet is created, and deserializes all rows with them.
+/// Columns of every following page are checked against these, so that rows are never
+/// deserialized with wrong types if the table is altered in the middle of paging.
+#[derive(Debug)]
+struct ExpectedColumns(Vec<(String, ColumnType<'static>)>);
+
+impl ExpectedColumns {
+    fn new<'a, 'spec: 'a>(specs: impl IntoIterator<Item = &'a ColumnSpec<'spec>>) -> Self {
+        ExpectedColumns(
+            specs
+                .into_iter()
+                .map(|spec| (spec.name().to_owned(), spec.typ().clone().into_owned()))
+                .collect(),
+        )
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks the number of columns of a row beginning a new page.
+    fn check_count(&self, count: usize) -> Result<(), ResultMetadataChangedError> {
+        if count != self.0.len() {
+            return Err(ResultMetadataChangedError(format!(
+                "the number of columns changed from {} to {}",
+                self.0.len(),
+                count
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks a single column of a row beginning a new page.
+    fn check_column(&self, column: &RawColumn<'_, '_>) -> Result<(), ResultMetadataChangedError> {
+        let (name, typ) = &self.0[column.index];
+        if column.spec.name() != name || column.spec.typ() != typ {
+            return Err(ResultMetadataChangedError(format!(
+                "column {} changed from `{}` of type {:?} to `{}` of type {:?}",
+                column.index,
+                name,
+                typ,
+                column.spec.name(),
+                column.spec.typ()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Columns of a row taken from the pager, read one by one as they are iterated over.
+///
+/// If the row begins a new page, its columns are checked against the expected ones
+/// while they are read. The following rows of the page share its metadata,
+/// so they are not checked again.
+struct CheckedColumns<'a, 'frame, 'metadata> {
+    columns: ColumnIterator<'frame, 'metadata>,
+    // Set only for the row beginning a new page.
+    expected: Option<&'a ExpectedColumns>,
+}
+
+impl<'a, 'frame, 'metadata> CheckedColumns<'a, 'frame, 'metadata> {
+    fn new(
+        columns: ColumnIterator<'frame, 'metadata>,
+        new_page_began: bool,
+        expected: &'a ExpectedColumns,
+    ) -> Result<Self, ResultMetadataChangedError> {
+        let expected = new_page_began.then_some(expected);
+        if let Some(expected) = expected {
+            expected.check_count(columns.columns_remaining())?;
+        }
+        Ok(CheckedColumns { columns, expected })
+    }
+}
+
+impl<'frame, 'metadata> Iterator for CheckedColumns<'_, 'frame, 'metadata> {
+    type Item = Result<RawColumn<'frame, 'metadata>, BufferRowsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let column = match self.columns.next()? {
+            Ok(column) => column,
+            Err(err) => return Some(Err(err.into())),
+        };
+        if let Some(expected) = self.expected
+            && let Err(err) = expected.check_column(&column)
+        {
+            return Some(Err(err.into()));
+        }
+        Some(Ok(column))
+    }
+}
+
+/// Error returned when a page of a paged result has different columns than the first page.
+#[derive(Error, Debug)]
+#[error("Result metadata changed between pages: {0}")]
+pub(crate) struct ResultMetadataChangedError(String);
+
+impl ErrorToException for ResultMetadataChangedError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors
+            .result_metadata_changed_exception_constructor
+            .construct_from_rust(&self.to_string())
+    }
+}
+
+/// Where the rows of a RowSet come from.
+#[derive(Debug)]
+enum RowSource {
+    /// Rows are fetched from the cluster page by page, as C# iterates over them.
+    /// `buffered` holds rows already taken from the pager by `row_set_fetch_next_page`,
+    /// which are returned before any further rows of the pager.
+    Pager {
+        pager: QueryPager,
+        columns: ExpectedColumns,
+        buffered: VecDeque<BufferedRow>,
+    },
+    /// All rows were received in a single response, e.g. the result of a conditional batch.
+    Page(FetchedPage),
+}
+
+impl RowSource {
+    fn column_specs(&self) -> ColumnSpecs<'_, '_> {
+        match self {
+            RowSource::Pager { pager, .. } => pager.column_specs(),
+            RowSource::Page(page) => page.result.column_specs(),
+        }
+    }
+}
+
+/// A single page of rows received in full, so that they can be handed to C# one row at a time.
+#[derive(Debug)]
+struct FetchedPage {
+    result: QueryRowsResult,
+    rows: VecDeque<BufferedRow>,
+}
+
+impl FetchedPage {
+    fn new(result: QueryRowsResult) -> Result<Self, BufferRowsError> {
+        let mut rows = VecDeque::with_capacity(result.rows_num());
+        for row in result.rows::<ColumnIterator>()? {
+            rows.push_back(buffer_row(row?)?);
+        }
+        Ok(FetchedPage { result, rows })
+    }
+}
+
+/// Takes the next row from the pager, together with whether it began a new page.
+///
+/// Rows beginning a new page have their columns checked against `expected_columns`,
+/// as the columns may change between pages, e.g. after an `ALTER TABLE`.
+async fn next_checked_row<'pager>(
+    pager: &'pager mut QueryPager,
+    expected_columns: &'pager ExpectedColumns,
+) -> Option<Result<(CheckedColumns<'pager, 'pager, 'pager>, bool), BufferRowsError>> {
+    let next = pager.next_column_iterator().await?;
+    let checked = next
+        .map_err(BufferRowsError::from)
+        .and_then(|(columns, new_page_began)| {
+            let columns = CheckedColumns::new(columns, new_page_began, expected_columns)?;
+            Ok((columns, new_page_began))
+        });
+    Some(checked)
+}
+
+/// Takes the rows of the next page from the pager, passing each of them to `take_row`.
+///
+/// Waits until at least one row is available, and then takes all rows that can be read
+/// without waiting for another page. No rows are taken if the pager has no more rows.
+async fn take_next_page(
+    pager: &mut QueryPager,
+    columns: &ExpectedColumns,
+    mut take_row: impl FnMut(CheckedColumns<'_, '_, '_>) -> Result<(), BufferRowsError>,
+) -> Result<(), BufferRowsError> {
+    let Some(next) = next_checked_row(pager, columns).await else {
+        return Ok(());
+    };
+    let (row, _new_page_began) = next?;
+    take_row(row)?;
+
+    // Dropping the pending future is fine here - the pager keeps the next page
+    // in its channel until it is requested again.
+    while let Some(Some(next)) = next_checked_row(pager, columns).now_or_never() {
+        let (row, new_page_began) = next?;
+        take_row(row)?;
+        if new_page_began {
+            // The row begins yet another page, which has already arrived. Stop here,
+            // so that a single call takes at most a page worth of rows.
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Errors that may occur while copying rows out of responses into a RowSet's buffer.
+#[derive(Error, Debug)]
+pub(crate) enum BufferRowsError {
+    #[error(transparent)]
+    IntoRowsResult(#[from] IntoRowsResultError),
+
+    #[error(transparent)]
+    Rows(#[from] RowsError),
+
+    #[error(transparent)]
+    NextRow(#[from] NextRowError),
+
+    #[error(transparent)]
+    Deserialization(#[from] DeserializationError),
+
+    #[error(transparent)]
+    ResultMetadataChanged(#[from] ResultMetadataChangedError),
+
+    #[error(transparent)]
+    RuntimeShutDown(#[from] RuntimeShutDownError),
+}
+
+impl ErrorToException for BufferRowsError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            BufferRowsError::NextRow(err) => err.to_exception(ctors),
+            BufferRowsError::Deserialization(err) => err.to_exception(ctors),
+            BufferRowsError::ResultMetadataChanged(err) => err.to_exception(ctors),
+            BufferRowsError::RuntimeShutDown(err) => err.to_exception(ctors),
+            BufferRowsError::IntoRowsResult(_) | BufferRowsError::Rows(_) => {
+                ctors.rust_exception_constructor.construct_from_rust(self)
+            }
+        }
+    }
+}
+
+// Errors of fetching the next pages, together with the nodes they were requested from.
+impl ErrorToException for FailedRequest<BufferRowsError> {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match &self.error {
+            BufferRowsError::NextRow(NextRowError::NextPageError(page_err)) => {
+                self.next_page_exception(page_err, ctors)
+            }
+            err => err.to_exception(ctors),
         }
     }
 }
@@ -55,8 +481,8 @@ pub extern "C" fn row_set_get_columns_count(
     row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
 ) -> usize {
     let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager = row_set.pager.lock().unwrap();
-    pager.as_ref().map(|p| p.column_specs().len()).unwrap_or(0)
+    let source = row_set.source.blocking_lock();
+    source.as_ref().map(|s| s.column_specs().len()).unwrap_or(0)
 }
 
 // Function pointer type for setting column metadata in C#.
@@ -84,8 +510,8 @@ pub extern "C" fn row_set_fill_columns_metadata(
     constructors: &ExceptionConstructors,
 ) -> FfiException {
     let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager_guard = row_set.pager.lock().unwrap();
-    let Some(pager) = pager_guard.as_ref() else {
+    let source_guard = row_set.source.blocking_lock();
+    let Some(source) = source_guard.as_ref() else {
         // Return a RustException built via constructors as a quick workaround.
         let ex = constructors
             .rust_exception_constructor
@@ -94,7 +520,7 @@ pub extern "C" fn row_set_fill_columns_metadata(
     };
 
     // Iterate column specs and call the metadata setter
-    for (i, spec) in pager.column_specs().iter().enumerate() {
+    for (i, spec) in source.column_specs().iter().enumerate() {
         let name = FFIStr::new(spec.name());
         let keyspace = FFIStr::new(spec.table_spec().ks_name());
         let table = FFIStr::new(spec.table_spec().table_name());
@@ -141,122 +567,214 @@ enum Columns {}
 #[derive(Clone, Copy)]
 pub struct ColumnsPtr(FfiPtr<'static, Columns>);
 
-#[derive(Clone, Copy)]
-enum Values {}
-
-#[derive(Clone, Copy)]
-#[repr(transparent)]
-pub struct ValuesPtr(FfiPtr<'static, Values>);
-
-#[derive(Clone, Copy)]
-enum Serializer {}
+/// Asynchronously buffers the next page of rows, without blocking the calling thread.
+///
+/// The task completes with an `EmptyBridgedResult` once the rows are buffered,
+/// so that the subsequent `row_set_next_page` call returns them without blocking.
+/// If there are still buffered rows, it completes immediately.
+/// If nothing was buffered (see `row_set_buffered_rows_count`), the RowSet has no more rows.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_fetch_next_page(
+    tcb: Tcb,
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    let row_set = ArcFFI::cloned_from_ptr(row_set_ptr).unwrap();
 
-#[derive(Clone, Copy)]
-#[repr(transparent)]
-pub struct SerializerPtr(FfiPtr<'static, Serializer>);
+    BridgedFuture::spawn::<_, _, FailedRequest<BufferRowsError>>(tcb, async move {
+        let mut source_guard = row_set.source.lock().await;
+        if let Some(RowSource::Pager {
+            pager,
+            columns,
+            buffered,
+        }) = source_guard.as_mut()
+            && buffered.is_empty()
+        {
+            let taken = take_next_page(pager, columns, |row| {
+                buffered.push_back(buffer_row(row)?);
+                Ok(())
+            })
+            .await;
+            row_set.request_details.record_pager(pager);
+            taken.map_err(|err| row_set.failed(err))?;
+            tracing::trace!("[FFI] Buffered {} rows of the next page", buffered.len());
+        }
+        Ok(EmptyBridgedResult)
+    })
+}
 
-type DeserializeValue = unsafe extern "C" fn(
-    columns_ptr: ColumnsPtr,
-    values_ptr: ValuesPtr,
-    value_index: usize,
-    serializer_ptr: SerializerPtr,
-    frame_slice: FFIByteSlice<'_>,
-) -> FfiException;
+/// Returns the number of rows that `row_set_next_page` can return without blocking.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_buffered_rows_count(
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+) -> usize {
+    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
+    let source = row_set.source.blocking_lock();
+    match source.as_ref() {
+        Some(RowSource::Pager { buffered, .. }) => buffered.len(),
+        Some(RowSource::Page(page)) => page.rows.len(),
+        None => 0,
+    }
+}
 
+/// Takes the next page of rows out of the RowSet at once, blocking if it has to be fetched.
+///
+/// C# receives all values of the page in a single buffer, so that it can deserialize them in a loop,
+/// instead of being called back for every single value.
+/// Rows buffered by `row_set_fetch_next_page` are returned as a page of their own.
+/// A page with no rows means that the RowSet has no more rows.
+/// The page must be freed with `row_set_page_free`.
 #[unsafe(no_mangle)]
-pub extern "C" fn row_set_next_row<'row_set>(
-    row_set_ptr: BridgedBorrowedSharedPtr<'row_set, RowSet>,
-    deserialize_value: DeserializeValue,
-    columns_ptr: ColumnsPtr,
-    values_ptr: ValuesPtr,
-    serializer_ptr: SerializerPtr,
-    out_has_row: *mut bool,
+pub extern "C" fn row_set_next_page(
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+    out_page: *mut BridgedOwnedExclusivePtr<BridgedPage>,
     constructors: &ExceptionConstructors,
 ) -> FfiException {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let mut pager_guard = row_set.pager.lock().unwrap();
-    let Some(pager) = pager_guard.as_mut() else {
-        unsafe {
-            *out_has_row = false;
+    if out_page.is_null() {
+        panic!("Null pointer passed to row_set_next_page");
+    }
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        panic!("Null pointer passed to row_set_next_page");
+    };
+    let mut source_guard = row_set.source.blocking_lock();
+
+    let page = match source_guard.as_mut() {
+        // Empty RowSet has no rows
+        None => Ok(BridgedPage::new(0)),
+        Some(RowSource::Page(page)) => {
+            let mut bridged_page = BridgedPage::new(page.result.column_specs().len());
+            for row in page.rows.drain(..) {
+                bridged_page.push_buffered_row(&row);
+            }
+            Ok(bridged_page)
+        }
+        Some(RowSource::Pager {
+            pager,
+            columns,
+            buffered,
+        }) => {
+            let mut bridged_page = BridgedPage::new(columns.len());
+            if buffered.is_empty() {
+                let take_fut = take_next_page(pager, columns, |row| bridged_page.push_row(row));
+                let taken = BridgedFuture::block_on(take_fut);
+                row_set.request_details.record_pager(pager);
+                taken
+                    .map_err(BufferRowsError::from)
+                    .and_then(|taken| taken.map(|()| bridged_page))
+            } else {
+                for row in buffered.drain(..) {
+                    bridged_page.push_buffered_row(&row);
+                }
+                Ok(bridged_page)
+            }
         }
-        return FfiException::ok(); // Empty RowSet has no rows
     };
-    let num_columns = pager.column_specs().len();
-
-    let deserialize_fut = async {
-        // Returns Ok(true) when a row was read and deserialized,
-        // Ok(false) when there are no more rows,
-        // Err(FfiException) when an error occurs and should be propagated to C#.
-        // TODO: consider how to handle possibility of the metadata to change between pages.
-        // While unlikely, it's not impossible.
-        // For now, we just assume it won't happen and ignore `_new_page_began`.
-        // The problem is that C# assumes the same metadata for the whole RowSet,
-        // and they are passed through `ColumnsPtr`. Currently, if the metadata changes,
-        // C# code will attempt to deserialize columns with wrong types, likely leading to exceptions.
-        let Some(next) = pager.next_column_iterator().await else {
-            tracing::trace!("[FFI] No more rows available!");
-            return Ok(false);
-        };
 
-        let (mut column_iterator, _new_page_began) = match next {
-            // Successfully obtained the next row's column iterator
-            Ok(values) => values,
-            // Error while fetching the column value
-            Err(err) => return Err(FfiException::from_error(err, constructors)),
-        };
+    match page {
+        Ok(page) => {
+            tracing::trace!("[FFI] Took a page of {} rows", page.rows_count());
+            unsafe {
+                out_page.write(BoxFFI::into_ptr(Box::new(page)));
+            }
+            FfiException::ok()
+        }
+        Err(err) => {
+            unsafe {
+                out_page.write(BoxFFI::null_mut());
+            }
+            FfiException::from_error(row_set.failed(err), constructors)
+        }
+    }
+}
 
-        for value_index in 0..num_columns {
-            let Some(column_res) = column_iterator.next() else {
-                // Error: fewer columns than expected
-                // TODO: Implement error type for too few columns - server provided less columns than claimed in the metadata
-                let ex = constructors
-                    .rust_exception_constructor
-                    .construct_from_rust(format!(
-                        "Row contains fewer columns ({} of {}) than metadata claims",
-                        value_index, num_columns
-                    ));
-                return Err(FfiException::from_exception(ex));
-            };
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_page_free(page_ptr: BridgedOwnedExclusivePtr<BridgedPage>) {
+    BoxFFI::free(page_ptr);
+    tracing::trace!("[FFI] RowSet page freed");
+}
 
-            let raw_column = match column_res {
-                Ok(rc) => rc,
-                Err(err) => return Err(FfiException::from_error(err, constructors)),
-            };
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_page_get_rows_count(
+    page_ptr: BridgedBorrowedSharedPtr<'_, BridgedPage>,
+) -> usize {
+    let Some(page) = BoxFFI::as_ref(page_ptr) else {
+        panic!("Null pointer passed to row_set_page_get_rows_count");
+    };
+    page.rows_count()
+}
 
-            let Some(frame_slice) = raw_column.slice else {
-                // The value is null, so we skip deserialization.
-                // We can do that because `object[] values` in C# is initialized with nulls.
-                continue;
-            };
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_page_get_columns_count(
+    page_ptr: BridgedBorrowedSharedPtr<'_, BridgedPage>,
+) -> usize {
+    let Some(page) = BoxFFI::as_ref(page_ptr) else {
+        panic!("Null pointer passed to row_set_page_get_columns_count");
+    };
+    page.columns_count
+}
 
-            unsafe {
-                let ffi_exception = deserialize_value(
-                    columns_ptr,
-                    values_ptr,
-                    value_index,
-                    serializer_ptr,
-                    FFIByteSlice::new(frame_slice.as_slice()),
-                );
-                if ffi_exception.has_exception() {
-                    return Err(ffi_exception);
-                }
-            }
-        }
+/// Returns the buffer holding the bytes of all non-null values of the page.
+/// The bytes are valid as long as the page is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_page_get_data<'page>(
+    page_ptr: BridgedBorrowedSharedPtr<'page, BridgedPage>,
+) -> FFIByteSlice<'page> {
+    let Some(page) = BoxFFI::as_ref(page_ptr) else {
+        panic!("Null pointer passed to row_set_page_get_data");
+    };
+    FFIByteSlice::new(&page.data)
+}
 
-        Ok(true)
+/// Returns a pointer to the `rows_count * columns_count` cells of the page, stored row by row.
+/// The cells are valid as long as the page is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_page_get_cells(
+    page_ptr: BridgedBorrowedSharedPtr<'_, BridgedPage>,
+) -> *const BridgedCell {
+    let Some(page) = BoxFFI::as_ref(page_ptr) else {
+        panic!("Null pointer passed to row_set_page_get_cells");
     };
+    page.cells.as_ptr()
+}
 
-    // This is inherently inefficient, but necessary due to blocking C# API upon page boundaries.
-    // TODO: implement async C# API (IAsyncEnumerable) to avoid this.
-    let (has_row, result) = match BridgedFuture::block_on(deserialize_fut) {
-        Ok(has_row) => (has_row, FfiException::ok()),
-        Err(exception) => (false, exception),
+/// Writes the paging state needed to fetch the page following the RowSet's rows.
+/// Returns false if there are no more pages, or the RowSet was not fetched page by page manually.
+/// The bytes are valid as long as the RowSet is alive - C# must copy them.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_get_paging_state<'row_set>(
+    row_set_ptr: BridgedBorrowedSharedPtr<'row_set, RowSet>,
+    out_paging_state: *mut FFIByteSlice<'row_set>,
+) -> bool {
+    if out_paging_state.is_null() {
+        panic!("Null pointer passed to row_set_get_paging_state");
+    }
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        panic!("Null pointer passed to row_set_get_paging_state");
+    };
+    let Some(paging_state) = row_set
+        .paging_state
+        .as_ref()
+        .and_then(|state| state.as_bytes_slice())
+    else {
+        return false;
     };
     unsafe {
-        *out_has_row = has_row;
+        out_paging_state.write(FFIByteSlice::new(paging_state.as_ref()));
     }
+    true
+}
 
-    result
+/// Takes a snapshot of the execution info of the request that produced the RowSet:
+/// coordinator, attempts, latency, warnings and tracing id.
+/// For RowSets fetched page by page, it covers the pages fetched so far.
+/// C# must free it with `execution_info_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_get_execution_info(
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+) -> BridgedOwnedExclusivePtr<BridgedExecutionInfo> {
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        panic!("Null pointer passed to row_set_get_execution_info");
+    };
+    BoxFFI::into_ptr(Box::new(row_set.request_details.execution_info()))
 }
 
 #[unsafe(no_mangle)]
@@ -460,7 +978,7 @@ pub extern "C" fn row_set_type_info_get_udt_field<'typ>(
     }
 }
 
-fn column_type_to_code(typ: &ColumnType) -> u8 {
+pub(crate) fn column_type_to_code(typ: &ColumnType) -> u8 {
     match typ {
         ColumnType::Native(nt) => match nt {
             NativeType::Ascii => 0x01,
@@ -497,3 +1015,178 @@ fn column_type_to_code(typ: &ColumnType) -> u8 {
         _ => 0x00,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    use scylla::deserialize::FrameSlice;
+    use scylla::errors::{NextPageError, RequestError};
+    use scylla::frame::response::result::TableSpec;
+    use scylla::observability::history::HistoryListener;
+
+    use super::*;
+    use crate::error_conversion::testing::{CONSTRUCTORS, TestException};
+    use crate::task::bridged_task_handle_free;
+    use crate::task::testing::{self, TaskOutcome};
+
+    #[test]
+    fn fetching_next_page_of_empty_row_set_completes_without_rows() {
+        let row_set = Arc::new(RowSet::empty());
+
+        let (tcb, outcome) = testing::tcb();
+        bridged_task_handle_free(row_set_fetch_next_page(tcb, ArcFFI::as_ptr(&row_set)));
+
+        assert!(matches!(testing::wait(&outcome), TaskOutcome::Completed));
+        assert_eq!(row_set_buffered_rows_count(ArcFFI::as_ptr(&row_set)), 0);
+    }
+
+    fn spec(name: &'static str, typ: NativeType) -> ColumnSpec<'static> {
+        ColumnSpec::borrowed(
+            name,
+            ColumnType::Native(typ),
+            TableSpec::borrowed("ks", "tbl"),
+        )
+    }
+
+    /// Serializes a row of `int` values, or nulls, as they are sent in a response frame.
+    fn int_row(values: &[Option<i32>]) -> Vec<u8> {
+        let mut row = Vec::new();
+        for value in values {
+            match value {
+                Some(value) => {
+                    row.extend_from_slice(&4i32.to_be_bytes());
+                    row.extend_from_slice(&value.to_be_bytes());
+                }
+                None => row.extend_from_slice(&(-1i32).to_be_bytes()),
+            }
+        }
+        row
+    }
+
+    fn read_row(
+        specs: &[ColumnSpec<'_>],
+        row: &[u8],
+        new_page_began: bool,
+        expected: &ExpectedColumns,
+    ) -> Result<BufferedRow, BufferRowsError> {
+        let columns = ColumnIterator::new(specs, FrameSlice::new_borrowed(row));
+        buffer_row(CheckedColumns::new(columns, new_page_began, expected)?)
+    }
+
+    #[test]
+    fn rows_of_a_page_with_unchanged_columns_are_read() {
+        let specs = [spec("id", NativeType::Int), spec("value", NativeType::Int)];
+        let expected = ExpectedColumns::new(&specs);
+        let row = int_row(&[Some(1), None]);
+
+        for new_page_began in [true, false] {
+            let buffered = read_row(&specs, &row, new_page_began, &expected).unwrap();
+            assert_eq!(buffered, vec![Some(1i32.to_be_bytes().to_vec()), None]);
+        }
+    }
+
+    #[test]
+    fn page_with_changed_column_type_is_rejected() {
+        let first_page = [spec("id", NativeType::Int), spec("value", NativeType::Int)];
+        let expected = ExpectedColumns::new(&first_page);
+        // e.g. after `ALTER TABLE tbl DROP value; ALTER TABLE tbl ADD value bigint`.
+        let next_page = [
+            spec("id", NativeType::Int),
+            spec("value", NativeType::BigInt),
+        ];
+        let row = int_row(&[Some(1), Some(2)]);
+
+        let err = read_row(&next_page, &row, true, &expected).unwrap_err();
+        assert!(
+            matches!(err, BufferRowsError::ResultMetadataChanged(_)),
+            "{err:?}"
+        );
+        assert!(err.to_string().contains("column 1 changed"), "{err}");
+    }
+
+    #[test]
+    fn page_with_changed_column_count_is_rejected() {
+        let first_page = [spec("id", NativeType::Int), spec("value", NativeType::Int)];
+        let expected = ExpectedColumns::new(&first_page);
+        let next_page = [
+            spec("id", NativeType::Int),
+            spec("value", NativeType::Int),
+            spec("added", NativeType::Int),
+        ];
+        let row = int_row(&[Some(1), Some(2), Some(3)]);
+
+        let err = read_row(&next_page, &row, true, &expected).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("the number of columns changed from 2 to 3"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn page_stores_cells_row_by_row_with_null_markers() {
+        let specs = [spec("id", NativeType::Int), spec("value", NativeType::Int)];
+        let expected = ExpectedColumns::new(&specs);
+        let mut page = BridgedPage::new(expected.len());
+        for row in [int_row(&[Some(1), None]), int_row(&[Some(2), Some(3)])] {
+            let columns = ColumnIterator::new(&specs, FrameSlice::new_borrowed(&row));
+            let columns = CheckedColumns::new(columns, false, &expected).unwrap();
+            page.push_row(columns).unwrap();
+        }
+
+        assert_eq!(page.rows_count(), 2);
+        let cells: Vec<_> = page
+            .cells
+            .iter()
+            .map(|cell| (cell.offset, cell.len))
+            .collect();
+        assert_eq!(cells, [(0, 4), (4, -1), (4, 4), (8, 4)]);
+        assert_eq!(page.data, [1i32, 2, 3].map(i32::to_be_bytes).concat());
+    }
+
+    #[test]
+    fn next_page_of_empty_row_set_has_no_rows() {
+        let row_set = Arc::new(RowSet::empty());
+        let mut page = BoxFFI::null_mut();
+
+        let result = row_set_next_page(ArcFFI::as_ptr(&row_set), &mut page, &CONSTRUCTORS);
+
+        assert_eq!(TestException::from_ffi(result), None);
+        assert_eq!(row_set_page_get_rows_count(page.borrow()), 0);
+        row_set_page_free(page);
+    }
+
+    #[test]
+    fn timeout_of_later_page_names_the_node_without_response() {
+        let node: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+        let request_span = RequestSpan::new("query", None, false, None, None, None);
+        let history = request_span.history_listener();
+        // The first page succeeded, the second one timed out.
+        history.log_request_start();
+        let request_id = history.log_request_start();
+        history.log_attempt_start(request_id, None, node);
+
+        let row_set = RowSet::empty().with_request_span(request_span);
+        let exception = TestException::from_error(row_set.failed(BufferRowsError::NextRow(
+            NextRowError::NextPageError(NextPageError::RequestFailure(
+                RequestError::RequestTimeout(Duration::from_secs(2)),
+            )),
+        )));
+        assert_eq!(exception.kind, "OperationTimedOutException");
+        assert_eq!(exception.args, ["127.0.0.2:9042", "2000"]);
+    }
+
+    #[test]
+    fn only_rows_beginning_a_page_are_checked() {
+        let first_page = [spec("id", NativeType::Int)];
+        let expected = ExpectedColumns::new(&first_page);
+        let next_page = [spec("renamed", NativeType::Int)];
+        let row = int_row(&[Some(1)]);
+
+        // The page's first row has already been checked - the following ones share its metadata.
+        assert!(read_row(&next_page, &row, false, &expected).is_ok());
+        assert!(read_row(&next_page, &row, true, &expected).is_err());
+    }
+}
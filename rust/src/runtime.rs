@@ -0,0 +1,361 @@
+use std::future::Future;
+use std::io;
+use std::num::NonZeroUsize;
+use std::str::Utf8Error;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{OnceLock, RwLock, RwLockReadGuard};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::sync::oneshot;
+
+use crate::CSharpStr;
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FfiException};
+use crate::task::ExceptionConstructors;
+
+/// The global Tokio runtime used to execute async tasks.
+///
+/// Started on first use, with the options passed to `runtime_init` if it was called before.
+static RUNTIME: OnceLock<BridgedRuntime> = OnceLock::new();
+
+/// Returns the global runtime, starting it with the default options if it is not running yet.
+pub(crate) fn runtime() -> &'static BridgedRuntime {
+    RUNTIME.get_or_init(|| {
+        BridgedRuntime::start(RuntimeOptions::default()).expect("Failed to start Tokio runtime")
+    })
+}
+
+/// Options of the global runtime. `None` keeps the Tokio default.
+#[derive(Debug, Default)]
+struct RuntimeOptions {
+    worker_threads: Option<NonZeroUsize>,
+    max_blocking_threads: Option<NonZeroUsize>,
+    thread_stack_size: Option<NonZeroUsize>,
+    thread_name_prefix: Option<String>,
+    current_thread: bool,
+}
+
+impl RuntimeOptions {
+    fn builder(&self) -> Builder {
+        let mut builder = if self.current_thread {
+            Builder::new_current_thread()
+        } else {
+            Builder::new_multi_thread()
+        };
+        builder.enable_all();
+
+        if let Some(worker_threads) = self.worker_threads {
+            builder.worker_threads(worker_threads.get());
+        }
+        if let Some(max_blocking_threads) = self.max_blocking_threads {
+            builder.max_blocking_threads(max_blocking_threads.get());
+        }
+        if let Some(thread_stack_size) = self.thread_stack_size {
+            builder.thread_stack_size(thread_stack_size.get());
+        }
+        if let Some(prefix) = self.thread_name_prefix.clone() {
+            let thread_id = AtomicUsize::new(0);
+            builder.thread_name_fn(move || {
+                let id = thread_id.fetch_add(1, Ordering::Relaxed);
+                format!("{prefix}-{id}")
+            });
+        }
+        builder
+    }
+
+    fn driver_thread_name(&self) -> String {
+        match &self.thread_name_prefix {
+            Some(prefix) => format!("{prefix}-driver"),
+            None => "tokio-runtime-driver".to_owned(),
+        }
+    }
+}
+
+/// The global runtime, together with what is needed to shut it down.
+pub(crate) struct BridgedRuntime {
+    handle: Handle,
+    // Taken out by the first `runtime_shutdown`. Tasks are spawned under the read lock,
+    // so that none of them is spawned onto a runtime that is being shut down.
+    shutdown: RwLock<Option<RuntimeShutdown>>,
+    is_shut_down: AtomicBool,
+}
+
+/// Owner of the runtime's threads.
+enum RuntimeShutdown {
+    /// Multi-thread runtime, which runs tasks on its worker threads.
+    MultiThread(Runtime),
+    /// Current-thread runtime, which runs tasks on a dedicated driver thread
+    /// until the driver is sent the shutdown timeout.
+    CurrentThread {
+        stop: oneshot::Sender<Duration>,
+        driver: JoinHandle<()>,
+    },
+}
+
+impl BridgedRuntime {
+    fn start(options: RuntimeOptions) -> io::Result<Self> {
+        // Logger must be initialized for the logs to be emitted. Unless C# has already
+        // initialized it with `logging_init`, we fall back to the default logger here,
+        // as the runtime is started before any async task runs.
+        crate::logging::init_logging();
+
+        let runtime = options.builder().build()?;
+        let handle = runtime.handle().clone();
+
+        let shutdown = if options.current_thread {
+            // A current-thread runtime only makes progress while it is blocked on,
+            // so it is driven by a thread of its own.
+            let (stop, stopped) = oneshot::channel::<Duration>();
+            let driver = std::thread::Builder::new()
+                .name(options.driver_thread_name())
+                .spawn(move || {
+                    let timeout = runtime.block_on(stopped).unwrap_or_default();
+                    runtime.shutdown_timeout(timeout);
+                })?;
+            RuntimeShutdown::CurrentThread { stop, driver }
+        } else {
+            RuntimeShutdown::MultiThread(runtime)
+        };
+
+        tracing::debug!("[FFI] Tokio runtime started with {:?}", options);
+        Ok(BridgedRuntime {
+            handle,
+            shutdown: RwLock::new(Some(shutdown)),
+            is_shut_down: AtomicBool::new(false),
+        })
+    }
+
+    pub(crate) fn is_shut_down(&self) -> bool {
+        self.is_shut_down.load(Ordering::Acquire)
+    }
+
+    /// Returns a spawner, which keeps the runtime from being shut down until it is dropped.
+    /// Fails if the runtime has already been shut down.
+    pub(crate) fn spawner(&self) -> Result<Spawner<'_>, RuntimeShutDownError> {
+        let shutdown = self.shutdown.read().unwrap();
+        if shutdown.is_none() {
+            return Err(RuntimeShutDownError);
+        }
+        Ok(Spawner {
+            handle: &self.handle,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// Spawns the future, unless the runtime has been shut down - then the future is dropped.
+    pub(crate) fn spawn<F>(&self, future: F) -> Result<(), RuntimeShutDownError>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawner()?.spawn(future);
+        Ok(())
+    }
+
+    pub(crate) fn block_on<T>(
+        &self,
+        future: impl Future<Output = T>,
+    ) -> Result<T, RuntimeShutDownError> {
+        if self.is_shut_down() {
+            return Err(RuntimeShutDownError);
+        }
+        Ok(self.handle.block_on(future))
+    }
+
+    fn shutdown(&self, timeout: Duration) {
+        let Some(shutdown) = self.shutdown.write().unwrap().take() else {
+            return;
+        };
+        self.is_shut_down.store(true, Ordering::Release);
+
+        match shutdown {
+            RuntimeShutdown::MultiThread(runtime) => runtime.shutdown_timeout(timeout),
+            RuntimeShutdown::CurrentThread { stop, driver } => {
+                // The driver may only have stopped if it panicked, which aborts the process anyway.
+                let _ = stop.send(timeout);
+                let _ = driver.join();
+            }
+        }
+        tracing::debug!("[FFI] Tokio runtime shut down");
+    }
+}
+
+/// Returned when a task is spawned or a blocking call is issued after `runtime_shutdown`.
+#[derive(Error, Debug)]
+#[error("Tokio runtime has been shut down")]
+pub(crate) struct RuntimeShutDownError;
+
+impl ErrorToException for RuntimeShutDownError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors
+            .already_shutdown_exception_constructor
+            .construct_from_rust(self)
+    }
+}
+
+/// Spawns tasks onto the global runtime, which cannot be shut down while the spawner is alive.
+///
+/// Checking whether the runtime is shut down and spawning under the same guard makes sure
+/// that no task is silently dropped by a runtime that is shut down in the meantime.
+pub(crate) struct Spawner<'a> {
+    handle: &'a Handle,
+    _shutdown: RwLockReadGuard<'a, Option<RuntimeShutdown>>,
+}
+
+impl Spawner<'_> {
+    pub(crate) fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.handle.spawn(future);
+    }
+}
+
+#[derive(Error, Debug)]
+pub(crate) enum RuntimeInitError {
+    #[error("Tokio runtime is already running - it must be configured before its first use")]
+    AlreadyRunning,
+
+    #[error("Thread name prefix is not valid UTF-8: {0}")]
+    InvalidThreadNamePrefix(#[from] Utf8Error),
+
+    #[error("Failed to start Tokio runtime: {0}")]
+    Start(#[from] io::Error),
+}
+
+impl ErrorToException for RuntimeInitError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+/// Configures and starts the global Tokio runtime.
+///
+/// Must be called before any function that uses the runtime, i.e. before the first session
+/// is created. Otherwise, the runtime is started with the Tokio defaults on first use.
+///
+/// Zero values keep the Tokio defaults: `worker_threads` - the number of CPU cores,
+/// `max_blocking_threads` - 512, `thread_stack_size` - 2 MiB.
+/// If `thread_name_prefix` is not null, runtime threads are named `<prefix>-<n>`.
+/// If `current_thread` is true, all tasks run on a single thread and `worker_threads` is ignored.
+///
+/// Returns an exception if the runtime is already running or cannot be started,
+/// or if `thread_name_prefix` is not valid UTF-8.
+#[unsafe(no_mangle)]
+pub extern "C" fn runtime_init(
+    worker_threads: usize,
+    max_blocking_threads: usize,
+    thread_stack_size: usize,
+    thread_name_prefix: CSharpStr<'_>,
+    current_thread: bool,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let thread_name_prefix = match thread_name_prefix.as_cstr().map(|prefix| prefix.to_str()) {
+        None => None,
+        Some(Ok(prefix)) => Some(prefix.to_owned()),
+        Some(Err(err)) => {
+            return FfiException::from_error(RuntimeInitError::from(err), constructors);
+        }
+    };
+    let options = RuntimeOptions {
+        worker_threads: NonZeroUsize::new(worker_threads),
+        max_blocking_threads: NonZeroUsize::new(max_blocking_threads),
+        thread_stack_size: NonZeroUsize::new(thread_stack_size),
+        thread_name_prefix,
+        current_thread,
+    };
+
+    if RUNTIME.get().is_some() {
+        return FfiException::from_error(RuntimeInitError::AlreadyRunning, constructors);
+    }
+    let runtime = match BridgedRuntime::start(options) {
+        Ok(runtime) => runtime,
+        Err(err) => return FfiException::from_error(RuntimeInitError::from(err), constructors),
+    };
+    match RUNTIME.set(runtime) {
+        Ok(()) => FfiException::ok(),
+        // Another thread started the runtime in the meantime.
+        Err(runtime) => {
+            runtime.shutdown(Duration::ZERO);
+            FfiException::from_error(RuntimeInitError::AlreadyRunning, constructors)
+        }
+    }
+}
+
+/// Shuts down the global Tokio runtime, e.g. before the process or AppDomain is unloaded.
+///
+/// Waits at most `timeout_ms` for the runtime's threads to stop. Tasks still in flight
+/// are dropped without completing their C# tasks, so sessions should be shut down first.
+/// Tasks spawned and blocking calls issued afterwards fail with `AlreadyShutdownException`.
+/// Has no effect if the runtime is not running.
+///
+/// Must not be called from within a runtime thread, e.g. from a callback invoked by Rust.
+#[unsafe(no_mangle)]
+pub extern "C" fn runtime_shutdown(timeout_ms: u64) {
+    let Some(runtime) = RUNTIME.get() else {
+        return;
+    };
+    runtime.shutdown(Duration::from_millis(timeout_ms));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_conversion::testing::{CONSTRUCTORS, TestException};
+
+    // The global runtime is shared by all tests, so shutdown is checked on runtimes of their own.
+    fn start(current_thread: bool) -> BridgedRuntime {
+        BridgedRuntime::start(RuntimeOptions {
+            current_thread,
+            ..RuntimeOptions::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn nothing_runs_after_shutdown() {
+        for current_thread in [false, true] {
+            let runtime = start(current_thread);
+            assert_eq!(runtime.block_on(async { 42 }).unwrap(), 42);
+            assert!(runtime.spawn(async {}).is_ok());
+
+            runtime.shutdown(Duration::from_secs(1));
+
+            assert!(runtime.is_shut_down());
+            assert!(runtime.spawner().is_err());
+            assert!(runtime.spawn(async {}).is_err());
+            assert!(runtime.block_on(async { 42 }).is_err());
+            // Shutting down again has no effect.
+            runtime.shutdown(Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn shutdown_waits_for_spawner() {
+        let runtime = start(false);
+        let spawner = runtime.spawner().unwrap();
+
+        std::thread::scope(|scope| {
+            let shutdown = scope.spawn(|| runtime.shutdown(Duration::from_secs(1)));
+            std::thread::sleep(Duration::from_millis(50));
+            // The task is spawned onto a runtime that is still running.
+            assert!(!runtime.is_shut_down());
+            spawner.spawn(async {});
+
+            drop(spawner);
+            shutdown.join().unwrap();
+        });
+        assert!(runtime.is_shut_down());
+    }
+
+    #[test]
+    fn non_utf8_thread_name_prefix_is_rejected() {
+        let prefix = c"driver-\xff";
+        let result = runtime_init(0, 0, 0, CSharpStr::from_cstr(prefix), false, &CONSTRUCTORS);
+
+        let exception = TestException::from_ffi(result).unwrap();
+        assert_eq!(exception.kind, "RustException");
+        assert!(exception.message().contains("not valid UTF-8"));
+    }
+}
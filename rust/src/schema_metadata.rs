@@ -0,0 +1,528 @@
+// Accessors of the schema metadata held in a cluster state snapshot.
+//
+// All handles returned here borrow from the `BridgedClusterState` they were obtained from,
+// so they must not be used after it is freed.
+// Entries of a keyspace (tables, views and UDTs) are indexed in the iteration order of their maps.
+// The snapshot is never modified, so the order is the same for all calls on the same snapshot.
+
+use std::collections::HashMap;
+
+use scylla::cluster::metadata::{
+    Column, ColumnKind, Keyspace, MaterializedView, Strategy, Table, UserDefinedType,
+};
+use scylla::frame::response::result::ColumnType;
+
+use crate::CSharpStr;
+use crate::cluster_state::BridgedClusterState;
+use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, FFI, FFIStr, FromRef, RefFFI};
+use crate::row_set::column_type_to_code;
+
+impl FFI for Keyspace {
+    type Origin = FromRef;
+}
+
+impl FFI for Table {
+    type Origin = FromRef;
+}
+
+impl FFI for MaterializedView {
+    type Origin = FromRef;
+}
+
+impl FFI for UserDefinedType<'_> {
+    type Origin = FromRef;
+}
+
+/// Replication strategy of a keyspace.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedReplicationStrategy<'ks> {
+    /// One of: 0 - SimpleStrategy, 1 - NetworkTopologyStrategy, 2 - LocalStrategy, 3 - other.
+    kind: u8,
+    /// Fully qualified class name of the strategy.
+    class: FFIStr<'ks>,
+    /// Replication factor of SimpleStrategy, or 0 for other strategies.
+    replication_factor: usize,
+}
+
+/// A column of a table or a materialized view.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedColumn<'table> {
+    name: FFIStr<'table>,
+    /// One of: 0 - regular, 1 - static, 2 - clustering key, 3 - partition key.
+    kind: u8,
+    type_code: u8,
+    /// Handle to be passed to the `row_set_type_info_*` accessors. Never null, also for native types.
+    type_info_handle: BridgedBorrowedSharedPtr<'table, ColumnType<'table>>,
+    is_frozen: bool,
+}
+
+/// Returns the entry at `index` in the iteration order of `map`.
+fn nth_entry<'a, V>(map: &'a HashMap<String, V>, index: usize, fn_name: &str) -> (&'a str, &'a V) {
+    let Some((name, value)) = map.iter().nth(index) else {
+        panic!("Index out of bounds in {fn_name}");
+    };
+    (name, value)
+}
+
+// --- Keyspaces ---
+
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_keyspaces_count(
+    state_ptr: BridgedBorrowedSharedPtr<'_, BridgedClusterState>,
+) -> usize {
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_keyspaces_count");
+    };
+    state.cluster_state().keyspaces_iter().count()
+}
+
+/// Returns the keyspace at `index` and writes its name.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_get_keyspace<'state>(
+    state_ptr: BridgedBorrowedSharedPtr<'state, BridgedClusterState>,
+    index: usize,
+    out_name: *mut FFIStr<'state>,
+) -> BridgedBorrowedSharedPtr<'state, Keyspace> {
+    if out_name.is_null() {
+        panic!("Null pointer passed to cluster_state_get_keyspace");
+    }
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_get_keyspace");
+    };
+    let Some((name, keyspace)) = state.cluster_state().keyspaces_iter().nth(index) else {
+        panic!("Index out of bounds in cluster_state_get_keyspace");
+    };
+    unsafe {
+        out_name.write(FFIStr::new(name));
+    }
+    RefFFI::as_ptr(keyspace)
+}
+
+/// Returns the keyspace named `name`, or null if there is no such keyspace.
+#[unsafe(no_mangle)]
+pub extern "C" fn cluster_state_find_keyspace<'state>(
+    state_ptr: BridgedBorrowedSharedPtr<'state, BridgedClusterState>,
+    name: CSharpStr<'_>,
+) -> BridgedBorrowedSharedPtr<'state, Keyspace> {
+    let Some(state) = ArcFFI::as_ref(state_ptr) else {
+        panic!("Null pointer passed to cluster_state_find_keyspace");
+    };
+    let name = name.as_cstr().unwrap().to_str().unwrap();
+    match state.cluster_state().get_keyspace(name) {
+        Some(keyspace) => RefFFI::as_ptr(keyspace),
+        None => RefFFI::null(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_strategy<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+) -> BridgedReplicationStrategy<'ks> {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_strategy");
+    };
+    let (kind, class, replication_factor) = match &keyspace.strategy {
+        Strategy::SimpleStrategy { replication_factor } => (
+            0,
+            "org.apache.cassandra.locator.SimpleStrategy",
+            *replication_factor,
+        ),
+        Strategy::NetworkTopologyStrategy { .. } => {
+            (1, "org.apache.cassandra.locator.NetworkTopologyStrategy", 0)
+        }
+        Strategy::LocalStrategy => (2, "org.apache.cassandra.locator.LocalStrategy", 0),
+        Strategy::Other { name, .. } => (3, name.as_str(), 0),
+        _ => (3, "", 0),
+    };
+    BridgedReplicationStrategy {
+        kind,
+        class: FFIStr::new(class),
+        replication_factor,
+    }
+}
+
+/// Returns the number of per-datacenter replication factors of NetworkTopologyStrategy,
+/// or the number of options of an other strategy.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_strategy_options_count(
+    keyspace_handle: BridgedBorrowedSharedPtr<'_, Keyspace>,
+) -> usize {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_strategy_options_count");
+    };
+    match &keyspace.strategy {
+        Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors,
+        } => datacenter_repfactors.len(),
+        Strategy::Other { data, .. } => data.len(),
+        _ => 0,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_datacenter_replication_factor<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    index: usize,
+    out_datacenter: *mut FFIStr<'ks>,
+    out_replication_factor: *mut usize,
+) {
+    if out_datacenter.is_null() || out_replication_factor.is_null() {
+        panic!("Null pointer passed to keyspace_get_datacenter_replication_factor");
+    }
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_datacenter_replication_factor");
+    };
+    match &keyspace.strategy {
+        Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors,
+        } => {
+            let (datacenter, replication_factor) = nth_entry(
+                datacenter_repfactors,
+                index,
+                "keyspace_get_datacenter_replication_factor",
+            );
+            unsafe {
+                out_datacenter.write(FFIStr::new(datacenter));
+                out_replication_factor.write(*replication_factor);
+            }
+        }
+        _ => panic!(
+            "keyspace_get_datacenter_replication_factor called on non-NetworkTopologyStrategy keyspace"
+        ),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_strategy_option<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    index: usize,
+    out_name: *mut FFIStr<'ks>,
+    out_value: *mut FFIStr<'ks>,
+) {
+    if out_name.is_null() || out_value.is_null() {
+        panic!("Null pointer passed to keyspace_get_strategy_option");
+    }
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_strategy_option");
+    };
+    match &keyspace.strategy {
+        Strategy::Other { data, .. } => {
+            let (name, value) = nth_entry(data, index, "keyspace_get_strategy_option");
+            unsafe {
+                out_name.write(FFIStr::new(name));
+                out_value.write(FFIStr::new(value));
+            }
+        }
+        _ => panic!("keyspace_get_strategy_option called on a keyspace with a known strategy"),
+    }
+}
+
+// --- Tables ---
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_tables_count(
+    keyspace_handle: BridgedBorrowedSharedPtr<'_, Keyspace>,
+) -> usize {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_tables_count");
+    };
+    keyspace.tables.len()
+}
+
+/// Returns the table at `index` and writes its name.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_table<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    index: usize,
+    out_name: *mut FFIStr<'ks>,
+) -> BridgedBorrowedSharedPtr<'ks, Table> {
+    if out_name.is_null() {
+        panic!("Null pointer passed to keyspace_get_table");
+    }
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_table");
+    };
+    let (name, table) = nth_entry(&keyspace.tables, index, "keyspace_get_table");
+    unsafe {
+        out_name.write(FFIStr::new(name));
+    }
+    RefFFI::as_ptr(table)
+}
+
+/// Returns the table named `name`, or null if there is no such table.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_find_table<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    name: CSharpStr<'_>,
+) -> BridgedBorrowedSharedPtr<'ks, Table> {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_find_table");
+    };
+    let name = name.as_cstr().unwrap().to_str().unwrap();
+    match keyspace.tables.get(name) {
+        Some(table) => RefFFI::as_ptr(table),
+        None => RefFFI::null(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_partition_key_count(
+    table_handle: BridgedBorrowedSharedPtr<'_, Table>,
+) -> usize {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_partition_key_count");
+    };
+    table.partition_key.len()
+}
+
+/// Returns the name of the partition key column at `index`, in the order of the partition key.
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_partition_key<'table>(
+    table_handle: BridgedBorrowedSharedPtr<'table, Table>,
+    index: usize,
+) -> FFIStr<'table> {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_partition_key");
+    };
+    let Some(name) = table.partition_key.get(index) else {
+        panic!("Index out of bounds in table_get_partition_key");
+    };
+    FFIStr::new(name)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_clustering_key_count(
+    table_handle: BridgedBorrowedSharedPtr<'_, Table>,
+) -> usize {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_clustering_key_count");
+    };
+    table.clustering_key.len()
+}
+
+/// Returns the name of the clustering key column at `index`, in the order of the clustering key.
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_clustering_key<'table>(
+    table_handle: BridgedBorrowedSharedPtr<'table, Table>,
+    index: usize,
+) -> FFIStr<'table> {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_clustering_key");
+    };
+    let Some(name) = table.clustering_key.get(index) else {
+        panic!("Index out of bounds in table_get_clustering_key");
+    };
+    FFIStr::new(name)
+}
+
+/// Returns the partitioner of the table, or empty if it uses the cluster's default one.
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_partitioner<'table>(
+    table_handle: BridgedBorrowedSharedPtr<'table, Table>,
+) -> FFIStr<'table> {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_partitioner");
+    };
+    FFIStr::new(table.partitioner.as_deref().unwrap_or_default())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_columns_count(
+    table_handle: BridgedBorrowedSharedPtr<'_, Table>,
+) -> usize {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_columns_count");
+    };
+    table.columns.len()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn table_get_column<'table>(
+    table_handle: BridgedBorrowedSharedPtr<'table, Table>,
+    index: usize,
+) -> BridgedColumn<'table> {
+    let Some(table) = RefFFI::as_ref(table_handle) else {
+        panic!("Null pointer passed to table_get_column");
+    };
+    let (name, column) = nth_entry(&table.columns, index, "table_get_column");
+    bridged_column(name, column)
+}
+
+fn bridged_column<'table>(name: &'table str, column: &'table Column) -> BridgedColumn<'table> {
+    let kind = match column.kind {
+        ColumnKind::Regular => 0,
+        ColumnKind::Static => 1,
+        ColumnKind::Clustering => 2,
+        ColumnKind::PartitionKey => 3,
+        _ => 0,
+    };
+    let is_frozen = match &column.typ {
+        ColumnType::Collection { frozen, .. } | ColumnType::UserDefinedType { frozen, .. } => {
+            *frozen
+        }
+        _ => false,
+    };
+    BridgedColumn {
+        name: FFIStr::new(name),
+        kind,
+        type_code: column_type_to_code(&column.typ),
+        type_info_handle: RefFFI::as_ptr(&column.typ),
+        is_frozen,
+    }
+}
+
+// --- Materialized views ---
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_views_count(
+    keyspace_handle: BridgedBorrowedSharedPtr<'_, Keyspace>,
+) -> usize {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_views_count");
+    };
+    keyspace.views.len()
+}
+
+/// Returns the materialized view at `index` and writes its name.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_view<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    index: usize,
+    out_name: *mut FFIStr<'ks>,
+) -> BridgedBorrowedSharedPtr<'ks, MaterializedView> {
+    if out_name.is_null() {
+        panic!("Null pointer passed to keyspace_get_view");
+    }
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_view");
+    };
+    let (name, view) = nth_entry(&keyspace.views, index, "keyspace_get_view");
+    unsafe {
+        out_name.write(FFIStr::new(name));
+    }
+    RefFFI::as_ptr(view)
+}
+
+/// Returns the materialized view named `name`, or null if there is no such view.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_find_view<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    name: CSharpStr<'_>,
+) -> BridgedBorrowedSharedPtr<'ks, MaterializedView> {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_find_view");
+    };
+    let name = name.as_cstr().unwrap().to_str().unwrap();
+    match keyspace.views.get(name) {
+        Some(view) => RefFFI::as_ptr(view),
+        None => RefFFI::null(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn view_get_base_table_name<'ks>(
+    view_handle: BridgedBorrowedSharedPtr<'ks, MaterializedView>,
+) -> FFIStr<'ks> {
+    let Some(view) = RefFFI::as_ref(view_handle) else {
+        panic!("Null pointer passed to view_get_base_table_name");
+    };
+    FFIStr::new(&view.base_table_name)
+}
+
+/// Returns the columns and keys of the view, to be read with the `table_*` accessors.
+#[unsafe(no_mangle)]
+pub extern "C" fn view_get_table<'ks>(
+    view_handle: BridgedBorrowedSharedPtr<'ks, MaterializedView>,
+) -> BridgedBorrowedSharedPtr<'ks, Table> {
+    let Some(view) = RefFFI::as_ref(view_handle) else {
+        panic!("Null pointer passed to view_get_table");
+    };
+    RefFFI::as_ptr(&view.view_metadata)
+}
+
+// --- User defined types ---
+
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_udts_count(
+    keyspace_handle: BridgedBorrowedSharedPtr<'_, Keyspace>,
+) -> usize {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_udts_count");
+    };
+    keyspace.user_defined_types.len()
+}
+
+/// Returns the user defined type at `index`. Its name is read with `udt_get_name`.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_get_udt<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    index: usize,
+) -> BridgedBorrowedSharedPtr<'ks, UserDefinedType<'ks>> {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_get_udt");
+    };
+    let (_, udt) = nth_entry(&keyspace.user_defined_types, index, "keyspace_get_udt");
+    RefFFI::as_ptr(udt.as_ref())
+}
+
+/// Returns the user defined type named `name`, or null if there is no such type.
+#[unsafe(no_mangle)]
+pub extern "C" fn keyspace_find_udt<'ks>(
+    keyspace_handle: BridgedBorrowedSharedPtr<'ks, Keyspace>,
+    name: CSharpStr<'_>,
+) -> BridgedBorrowedSharedPtr<'ks, UserDefinedType<'ks>> {
+    let Some(keyspace) = RefFFI::as_ref(keyspace_handle) else {
+        panic!("Null pointer passed to keyspace_find_udt");
+    };
+    let name = name.as_cstr().unwrap().to_str().unwrap();
+    match keyspace.user_defined_types.get(name) {
+        Some(udt) => RefFFI::as_ptr(udt.as_ref()),
+        None => RefFFI::null(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_get_name<'ks>(
+    udt_handle: BridgedBorrowedSharedPtr<'ks, UserDefinedType<'ks>>,
+) -> FFIStr<'ks> {
+    let Some(udt) = RefFFI::as_ref(udt_handle) else {
+        panic!("Null pointer passed to udt_get_name");
+    };
+    FFIStr::new(udt.name.as_ref())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_get_field_count(
+    udt_handle: BridgedBorrowedSharedPtr<'_, UserDefinedType<'_>>,
+) -> usize {
+    let Some(udt) = RefFFI::as_ref(udt_handle) else {
+        panic!("Null pointer passed to udt_get_field_count");
+    };
+    udt.field_types.len()
+}
+
+/// Writes the name and the type of the field at `index`, in the order of the type definition.
+/// The type handle is to be passed to the `row_set_type_info_*` accessors.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_get_field<'ks>(
+    udt_handle: BridgedBorrowedSharedPtr<'ks, UserDefinedType<'ks>>,
+    index: usize,
+    out_field_name: *mut FFIStr<'ks>,
+    out_field_type_handle: *mut BridgedBorrowedSharedPtr<'ks, ColumnType<'ks>>,
+) {
+    if out_field_name.is_null() || out_field_type_handle.is_null() {
+        panic!("Null pointer passed to udt_get_field");
+    }
+    let Some(udt) = RefFFI::as_ref(udt_handle) else {
+        panic!("Null pointer passed to udt_get_field");
+    };
+    let Some((field_name, field_type)) = udt.field_types.get(index) else {
+        panic!("Index out of bounds in udt_get_field");
+    };
+    unsafe {
+        out_field_name.write(FFIStr::new(field_name.as_ref()));
+        out_field_type_handle.write(RefFFI::as_ptr(field_type));
+    }
+}
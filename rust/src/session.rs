@@ -1,21 +1,47 @@
 use std::convert::Infallible;
+use std::sync::Arc;
 
+use futures::future::abortable;
 use scylla::client::session::Session;
 use scylla::client::session_builder::SessionBuilder;
-use scylla::errors::{NewSessionError, PagerExecutionError, PrepareError};
+use scylla::cluster::ClusterState;
+use scylla::errors::{
+    ExecutionError, MetadataError, NewSessionError, PagerExecutionError, PrepareError,
+    RequestAttemptError, TracingError, UseKeyspaceError,
+};
+use scylla::frame::response::event::Event;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::statement::Consistency;
+use scylla::statement::unprepared::Statement;
 use scylla_cql::serialize::row::SerializedValues;
-use tokio::sync::RwLock;
+use thiserror::Error;
+use tokio::sync::{RwLock, broadcast};
+use uuid::Uuid;
 
 use crate::CSharpStr;
-use crate::error_conversion::MaybeShutdownError;
+use crate::batch::BridgedBatch;
+use crate::cluster_events::{
+    BridgedClusterEventCallbacks, BridgedClusterEventSubscription, CSharpClusterEventListener,
+    ClusterWatcher,
+};
+use crate::cluster_state::{BridgedClusterState, ReleaseVersions};
+use crate::error_conversion::{
+    ErrorToException, ExceptionPtr, FailedRequest, FailedSessionCreation, FfiException,
+    MaybeShutdownError,
+};
 use crate::ffi::{
     ArcFFI, BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, BridgedOwnedSharedPtr, FFI,
-    FromArc,
+    FFIByteSlice, FromArc,
 };
 use crate::pre_serialized_values::pre_serialized_values::PreSerializedValues;
 use crate::prepared_statement::BridgedPreparedStatement;
-use crate::row_set::RowSet;
-use crate::task::{BridgedFuture, Tcb};
+use crate::request_span::{RequestSpan, TraceParent, session_operation_span};
+use crate::row_set::{BufferRowsError, RowSet};
+use crate::runtime::runtime;
+use crate::session_config::BridgedSessionConfig;
+use crate::statement_options::BridgedStatementOptions;
+use crate::task::{BridgedFuture, BridgedTaskHandle, ExceptionConstructors, Tcb};
+use crate::tracing_info::BridgedTracingInfo;
 
 /// Internal representation of a session bridged to C#.
 /// It contains optional connected session state to allow for shutdown.
@@ -23,6 +49,50 @@ use crate::task::{BridgedFuture, Tcb};
 #[derive(Debug)]
 pub(crate) struct BridgedSessionInner {
     session: Option<Session>,
+    // Whether statement texts are recorded in request spans.
+    include_statement_in_spans: bool,
+    release_versions: Arc<ReleaseVersions>,
+}
+
+impl BridgedSessionInner {
+    /// Returns the current cluster state, or None if the session has been shut down.
+    pub(crate) fn cluster_state(&self) -> Option<Arc<ClusterState>> {
+        self.session
+            .as_ref()
+            .map(|session| session.get_cluster_state())
+    }
+
+    /// Versions of the nodes, which the driver does not track on its own.
+    pub(crate) fn release_versions(&self) -> &Arc<ReleaseVersions> {
+        &self.release_versions
+    }
+
+    /// Subscribes to the events the server pushes to the driver's control connection,
+    /// or returns None if the session has been shut down.
+    pub(crate) fn subscribe_server_events(&self) -> Option<broadcast::Receiver<Event>> {
+        self.session
+            .as_ref()
+            .map(|session| session.subscribe_server_events())
+    }
+}
+
+/// Creates the span of a request issued on the session.
+/// `session` is None if the session is shutting down.
+fn request_span(
+    session: Option<&BridgedSessionInner>,
+    operation: &'static str,
+    statement: Option<&str>,
+    consistency: Option<Consistency>,
+    trace_parent: CSharpStr<'_>,
+) -> RequestSpan {
+    RequestSpan::new(
+        operation,
+        session.and_then(|inner| inner.session.as_ref()),
+        session.is_some_and(|inner| inner.include_statement_in_spans),
+        statement,
+        consistency,
+        TraceParent::from_csharp(trace_parent),
+    )
 }
 
 /// BridgedSession is a thread-safe, asynchronously accessible session wrapper.
@@ -42,6 +112,50 @@ impl FFI for EmptyBridgedResult {
     type Origin = FromArc;
 }
 
+/// Errors of requests returning a single page of results, e.g. batches.
+#[derive(Error, Debug)]
+pub(crate) enum UnpagedExecutionError {
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
+
+    #[error(transparent)]
+    BufferRows(#[from] BufferRowsError),
+}
+
+impl ErrorToException for UnpagedExecutionError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match self {
+            UnpagedExecutionError::Execution(err) => err.to_exception(ctors),
+            UnpagedExecutionError::BufferRows(err) => err.to_exception(ctors),
+        }
+    }
+}
+
+/// Error of a paged request, together with the nodes it was sent to.
+type PagedRequestError = FailedRequest<PagerExecutionError>;
+
+/// Error of a request returning a single page, together with the nodes it was sent to.
+type UnpagedRequestError = FailedRequest<UnpagedExecutionError>;
+
+impl ErrorToException for UnpagedRequestError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        match &self.error {
+            UnpagedExecutionError::Execution(
+                ExecutionError::EmptyPlan
+                | ExecutionError::ConnectionPoolError(_)
+                | ExecutionError::LastAttemptError(
+                    RequestAttemptError::BrokenConnectionError(_)
+                    | RequestAttemptError::UnableToAllocStreamId,
+                ),
+            ) => self.no_host_available_exception(ctors),
+            UnpagedExecutionError::Execution(ExecutionError::RequestTimeout(timeout)) => {
+                self.operation_timed_out_exception(*timeout, ctors)
+            }
+            err => err.to_exception(ctors),
+        }
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn empty_bridged_result_free(ptr: BridgedOwnedSharedPtr<EmptyBridgedResult>) {
     ArcFFI::free(ptr);
@@ -49,25 +163,95 @@ pub extern "C" fn empty_bridged_result_free(ptr: BridgedOwnedSharedPtr<EmptyBrid
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn session_create(tcb: Tcb, uri: CSharpStr<'_>) {
+pub extern "C" fn session_create(
+    tcb: Tcb,
+    uri: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
     // Convert the raw C string to a Rust string
     let uri = uri.as_cstr().unwrap().to_str().unwrap();
     let uri = uri.to_owned();
+    let span = session_operation_span("connect", None);
+    // The spawned task runs within the span entered here.
+    let _entered = span.enter();
 
-    BridgedFuture::spawn::<_, _, NewSessionError>(tcb, async move {
+    BridgedFuture::spawn::<_, _, FailedSessionCreation>(tcb, async move {
         tracing::debug!("[FFI] Create Session... {}", uri);
-        let session = SessionBuilder::new().known_node(&uri).build().await?;
+        let session = match SessionBuilder::new().known_node(&uri).build().await {
+            Ok(session) => session,
+            Err(err) => return Err(failed_session_creation(err, &[uri]).await),
+        };
         tracing::info!("[FFI] Session created! URI: {}", uri);
         tracing::trace!(
             "[FFI] Contacted node's address: {}",
             session.get_cluster_state().get_nodes_info()[0].address
         );
+        let release_versions = Arc::new(ReleaseVersions::default());
+        release_versions.refresh(&session).await;
+        Ok(RwLock::new(BridgedSessionInner {
+            session: Some(session),
+            include_statement_in_spans: false,
+            release_versions,
+        }))
+    })
+}
+
+/// Creates a session configured with all options gathered in `BridgedSessionConfig`.
+///
+/// Takes ownership of the config, so the C# side must not free it afterwards.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_create_with_config(
+    tcb: Tcb,
+    config_ptr: BridgedOwnedExclusivePtr<BridgedSessionConfig>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    // Take ownership of the config first, so that it is not leaked if anything below panics.
+    let config = BoxFFI::from_ptr(config_ptr).expect("non-null BridgedSessionConfig pointer");
+    let include_statement_in_spans = config.include_statement_in_spans();
+    let span = session_operation_span("connect", None);
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, FailedSessionCreation>(tcb, async move {
+        let contact_points = config.contact_points().to_vec();
+        tracing::debug!("[FFI] Create Session... {}", contact_points.join(", "));
+        let session = match config.into_session_builder().build().await {
+            Ok(session) => session,
+            Err(err) => return Err(failed_session_creation(err, &contact_points).await),
+        };
+        tracing::info!(
+            "[FFI] Session created! Contact points: {}",
+            contact_points.join(", ")
+        );
+        let release_versions = Arc::new(ReleaseVersions::default());
+        release_versions.refresh(&session).await;
         Ok(RwLock::new(BridgedSessionInner {
             session: Some(session),
+            include_statement_in_spans,
+            release_versions,
         }))
     })
 }
 
+/// Attaches the addresses of the contact points to the error if none of them could be reached,
+/// so that each of them is reported. Contact points that do not resolve are left out.
+async fn failed_session_creation(
+    error: NewSessionError,
+    contact_points: &[String],
+) -> FailedSessionCreation {
+    let mut addresses = Vec::new();
+    if let NewSessionError::MetadataError(MetadataError::ConnectionPoolError(_)) = error {
+        for contact_point in contact_points {
+            match tokio::net::lookup_host(contact_point.as_str()).await {
+                Ok(resolved) => addresses.extend(resolved),
+                Err(err) => tracing::debug!(
+                    "[FFI] Failed to resolve contact point {}: {}",
+                    contact_point,
+                    err
+                ),
+            }
+        }
+    }
+    FailedSessionCreation::new(error, addresses)
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn session_free(session_ptr: BridgedOwnedSharedPtr<BridgedSession>) {
     ArcFFI::free(session_ptr);
@@ -80,12 +264,14 @@ pub extern "C" fn session_free(session_ptr: BridgedOwnedSharedPtr<BridgedSession
 pub extern "C" fn session_shutdown(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
-) {
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
     // Session pointer being null or invalid implies a serious error on the C# side.
     // We unwrap here to catch such issues early and panic.
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
 
     tracing::trace!("[FFI] Scheduling session shutdown");
+    let span = session_operation_span("shutdown", None);
+    let _entered = span.enter();
 
     BridgedFuture::spawn::<_, _, Infallible>(tcb, async move {
         tracing::debug!("[FFI] Shutting down session");
@@ -111,7 +297,8 @@ pub extern "C" fn session_prepare(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
-) {
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
     // Convert the raw C string to a Rust string.
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
@@ -124,6 +311,9 @@ pub extern "C" fn session_prepare(
     // Try to acquire an owned read lock.
     // If the operation fails, treat it as session shutting down.
     let session_guard_res = session_arc.try_read_owned();
+    // No request history is recorded for this operation, so it gets no request span.
+    let span = session_operation_span("prepare", TraceParent::from_csharp(trace_parent));
+    let _entered = span.enter();
 
     BridgedFuture::spawn::<_, _, MaybeShutdownError<PrepareError>>(tcb, async move {
         tracing::debug!("[FFI] Preparing statement \"{}\"", statement);
@@ -159,7 +349,14 @@ pub extern "C" fn session_query(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
-) {
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
     // Convert the raw C string to a Rust string.
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
@@ -173,8 +370,17 @@ pub extern "C" fn session_query(
     // Try to acquire an owned read lock.
     // If the operation fails, treat it as session shutting down.
     let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "query",
+        Some(&statement),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
 
-    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagerExecutionError>>(tcb, async move {
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagedRequestError>>(tcb, async move {
         tracing::debug!("[FFI] Executing statement \"{}\"", statement);
 
         let Ok(session_guard) = session_guard_res else {
@@ -192,17 +398,19 @@ pub extern "C" fn session_query(
         // preventing shutdown until this future completes
         // Map underlying `PagerExecutionError` into `MaybeShutdownError::Inner` so
         // the BridgedFuture's error type matches.
+        let mut statement = Statement::new(statement);
+        options.apply_to(&mut statement);
+        statement.set_history_listener(request_span.history_listener());
+
         let query_pager = session
             .query_iter(statement, ())
             .await
-            .map_err(MaybeShutdownError::Inner)?;
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e)))?;
 
         tracing::trace!("[FFI] Statement executed");
 
-        Ok(RowSet {
-            pager: std::sync::Mutex::new(Some(query_pager)),
-        })
-    });
+        Ok(RowSet::from_pager(query_pager).with_request_span(request_span))
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -211,13 +419,20 @@ pub extern "C" fn session_query_with_values(
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
     values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
-) {
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
     // Take ownership of the pre-serialized values box so we can move it into the async task.
     // Important: the order of operations here matters. We need to ensure we take ownership of the box first. In case any further operations panic,
     // we don't want to leak the pointer.
     // Note: this transfers ownership, so the C# side must not free it!
     let values_box = BoxFFI::from_ptr(values_ptr).expect("non-null PreSerializedValues pointer");
 
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
     // Convert the raw C string to a Rust string.
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
@@ -226,8 +441,17 @@ pub extern "C" fn session_query_with_values(
     // Try to acquire an owned read lock.
     // If the operation fails, treat it as session shutting down.
     let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "query",
+        Some(&statement),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
 
-    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagerExecutionError>>(tcb, async move {
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagedRequestError>>(tcb, async move {
         tracing::debug!(
             "[FFI] Preparing and executing statement with pre-serialized values \"{}\"",
             statement
@@ -246,10 +470,11 @@ pub extern "C" fn session_query_with_values(
 
         // First, prepare the statement. Map PrepareError into PagerExecutionError::PrepareError
         // and then into MaybeShutdownError::Inner so the error type matches.
-        let prepared = session
-            .prepare(statement)
-            .await
-            .map_err(|e| MaybeShutdownError::Inner(PagerExecutionError::PrepareError(e)))?;
+        let mut prepared = session.prepare(statement).await.map_err(|e| {
+            MaybeShutdownError::Inner(request_span.failed(PagerExecutionError::PrepareError(e)))
+        })?;
+        options.apply_to(&mut prepared);
+        prepared.set_history_listener(request_span.history_listener());
 
         // Convert our FFI wrapper into SerializedValues by consuming it.
         let serialized_values: SerializedValues = values_box.into_serialized_values();
@@ -259,14 +484,12 @@ pub extern "C" fn session_query_with_values(
         let query_pager = session
             .execute_iter_preserialized(prepared, serialized_values)
             .await
-            .map_err(MaybeShutdownError::Inner)?;
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e)))?;
 
         tracing::trace!("[FFI] Prepared statement executed with pre-serialized values");
 
-        Ok(RowSet {
-            pager: std::sync::Mutex::new(Some(query_pager)),
-        })
-    });
+        Ok(RowSet::from_pager(query_pager).with_request_span(request_span))
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -274,7 +497,14 @@ pub extern "C" fn session_query_bound(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
-) {
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
     let bridged_prepared = ArcFFI::cloned_from_ptr(prepared_statement_ptr).unwrap();
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
 
@@ -283,8 +513,17 @@ pub extern "C" fn session_query_bound(
     // Try to acquire an owned read lock.
     // If the operation fails, treat it as session shutting down.
     let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "execute",
+        Some(bridged_prepared.inner.get_statement()),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
 
-    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagerExecutionError>>(tcb, async move {
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagedRequestError>>(tcb, async move {
         tracing::debug!("[FFI] Executing prepared statement");
 
         let Ok(session_guard) = session_guard_res else {
@@ -302,27 +541,347 @@ pub extern "C" fn session_query_bound(
         // preventing shutdown until this future completes
         // Map underlying `PagerExecutionError` into `MaybeShutdownError::Inner` so
         // the BridgedFuture's error type matches.
+        let mut prepared = bridged_prepared.inner.clone();
+        options.apply_to(&mut prepared);
+        prepared.set_history_listener(request_span.history_listener());
+
         let query_pager = session
-            .execute_iter(bridged_prepared.inner.clone(), ())
+            .execute_iter(prepared, ())
             .await
-            .map_err(MaybeShutdownError::Inner)?;
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e)))?;
 
         tracing::trace!("[FFI] Prepared statement executed");
 
-        Ok(RowSet {
-            pager: std::sync::Mutex::new(Some(query_pager)),
-        })
+        Ok(RowSet::from_pager(query_pager).with_request_span(request_span))
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn session_query_bound_with_values(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    // Take ownership of the pre-serialized values box first, so that it is not leaked
+    // if any further operation panics.
+    // Note: this transfers ownership, so the C# side must not free it!
+    let values_box = BoxFFI::from_ptr(values_ptr).expect("non-null PreSerializedValues pointer");
+
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
+    let bridged_prepared = ArcFFI::cloned_from_ptr(prepared_statement_ptr).unwrap();
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling prepared statement execution with pre-serialized values");
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "execute",
+        Some(bridged_prepared.inner.get_statement()),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagedRequestError>>(tcb, async move {
+        tracing::debug!("[FFI] Executing prepared statement with pre-serialized values");
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Convert our FFI wrapper into SerializedValues by consuming it.
+        let serialized_values: SerializedValues = values_box.into_serialized_values();
+
+        let mut prepared = bridged_prepared.inner.clone();
+        options.apply_to(&mut prepared);
+        prepared.set_history_listener(request_span.history_listener());
+
+        // The statement is already prepared, so no PREPARE round trip is needed here.
+        let query_pager = session
+            .execute_iter_preserialized(prepared, serialized_values)
+            .await
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e)))?;
+
+        tracing::trace!("[FFI] Prepared statement executed with pre-serialized values");
+
+        Ok(RowSet::from_pager(query_pager).with_request_span(request_span))
     })
 }
 
-// TO DO: Handle setting keyspace in session_query
+/// Converts a paging state passed from C# into the scylla type.
+/// An empty slice means that the first page should be fetched.
+fn paging_state_from_csharp(paging_state: FFIByteSlice<'_>) -> PagingState {
+    // SAFETY: C# keeps the paging state buffer pinned for the duration of the call
+    // and the bytes are copied before it returns.
+    let bytes = unsafe { paging_state.as_slice() };
+    if bytes.is_empty() {
+        PagingState::start()
+    } else {
+        PagingState::new_from_raw_bytes(bytes.to_vec())
+    }
+}
+
+/// Returns the paging state of the following page, if there is one.
+fn next_paging_state(paging_state_response: PagingStateResponse) -> Option<PagingState> {
+    match paging_state_response {
+        PagingStateResponse::HasMorePages { state } => Some(state),
+        PagingStateResponse::NoMorePages => None,
+    }
+}
+
+/// Executes a single page of an unprepared statement, starting from the given paging state
+/// (empty to fetch the first page).
+/// The returned RowSet contains only the rows of that page. The paging state of the following
+/// page can be read with `row_set_get_paging_state`.
+///
+/// Note: this transfers ownership of `values_ptr` (which may be null), so the C# side must not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_query_single_page(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    statement: CSharpStr<'_>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    paging_state: FFIByteSlice<'_>,
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    // Take ownership of the values first, so that they are not leaked if any further operation panics.
+    let values = PreSerializedValues::from_nullable_ptr(values_ptr);
+
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
+    let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
+    let paging_state = paging_state_from_csharp(paging_state);
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!(
+        "[FFI] Scheduling single page of statement for execution: \"{}\"",
+        statement
+    );
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "query",
+        Some(&statement),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<UnpagedRequestError>>(tcb, async move {
+        tracing::debug!("[FFI] Executing single page of statement \"{}\"", statement);
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        let mut statement = Statement::new(statement);
+        options.apply_to(&mut statement);
+        statement.set_history_listener(request_span.history_listener());
+
+        let (result, paging_state_response) = session
+            .query_single_page(statement, values, paging_state)
+            .await
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))?;
+
+        tracing::trace!("[FFI] Single page of statement executed");
+
+        RowSet::from_query_result(result, next_paging_state(paging_state_response))
+            .map(|row_set| row_set.with_history(request_span.history()))
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))
+    })
+}
+
+/// Executes a single page of a prepared statement, starting from the given paging state
+/// (empty to fetch the first page).
+/// The returned RowSet contains only the rows of that page. The paging state of the following
+/// page can be read with `row_set_get_paging_state`.
+///
+/// Note: this transfers ownership of `values_ptr` (which may be null), so the C# side must not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_query_bound_single_page(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+    paging_state: FFIByteSlice<'_>,
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    // Take ownership of the values first, so that they are not leaked if any further operation panics.
+    let values = PreSerializedValues::from_nullable_ptr(values_ptr);
+
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+
+    let bridged_prepared = ArcFFI::cloned_from_ptr(prepared_statement_ptr).unwrap();
+    let paging_state = paging_state_from_csharp(paging_state);
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling single page of prepared statement for execution");
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "execute",
+        Some(bridged_prepared.inner.get_statement()),
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<UnpagedRequestError>>(tcb, async move {
+        tracing::debug!("[FFI] Executing single page of prepared statement");
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        let mut prepared = bridged_prepared.inner.clone();
+        options.apply_to(&mut prepared);
+        prepared.set_history_listener(request_span.history_listener());
+
+        let (result, paging_state_response) = session
+            .execute_single_page(&prepared, values, paging_state)
+            .await
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))?;
+
+        tracing::trace!("[FFI] Single page of prepared statement executed");
+
+        RowSet::from_query_result(result, next_paging_state(paging_state_response))
+            .map(|row_set| row_set.with_history(request_span.history()))
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))
+    })
+}
+
+/// Executes the batch and returns its result as a RowSet.
+/// The RowSet is empty, unless the batch is conditional - then it contains the `[applied]` row.
+///
+/// Note: this transfers ownership of `batch_ptr`, so the C# side must not free it.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_batch(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    batch_ptr: BridgedOwnedExclusivePtr<BridgedBatch>,
+    options: BridgedStatementOptions,
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    // Take ownership of the batch first, so that it is not leaked if any further operation panics.
+    // Note: this transfers ownership, so the C# side must not free it!
+    let batch_box = BoxFFI::from_ptr(batch_ptr).expect("non-null BridgedBatch pointer");
+    let (mut batch, values) = batch_box.into_parts();
+
+    let options = match options.validate() {
+        Ok(options) => options,
+        Err(err) => return BridgedFuture::fail(tcb, err),
+    };
+    options.apply_to(&mut batch);
+
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!(
+        "[FFI] Scheduling batch of {} statements for execution",
+        values.len()
+    );
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+    let request_span = request_span(
+        session_guard_res.as_deref().ok(),
+        "batch",
+        None,
+        options.consistency(),
+        trace_parent,
+    );
+    let span = request_span.span().clone();
+    batch.set_history_listener(request_span.history_listener());
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<UnpagedRequestError>>(tcb, async move {
+        tracing::debug!("[FFI] Executing batch");
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Lock is held for the entire duration of the batch operation,
+        // preventing shutdown until this future completes.
+        let result = session
+            .batch(&batch, values)
+            .await
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))?;
+
+        tracing::trace!("[FFI] Batch executed");
+
+        // Only conditional batches return rows.
+        RowSet::from_query_result(result, None)
+            .map(|row_set| row_set.with_history(request_span.history()))
+            .map_err(|e| MaybeShutdownError::Inner(request_span.failed(e.into())))
+    })
+}
+
+/// Switches the keyspace of all connections of the session.
+/// Resolves to an EmptyBridgedResult, which must be freed with `empty_bridged_result_free`.
 #[unsafe(no_mangle)]
 pub extern "C" fn session_use_keyspace(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     keyspace: CSharpStr<'_>,
     case_sensitive: bool,
-) {
+    trace_parent: CSharpStr<'_>,
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
     let keyspace = keyspace.as_cstr().unwrap().to_str().unwrap().to_owned();
     let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
 
@@ -335,8 +894,11 @@ pub extern "C" fn session_use_keyspace(
     // Try to acquire an owned read lock.
     // If the operation fails, treat it as session shutting down.
     let session_guard_res = session_arc.try_read_owned();
+    // No request history is recorded for this operation, so it gets no request span.
+    let span = session_operation_span("use_keyspace", TraceParent::from_csharp(trace_parent));
+    let _entered = span.enter();
 
-    BridgedFuture::spawn::<_, _, MaybeShutdownError<PagerExecutionError>>(tcb, async move {
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<UseKeyspaceError>>(tcb, async move {
         tracing::debug!("[FFI] Executing use_keyspace \"{}\"", keyspace);
 
         let Ok(session_guard) = session_guard_res else {
@@ -350,42 +912,172 @@ pub extern "C" fn session_use_keyspace(
             return Err(MaybeShutdownError::AlreadyShutdown);
         };
 
-        // TO DO: Fix error handling here to create a new C# exception type for
-        // UseKeyspaceError when use_keyspace isn't called anymore as part of Execute.
         // Use Session::use_keyspace() to update the Rust session's internal keyspace state.
         session
             .use_keyspace(&keyspace, case_sensitive)
             .await
-            .map_err(|e| {
-                // Error type conversion: UseKeyspaceError -> PagerExecutionError
-                // We need this because BridgedFuture expects PagerExecutionError to match RowSet return.
-                match e {
-                    scylla::errors::UseKeyspaceError::RequestError(req_err) => {
-                        // Common case: request failure (e.g., keyspace doesn't exist)
-                        let req_error: scylla::errors::RequestError = req_err.into();
-                        MaybeShutdownError::Inner(PagerExecutionError::NextPageError(
-                            req_error.into(),
-                        ))
-                    }
-                    scylla::errors::UseKeyspaceError::BadKeyspaceName(_)
-                    | scylla::errors::UseKeyspaceError::KeyspaceNameMismatch { .. }
-                    | scylla::errors::UseKeyspaceError::RequestTimeout(..)
-                    | _ => {
-                        // Catch-all for BadKeyspaceName, KeyspaceNameMismatch, RequestTimeout
-                        // and any future UseKeyspaceError variants (marked #[non_exhaustive])
-                        let req_attempt_err =
-                            scylla::errors::RequestAttemptError::UnexpectedResponse(
-                                scylla::errors::CqlResponseKind::Error,
-                            );
-                        let req_error: scylla::errors::RequestError = req_attempt_err.into();
-                        MaybeShutdownError::Inner(PagerExecutionError::NextPageError(
-                            req_error.into(),
-                        ))
-                    }
-                }
-            })?;
+            .map_err(MaybeShutdownError::Inner)?;
 
         tracing::trace!("[FFI] use_keyspace executed successfully");
-        Ok(RowSet::empty())
+        Ok(EmptyBridgedResult)
     })
 }
+
+/// Retrieves the trace of a request executed with tracing enabled, from the `system_traces` keyspace.
+/// `tracing_id` points to the 16 bytes of the tracing session id, in network byte order,
+/// as written by `execution_info_get_tracing_id`.
+///
+/// The trace is written by the nodes asynchronously, so it is fetched again if it is not complete
+/// yet, as configured with `session_config_set_tracing_info_fetch`. If it is still not available
+/// after the last attempt, the task fails with `TraceRetrievalException`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_get_tracing_info(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    tracing_id: *const [u8; 16],
+) -> BridgedOwnedSharedPtr<BridgedTaskHandle> {
+    if tracing_id.is_null() {
+        panic!("Null pointer passed to session_get_tracing_info");
+    }
+    // SAFETY: C# passes a non-null pointer to 16 readable bytes, pinned for the duration of this call.
+    // The id is copied out before the call returns.
+    let tracing_id = Uuid::from_bytes(unsafe { tracing_id.read() });
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling tracing info retrieval: {}", tracing_id);
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+    let span = session_operation_span("get_tracing_info", None);
+    let _entered = span.enter();
+
+    BridgedFuture::spawn::<_, _, MaybeShutdownError<TracingError>>(tcb, async move {
+        tracing::debug!("[FFI] Retrieving tracing info {}", tracing_id);
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(MaybeShutdownError::AlreadyShutdown);
+        };
+
+        let tracing_info = session
+            .get_tracing_info(&tracing_id)
+            .await
+            .map_err(MaybeShutdownError::Inner)?;
+
+        tracing::trace!("[FFI] Tracing info retrieved");
+
+        Ok(BridgedTracingInfo::from(tracing_info))
+    })
+}
+
+/// Writes a snapshot of the cluster topology, as currently known by the session.
+/// The snapshot does not change afterwards; it must be taken again to observe topology changes.
+/// The snapshot must be freed with `cluster_state_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_get_cluster_state(
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    out_state: *mut BridgedOwnedSharedPtr<BridgedClusterState>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    if out_state.is_null() {
+        panic!("Null pointer passed to session_get_cluster_state");
+    }
+    let Some(session_lock) = ArcFFI::as_ref(session_ptr) else {
+        panic!("Null pointer passed to session_get_cluster_state");
+    };
+
+    // If the lock cannot be acquired right away, the session is shutting down.
+    let state = session_lock.try_read().ok().and_then(|session_guard| {
+        let cluster_state = session_guard.cluster_state()?;
+        let state = BridgedClusterState::new(cluster_state, session_guard.release_versions());
+        let refresh =
+            state.has_unknown_versions() && session_guard.release_versions().start_refresh();
+        Some((state, refresh))
+    });
+    let Some((state, refresh)) = state else {
+        return FfiException::from_error(
+            MaybeShutdownError::<Infallible>::AlreadyShutdown,
+            constructors,
+        );
+    };
+
+    tracing::trace!(
+        "[FFI] Cluster state taken, {} nodes",
+        state.cluster_state().get_nodes_info().len()
+    );
+    if refresh {
+        refresh_release_versions(ArcFFI::cloned_from_ptr(session_ptr).unwrap());
+    }
+    let state = ArcFFI::into_ptr(Arc::new(state));
+    unsafe { out_state.write(state) };
+    FfiException::ok()
+}
+
+/// Queries the versions of the nodes in the background, so that later snapshots include them.
+/// Must be called only once `ReleaseVersions::start_refresh` allowed it.
+fn refresh_release_versions(session_arc: Arc<BridgedSession>) {
+    // After the runtime is shut down, there is no session to query anyway.
+    let _ = runtime().spawn(async move {
+        let session_guard = session_arc.read().await;
+        let Some(session) = session_guard.session.as_ref() else {
+            return;
+        };
+        session_guard.release_versions.refresh(session).await;
+    });
+}
+
+/// Subscribes to changes of the cluster: nodes being added, removed, going up or down,
+/// and schema changes of keyspaces, tables, views and user defined types.
+///
+/// The changes are detected from the events the server pushes to the driver's control
+/// connection, and reported through `callbacks` from a Tokio thread.
+/// Changes already present when subscribing are not reported.
+/// The subscription ends when it is freed with `cluster_event_subscription_free`,
+/// or when the session is shut down.
+///
+/// Takes ownership of the listener handle in `callbacks`, which is freed by Rust
+/// even if subscribing fails.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_subscribe_cluster_events(
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    callbacks: BridgedClusterEventCallbacks,
+    out_subscription: *mut BridgedOwnedExclusivePtr<BridgedClusterEventSubscription>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    // Take ownership of the listener first, so that it is freed if anything below fails.
+    let listener = CSharpClusterEventListener::new(callbacks);
+    if out_subscription.is_null() {
+        panic!("Null pointer passed to session_subscribe_cluster_events");
+    }
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::debug!("[FFI] Subscribing to cluster events");
+
+    // The watcher must not keep the session alive after C# frees it.
+    let session = Arc::downgrade(&session_arc);
+    let (watcher, abort_handle) = abortable(async move {
+        if let Some(watcher) = ClusterWatcher::new(session, listener).await {
+            watcher.run().await;
+        }
+    });
+    // If spawning fails, the watcher is dropped together with the listener, freeing its handle.
+    if let Err(err) = runtime().spawn(async move {
+        let _ = watcher.await;
+    }) {
+        return FfiException::from_error(err, constructors);
+    }
+
+    unsafe {
+        out_subscription.write(BoxFFI::into_ptr(Box::new(
+            BridgedClusterEventSubscription::new(abort_handle),
+        )))
+    };
+    FfiException::ok()
+}
@@ -0,0 +1,594 @@
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustls::ClientConfig;
+use scylla::client::execution_profile::ExecutionProfile;
+use scylla::client::session_builder::SessionBuilder;
+use scylla::client::{Compression, PoolSize};
+use scylla::policies::load_balancing::{DefaultPolicy, LoadBalancingPolicy};
+use scylla::statement::Consistency;
+use thiserror::Error;
+
+use crate::CSharpStr;
+use crate::authentication::{BridgedAuthenticatorCallbacks, CSharpAuthenticatorProvider};
+use crate::error_conversion::{ErrorToException, ExceptionPtr, FfiException};
+use crate::ffi::{
+    BoxFFI, BridgedBorrowedExclusivePtr, BridgedOwnedExclusivePtr, FFI, FFIByteSlice, FromBox,
+};
+use crate::task::ExceptionConstructors;
+use crate::tls::{TlsConfigError, TlsOptions};
+
+/// Session configuration gathered from C# before the session is created.
+///
+/// C# creates the config with `session_config_new`, fills it using the setters below
+/// and then passes its ownership to `session_create_with_config`.
+/// Options that were never set keep the Rust driver defaults.
+#[derive(Debug, Default)]
+pub struct BridgedSessionConfig {
+    contact_points: Vec<String>,
+    keyspace: Option<(String, bool)>,
+    connection_timeout: Option<Duration>,
+    // Outer Option: whether the timeout was set at all.
+    // Inner Option: `None` disables the client-side request timeout.
+    request_timeout: Option<Option<Duration>>,
+    load_balancing_policy: Option<Arc<dyn LoadBalancingPolicy>>,
+    compression: Option<Compression>,
+    pool_size: Option<PoolSize>,
+    credentials: Option<(String, String)>,
+    authenticator_provider: Option<Arc<CSharpAuthenticatorProvider>>,
+    tls_config: Option<Arc<ClientConfig>>,
+    tls_server_name: Option<String>,
+    include_statement_in_spans: bool,
+    tracing_info_fetch_attempts: Option<NonZeroU32>,
+    tracing_info_fetch_interval: Option<Duration>,
+    tracing_info_fetch_consistency: Option<Consistency>,
+}
+
+impl FFI for BridgedSessionConfig {
+    type Origin = FromBox;
+}
+
+/// Errors that may occur when C# passes an invalid option to one of the setters.
+#[derive(Error, Debug)]
+pub(crate) enum SessionConfigError {
+    #[error("Unknown compression code {0}")]
+    UnknownCompression(u8),
+
+    #[error("Connection pool size must be positive")]
+    ZeroPoolSize,
+
+    #[error("Unknown consistency code {0}")]
+    UnknownConsistency(i16),
+}
+
+impl ErrorToException for SessionConfigError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+impl BridgedSessionConfig {
+    /// Contact points the session will initially connect to.
+    pub(crate) fn contact_points(&self) -> &[String] {
+        &self.contact_points
+    }
+
+    /// Whether statement texts are recorded in request spans.
+    pub(crate) fn include_statement_in_spans(&self) -> bool {
+        self.include_statement_in_spans
+    }
+
+    /// Consumes the config and maps it onto a `SessionBuilder`.
+    pub(crate) fn into_session_builder(self) -> SessionBuilder {
+        let mut builder = SessionBuilder::new().known_nodes(&self.contact_points);
+
+        if let Some((keyspace, case_sensitive)) = self.keyspace {
+            builder = builder.use_keyspace(keyspace, case_sensitive);
+        }
+
+        if let Some(connection_timeout) = self.connection_timeout {
+            builder = builder.connection_timeout(connection_timeout);
+        }
+
+        if self.request_timeout.is_some() || self.load_balancing_policy.is_some() {
+            let mut profile = ExecutionProfile::builder();
+            if let Some(request_timeout) = self.request_timeout {
+                profile = profile.request_timeout(request_timeout);
+            }
+            if let Some(load_balancing_policy) = self.load_balancing_policy {
+                profile = profile.load_balancing_policy(load_balancing_policy);
+            }
+            builder = builder.default_execution_profile_handle(profile.build().into_handle());
+        }
+
+        if let Some(compression) = self.compression {
+            builder = builder.compression(Some(compression));
+        }
+
+        if let Some(pool_size) = self.pool_size {
+            builder = builder.pool_size(pool_size);
+        }
+
+        if let Some((username, password)) = self.credentials {
+            builder = builder.user(username, password);
+        }
+
+        if let Some(authenticator_provider) = self.authenticator_provider {
+            builder = builder.authenticator_provider(authenticator_provider);
+        }
+
+        if let Some(tls_config) = self.tls_config {
+            builder = builder.tls_context(Some(tls_config));
+            // Nodes are dialed by their IP addresses, so without a configured server name no SNI is sent.
+            if let Some(server_name) = self.tls_server_name {
+                builder = builder.tls_sni(server_name);
+            }
+        }
+
+        if let Some(attempts) = self.tracing_info_fetch_attempts {
+            builder = builder.tracing_info_fetch_attempts(attempts);
+        }
+        if let Some(interval) = self.tracing_info_fetch_interval {
+            builder = builder.tracing_info_fetch_interval(interval);
+        }
+        if let Some(consistency) = self.tracing_info_fetch_consistency {
+            builder = builder.tracing_info_fetch_consistency(consistency);
+        }
+
+        builder
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_new() -> BridgedOwnedExclusivePtr<BridgedSessionConfig> {
+    BoxFFI::into_ptr(Box::new(BridgedSessionConfig::default()))
+}
+
+/// Frees the config if it was not consumed by `session_create_with_config`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_free(config_ptr: BridgedOwnedExclusivePtr<BridgedSessionConfig>) {
+    BoxFFI::free(config_ptr);
+    tracing::trace!("[FFI] Session config freed");
+}
+
+/// Adds a contact point (`host` or `host:port`) to the config.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_add_contact_point(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    contact_point: CSharpStr<'_>,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_add_contact_point");
+    };
+    let contact_point = contact_point
+        .as_cstr()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+    config.contact_points.push(contact_point);
+}
+
+/// Sets the keyspace the session will use right after connecting.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_keyspace(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    keyspace: CSharpStr<'_>,
+    case_sensitive: bool,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_keyspace");
+    };
+    let keyspace = keyspace.as_cstr().unwrap().to_str().unwrap().to_owned();
+    config.keyspace = Some((keyspace, case_sensitive));
+}
+
+/// Sets the timeout for establishing a connection to a node.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_connection_timeout(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    timeout_ms: u64,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_connection_timeout");
+    };
+    config.connection_timeout = Some(Duration::from_millis(timeout_ms));
+}
+
+/// Sets the default client-side request timeout.
+/// A timeout of 0 disables the client-side timeout, as in the C# driver.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_request_timeout(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    timeout_ms: u64,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_request_timeout");
+    };
+    let timeout = (timeout_ms != 0).then(|| Duration::from_millis(timeout_ms));
+    config.request_timeout = Some(timeout);
+}
+
+/// Sets the default load balancing policy.
+/// If `local_dc` is not null, nodes of that datacenter are preferred and remote nodes are
+/// not queried. If `token_aware` is true, replicas of the partition are queried first.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_load_balancing(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    local_dc: CSharpStr<'_>,
+    token_aware: bool,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_load_balancing");
+    };
+    let mut policy = DefaultPolicy::builder().token_aware(token_aware);
+    if let Some(local_dc) = local_dc.as_cstr() {
+        policy = policy.prefer_datacenter(local_dc.to_str().unwrap().to_owned());
+    }
+    config.load_balancing_policy = Some(policy.build());
+}
+
+/// Sets the transport compression.
+/// Codes match the C# `CompressionType`: 0 - no compression, 1 - Snappy, 2 - LZ4.
+///
+/// Returns an exception for an unknown code, leaving the config unchanged.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_compression(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    compression: u8,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_compression");
+    };
+    config.compression = match compression {
+        0 => None,
+        1 => Some(Compression::Snappy),
+        2 => Some(Compression::Lz4),
+        _ => {
+            return FfiException::from_error(
+                SessionConfigError::UnknownCompression(compression),
+                constructors,
+            );
+        }
+    };
+    FfiException::ok()
+}
+
+/// Sets the connection pool size.
+/// If `per_shard` is true, `size` connections are opened to every shard of every node,
+/// otherwise `size` connections are opened to every node.
+///
+/// Returns an exception if `size` is zero, leaving the config unchanged.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_pool_size(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    size: usize,
+    per_shard: bool,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_pool_size");
+    };
+    let Some(size) = NonZeroUsize::new(size) else {
+        return FfiException::from_error(SessionConfigError::ZeroPoolSize, constructors);
+    };
+    config.pool_size = Some(if per_shard {
+        PoolSize::PerShard(size)
+    } else {
+        PoolSize::PerHost(size)
+    });
+    FfiException::ok()
+}
+
+/// Sets the credentials used by the `PasswordAuthenticator`,
+/// as supplied to the C# `PlainTextAuthProvider`.
+/// Overrides any authenticator set with `session_config_set_authenticator`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_credentials(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    username: CSharpStr<'_>,
+    password: CSharpStr<'_>,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_credentials");
+    };
+    let username = username.as_cstr().unwrap().to_str().unwrap().to_owned();
+    let password = password.as_cstr().unwrap().to_str().unwrap().to_owned();
+    config.authenticator_provider = None;
+    config.credentials = Some((username, password));
+}
+
+/// Sets a custom authenticator backed by a C# `IAuthProvider`.
+/// Overrides any credentials set with `session_config_set_credentials`.
+///
+/// Takes ownership of the provider handle inside `callbacks` - it is freed
+/// through `callbacks.free_provider` once no longer needed.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_authenticator(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    callbacks: BridgedAuthenticatorCallbacks,
+) {
+    // Wrap the callbacks first, so that the provider handle is freed even if we panic below.
+    let provider = Arc::new(CSharpAuthenticatorProvider::new(callbacks));
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_authenticator");
+    };
+    config.credentials = None;
+    config.authenticator_provider = Some(provider);
+}
+
+/// Enables TLS for all connections of the session.
+///
+/// Certificates and the private key are passed as PEM bytes, which are parsed immediately.
+/// `ca_certs_pem` must contain at least one CA certificate. Client certificate and key
+/// are optional (empty), but must be provided together.
+/// If `server_name` is not null, it is sent as SNI to every node and server certificates
+/// are verified against it instead of against the node's address.
+/// If `verify_hostname` is false, only the certificate chain is verified.
+///
+/// Returns an exception if the supplied options are invalid.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_tls(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    ca_certs_pem: FFIByteSlice<'_>,
+    client_cert_pem: FFIByteSlice<'_>,
+    client_key_pem: FFIByteSlice<'_>,
+    verify_hostname: bool,
+    server_name: CSharpStr<'_>,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_tls");
+    };
+
+    let server_name = match server_name.as_cstr().map(|name| (name, name.to_str())) {
+        None => None,
+        Some((_, Ok(name))) => Some(name),
+        Some((name, Err(_))) => {
+            let name = name.to_string_lossy().into_owned();
+            return FfiException::from_error(TlsConfigError::InvalidServerName(name), constructors);
+        }
+    };
+
+    // SAFETY: C# keeps the PEM buffers pinned for the duration of this call,
+    // and they are not used after it returns.
+    let options = unsafe {
+        TlsOptions {
+            ca_certs_pem: ca_certs_pem.as_slice(),
+            client_cert_pem: client_cert_pem.as_slice(),
+            client_key_pem: client_key_pem.as_slice(),
+            verify_hostname,
+            server_name,
+        }
+    };
+
+    match options.build_client_config() {
+        Ok(tls_config) => {
+            config.tls_config = Some(tls_config);
+            config.tls_server_name = server_name.map(str::to_owned);
+            FfiException::ok()
+        }
+        Err(err) => FfiException::from_error(err, constructors),
+    }
+}
+
+/// Sets whether statement texts are recorded in the `db.statement` field of request spans.
+/// Disabled by default, as statements may contain sensitive data.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_include_statement_in_spans(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    include_statement: bool,
+) {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_include_statement_in_spans");
+    };
+    config.include_statement_in_spans = include_statement;
+}
+
+/// Sets how traces are fetched by `session_get_tracing_info`, as they may not be complete
+/// right after the traced request finishes.
+/// `attempts` - how many times the trace is fetched before giving up,
+/// `interval_ms` - delay between the attempts,
+/// `consistency` - consistency code of the queries to `system_traces`.
+/// Zero `attempts` or `interval_ms`, and negative `consistency`, keep the defaults.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_config_set_tracing_info_fetch(
+    config_ptr: BridgedBorrowedExclusivePtr<'_, BridgedSessionConfig>,
+    attempts: u32,
+    interval_ms: u64,
+    consistency: i16,
+    constructors: &ExceptionConstructors,
+) -> FfiException {
+    let Some(config) = BoxFFI::as_mut_ref(config_ptr) else {
+        panic!("Null pointer passed to session_config_set_tracing_info_fetch");
+    };
+    let consistency = if consistency >= 0 {
+        let Ok(consistency) = Consistency::try_from(consistency as u16) else {
+            return FfiException::from_error(
+                SessionConfigError::UnknownConsistency(consistency),
+                constructors,
+            );
+        };
+        Some(consistency)
+    } else {
+        None
+    };
+    config.tracing_info_fetch_attempts = NonZeroU32::new(attempts);
+    config.tracing_info_fetch_interval =
+        (interval_ms > 0).then(|| Duration::from_millis(interval_ms));
+    config.tracing_info_fetch_consistency = consistency;
+    FfiException::ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_conversion::testing::{CONSTRUCTORS, TestException};
+    use crate::tls::testing::{SERVER_NAME, self_signed_cert, tls_listener};
+
+    fn into_config(
+        config_ptr: BridgedOwnedExclusivePtr<BridgedSessionConfig>,
+    ) -> BridgedSessionConfig {
+        *BoxFFI::from_ptr(config_ptr).unwrap()
+    }
+
+    #[test]
+    fn compression_codes_match_csharp_compression_type() {
+        for (code, expected) in [
+            (0, None),
+            (1, Some(Compression::Snappy)),
+            (2, Some(Compression::Lz4)),
+        ] {
+            let mut config_ptr = session_config_new();
+            let result =
+                session_config_set_compression(config_ptr.borrow_mut(), code, &CONSTRUCTORS);
+            assert!(!result.has_exception());
+            assert_eq!(into_config(config_ptr).compression, expected);
+        }
+    }
+
+    #[test]
+    fn unknown_compression_code_is_rejected() {
+        let mut config_ptr = session_config_new();
+        let result = session_config_set_compression(config_ptr.borrow_mut(), 3, &CONSTRUCTORS);
+        let exception = TestException::from_ffi(result).unwrap();
+        assert_eq!(exception.kind, "RustException");
+        assert!(exception.message().contains("Unknown compression code 3"));
+        assert!(into_config(config_ptr).compression.is_none());
+    }
+
+    #[test]
+    fn zero_pool_size_is_rejected() {
+        let mut config_ptr = session_config_new();
+        let result = session_config_set_pool_size(config_ptr.borrow_mut(), 0, true, &CONSTRUCTORS);
+        let exception = TestException::from_ffi(result).unwrap();
+        assert_eq!(exception.kind, "RustException");
+        assert!(into_config(config_ptr).pool_size.is_none());
+    }
+
+    #[test]
+    fn tracing_info_fetch_options_are_stored() {
+        let mut config_ptr = session_config_new();
+        let result = session_config_set_tracing_info_fetch(
+            config_ptr.borrow_mut(),
+            5,
+            10,
+            Consistency::Quorum as i16,
+            &CONSTRUCTORS,
+        );
+        assert!(!result.has_exception());
+
+        let config = into_config(config_ptr);
+        assert_eq!(config.tracing_info_fetch_attempts, NonZeroU32::new(5));
+        assert_eq!(
+            config.tracing_info_fetch_interval,
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            config.tracing_info_fetch_consistency,
+            Some(Consistency::Quorum)
+        );
+    }
+
+    #[test]
+    fn unknown_tracing_info_fetch_consistency_is_rejected() {
+        let mut config_ptr = session_config_new();
+        let result = session_config_set_tracing_info_fetch(
+            config_ptr.borrow_mut(),
+            5,
+            10,
+            0x7fff,
+            &CONSTRUCTORS,
+        );
+        let exception = TestException::from_ffi(result).unwrap();
+        assert_eq!(exception.kind, "RustException");
+        assert!(
+            exception
+                .message()
+                .contains("Unknown consistency code 32767")
+        );
+
+        let config = into_config(config_ptr);
+        assert!(config.tracing_info_fetch_attempts.is_none());
+        assert!(config.tracing_info_fetch_consistency.is_none());
+    }
+
+    #[test]
+    fn request_timeout_zero_disables_timeout() {
+        let mut config_ptr = session_config_new();
+        session_config_set_request_timeout(config_ptr.borrow_mut(), 0);
+        assert_eq!(into_config(config_ptr).request_timeout, Some(None));
+
+        let mut config_ptr = session_config_new();
+        session_config_set_request_timeout(config_ptr.borrow_mut(), 1500);
+        assert_eq!(
+            into_config(config_ptr).request_timeout,
+            Some(Some(Duration::from_millis(1500)))
+        );
+    }
+
+    #[test]
+    fn unset_options_keep_defaults() {
+        let config = into_config(session_config_new());
+        assert!(config.request_timeout.is_none());
+        assert!(config.load_balancing_policy.is_none());
+        assert!(config.pool_size.is_none());
+        assert!(config.compression.is_none());
+    }
+
+    #[test]
+    fn contact_points_and_load_balancing_are_stored() {
+        let mut config_ptr = session_config_new();
+        session_config_add_contact_point(
+            config_ptr.borrow_mut(),
+            CSharpStr::from_cstr(c"127.0.0.1:9042"),
+        );
+        session_config_add_contact_point(
+            config_ptr.borrow_mut(),
+            CSharpStr::from_cstr(c"127.0.0.2:9042"),
+        );
+        session_config_set_load_balancing(
+            config_ptr.borrow_mut(),
+            CSharpStr::from_cstr(c"dc1"),
+            true,
+        );
+        let result = session_config_set_pool_size(config_ptr.borrow_mut(), 2, true, &CONSTRUCTORS);
+        assert!(!result.has_exception());
+
+        let config = into_config(config_ptr);
+        assert_eq!(
+            config.contact_points(),
+            ["127.0.0.1:9042", "127.0.0.2:9042"]
+        );
+        assert!(config.load_balancing_policy.is_some());
+        assert!(matches!(config.pool_size, Some(PoolSize::PerShard(size)) if size.get() == 2));
+    }
+
+    #[tokio::test]
+    async fn session_sends_configured_server_name_as_sni() {
+        let cert = self_signed_cert();
+        let ca_pem = cert.cert.pem();
+        let (address, server) = tls_listener(&cert).await;
+        let tls_options = TlsOptions {
+            ca_certs_pem: ca_pem.as_bytes(),
+            client_cert_pem: &[],
+            client_key_pem: &[],
+            verify_hostname: true,
+            server_name: Some(SERVER_NAME),
+        };
+        let config = BridgedSessionConfig {
+            contact_points: vec![address.to_string()],
+            connection_timeout: Some(Duration::from_secs(5)),
+            tls_config: Some(tls_options.build_client_config().unwrap()),
+            tls_server_name: Some(SERVER_NAME.to_owned()),
+            ..Default::default()
+        };
+
+        // The listener does not speak CQL, so the session fails right after the TLS handshake.
+        assert!(config.into_session_builder().build().await.is_err());
+        let sni = server.await.unwrap().unwrap();
+        assert_eq!(sni.as_deref(), Some(SERVER_NAME));
+    }
+}
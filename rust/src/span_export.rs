@@ -0,0 +1,114 @@
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+
+use opentelemetry::Context;
+use opentelemetry::trace::{Status, TracerProvider as _};
+use opentelemetry_sdk::error::OTelSdkResult;
+use opentelemetry_sdk::trace::{
+    Sampler, SdkTracer, SdkTracerProvider, Span, SpanData, SpanProcessor,
+};
+
+use crate::ffi::FFIStr;
+use crate::logging::BridgedLogField;
+
+/// Function pointer type to pass a finished span to C#.
+///
+/// `parent` is the W3C `traceparent` of the C# `Activity` the span is a child of.
+/// `start_us` and `end_us` are microseconds since the Unix epoch.
+/// All strings and `attributes` are only valid for the duration of the call.
+type SpanCallback = unsafe extern "C" fn(
+    name: FFIStr<'_>,
+    parent: FFIStr<'_>,
+    start_us: i64,
+    end_us: i64,
+    is_error: bool,
+    attributes: *const BridgedLogField<'_>,
+    attributes_count: usize,
+);
+
+/// Set once C# starts receiving spans.
+static SPAN_CALLBACK: OnceLock<SpanCallback> = OnceLock::new();
+
+/// Tracer of the OpenTelemetry layer installed with logging, passing finished spans to C#.
+///
+/// Only spans with a C# `Activity` as parent are sampled - the same way as the `Activity`.
+pub(crate) fn tracer() -> SdkTracer {
+    SdkTracerProvider::builder()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::AlwaysOff)))
+        .with_span_processor(CSharpSpanProcessor)
+        .build()
+        .tracer("csharp_wrapper")
+}
+
+/// Passes every finished span whose parent is a C# `Activity` to `SPAN_CALLBACK`.
+///
+/// C# assigns its own ids to the activities created from the spans, so spans nested
+/// in other Rust spans (e.g. those of the Rust driver) could not be attached to their parents
+/// and are not passed.
+#[derive(Debug)]
+struct CSharpSpanProcessor;
+
+impl SpanProcessor for CSharpSpanProcessor {
+    fn on_start(&self, _span: &mut Span, _cx: &Context) {}
+
+    fn on_end(&self, span: SpanData) {
+        let Some(callback) = SPAN_CALLBACK.get() else {
+            return;
+        };
+        if !span.parent_span_is_remote {
+            return;
+        }
+
+        let parent = format!(
+            "00-{}-{}-{:02x}",
+            span.span_context.trace_id(),
+            span.parent_span_id,
+            span.span_context.trace_flags().to_u8()
+        );
+        let values: Vec<_> = span
+            .attributes
+            .iter()
+            .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()))
+            .collect();
+        let attributes: Vec<BridgedLogField> = values
+            .iter()
+            .map(|(name, value)| BridgedLogField::new(name, value))
+            .collect();
+
+        unsafe {
+            callback(
+                FFIStr::new(&span.name),
+                FFIStr::new(&parent),
+                unix_micros(span.start_time),
+                unix_micros(span.end_time),
+                matches!(span.status, Status::Error { .. }),
+                attributes.as_ptr(),
+                attributes.len(),
+            )
+        }
+    }
+
+    fn force_flush(&self) -> OTelSdkResult {
+        Ok(())
+    }
+
+    fn shutdown_with_timeout(&self, _timeout: Duration) -> OTelSdkResult {
+        Ok(())
+    }
+}
+
+fn unix_micros(time: SystemTime) -> i64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_micros() as i64)
+}
+
+/// Starts passing the spans of bridged operations to C# through `callback`,
+/// as children of the C# `Activity` that started each operation.
+///
+/// The spans are recorded by the subscriber installed with logging, so they are subject
+/// to the log filter. Only the first call has an effect.
+/// `callback` may be called from any thread, also concurrently, and must not throw.
+#[unsafe(no_mangle)]
+pub extern "C" fn span_export_init(callback: SpanCallback) {
+    let _ = SPAN_CALLBACK.set(callback);
+}
@@ -0,0 +1,255 @@
+use std::time::Duration;
+
+use scylla::statement::batch::Batch;
+use scylla::statement::prepared::PreparedStatement;
+use scylla::statement::unprepared::Statement;
+use scylla::statement::{Consistency, SerialConsistency};
+use thiserror::Error;
+
+use crate::error_conversion::{ErrorToException, ExceptionPtr};
+use crate::task::ExceptionConstructors;
+
+/// Per-statement execution options set on the C# `IStatement`.
+///
+/// Options that C# leaves unset keep the defaults of the session's execution profile.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BridgedStatementOptions {
+    /// Consistency code as defined by the CQL protocol, or -1 to use the default.
+    consistency: i16,
+    /// Serial consistency code as defined by the CQL protocol, or -1 to use the default.
+    serial_consistency: i16,
+    /// Page size, or a non-positive value to use the default.
+    page_size: i32,
+    /// Default timestamp in microseconds since the Unix epoch.
+    /// Used only if `has_timestamp` is set.
+    timestamp: i64,
+    /// Client-side request timeout in milliseconds, or a non-positive value to use the default.
+    request_timeout_ms: i32,
+    has_timestamp: bool,
+    is_idempotent: bool,
+    is_tracing: bool,
+}
+
+/// Errors that may occur when C# passes invalid statement options.
+#[derive(Error, Debug)]
+pub(crate) enum StatementOptionsError {
+    #[error("Unknown consistency code {0}")]
+    UnknownConsistency(i16),
+
+    #[error("Unknown serial consistency code {0}")]
+    UnknownSerialConsistency(i16),
+}
+
+impl ErrorToException for StatementOptionsError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors.rust_exception_constructor.construct_from_rust(self)
+    }
+}
+
+impl BridgedStatementOptions {
+    /// Decodes the options passed from C#.
+    pub(crate) fn validate(&self) -> Result<StatementOptions, StatementOptionsError> {
+        let consistency = (self.consistency >= 0)
+            .then(|| {
+                Consistency::try_from(self.consistency as u16)
+                    .map_err(|_| StatementOptionsError::UnknownConsistency(self.consistency))
+            })
+            .transpose()?;
+        let serial_consistency = (self.serial_consistency >= 0)
+            .then(|| {
+                SerialConsistency::try_from(self.serial_consistency).map_err(|_| {
+                    StatementOptionsError::UnknownSerialConsistency(self.serial_consistency)
+                })
+            })
+            .transpose()?;
+
+        Ok(StatementOptions {
+            consistency,
+            serial_consistency,
+            page_size: (self.page_size > 0).then_some(self.page_size),
+            timestamp: self.has_timestamp.then_some(self.timestamp),
+            request_timeout: (self.request_timeout_ms > 0)
+                .then(|| Duration::from_millis(self.request_timeout_ms as u64)),
+            is_idempotent: self.is_idempotent,
+            is_tracing: self.is_tracing,
+        })
+    }
+}
+
+/// Statement options decoded from `BridgedStatementOptions`.
+/// Options that are None keep the defaults of the session's execution profile.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StatementOptions {
+    consistency: Option<Consistency>,
+    serial_consistency: Option<SerialConsistency>,
+    page_size: Option<i32>,
+    timestamp: Option<i64>,
+    request_timeout: Option<Duration>,
+    is_idempotent: bool,
+    is_tracing: bool,
+}
+
+impl StatementOptions {
+    pub(crate) fn consistency(&self) -> Option<Consistency> {
+        self.consistency
+    }
+
+    /// Applies the options to an unprepared statement, a prepared statement or a batch.
+    pub(crate) fn apply_to(&self, statement: &mut impl ConfigurableStatement) {
+        if let Some(consistency) = self.consistency {
+            statement.set_consistency(consistency);
+        }
+        if let Some(serial_consistency) = self.serial_consistency {
+            statement.set_serial_consistency(Some(serial_consistency));
+        }
+        if let Some(page_size) = self.page_size {
+            statement.set_page_size(page_size);
+        }
+        if let Some(timestamp) = self.timestamp {
+            statement.set_timestamp(Some(timestamp));
+        }
+        if let Some(request_timeout) = self.request_timeout {
+            statement.set_request_timeout(Some(request_timeout));
+        }
+        statement.set_is_idempotent(self.is_idempotent);
+        statement.set_tracing(self.is_tracing);
+    }
+}
+
+/// Statement types that `StatementOptions` can be applied to.
+pub(crate) trait ConfigurableStatement {
+    fn set_consistency(&mut self, consistency: Consistency);
+    fn set_serial_consistency(&mut self, serial_consistency: Option<SerialConsistency>);
+    fn set_page_size(&mut self, page_size: i32);
+    fn set_timestamp(&mut self, timestamp: Option<i64>);
+    fn set_request_timeout(&mut self, timeout: Option<Duration>);
+    fn set_is_idempotent(&mut self, is_idempotent: bool);
+    fn set_tracing(&mut self, should_trace: bool);
+}
+
+/// Implements `ConfigurableStatement` by forwarding to the inherent setters of the type.
+macro_rules! impl_configurable_statement {
+    ($ty:ty, |$statement:ident, $page_size:ident| $set_page_size:expr) => {
+        impl ConfigurableStatement for $ty {
+            fn set_consistency(&mut self, consistency: Consistency) {
+                <$ty>::set_consistency(self, consistency)
+            }
+            fn set_serial_consistency(&mut self, serial_consistency: Option<SerialConsistency>) {
+                <$ty>::set_serial_consistency(self, serial_consistency)
+            }
+            fn set_page_size(&mut self, $page_size: i32) {
+                let $statement = self;
+                $set_page_size
+            }
+            fn set_timestamp(&mut self, timestamp: Option<i64>) {
+                <$ty>::set_timestamp(self, timestamp)
+            }
+            fn set_request_timeout(&mut self, timeout: Option<Duration>) {
+                <$ty>::set_request_timeout(self, timeout)
+            }
+            fn set_is_idempotent(&mut self, is_idempotent: bool) {
+                <$ty>::set_is_idempotent(self, is_idempotent)
+            }
+            fn set_tracing(&mut self, should_trace: bool) {
+                <$ty>::set_tracing(self, should_trace)
+            }
+        }
+    };
+}
+
+impl_configurable_statement!(Statement, |statement, page_size| {
+    Statement::set_page_size(statement, page_size)
+});
+impl_configurable_statement!(PreparedStatement, |prepared, page_size| {
+    PreparedStatement::set_page_size(prepared, page_size)
+});
+// Batches are not paged, so page size is ignored.
+impl_configurable_statement!(Batch, |_batch, _page_size| ());
+
+#[cfg(test)]
+mod tests {
+    use scylla::statement::batch::BatchType;
+
+    use super::*;
+
+    fn options(consistency: i16, serial_consistency: i16) -> BridgedStatementOptions {
+        BridgedStatementOptions {
+            consistency,
+            serial_consistency,
+            page_size: 0,
+            timestamp: 0,
+            request_timeout_ms: 0,
+            has_timestamp: false,
+            is_idempotent: false,
+            is_tracing: false,
+        }
+    }
+
+    #[test]
+    fn unknown_consistency_codes_are_rejected() {
+        assert!(matches!(
+            options(42, -1).validate(),
+            Err(StatementOptionsError::UnknownConsistency(42))
+        ));
+        // Only SERIAL (8) and LOCAL_SERIAL (9) are serial consistencies.
+        assert!(matches!(
+            options(-1, 1).validate(),
+            Err(StatementOptionsError::UnknownSerialConsistency(1))
+        ));
+    }
+
+    #[test]
+    fn unset_options_keep_statement_defaults() {
+        let mut statement = Statement::new("SELECT * FROM t");
+        let default_page_size = statement.get_page_size();
+        options(-1, -1).validate().unwrap().apply_to(&mut statement);
+
+        assert_eq!(statement.get_consistency(), None);
+        assert_eq!(statement.get_serial_consistency(), None);
+        assert_eq!(statement.get_page_size(), default_page_size);
+        assert_eq!(statement.get_timestamp(), None);
+        assert_eq!(statement.get_request_timeout(), None);
+    }
+
+    #[test]
+    fn options_are_applied_to_statements_and_batches() {
+        let bridged = BridgedStatementOptions {
+            page_size: 100,
+            timestamp: 1234,
+            request_timeout_ms: 500,
+            has_timestamp: true,
+            is_idempotent: true,
+            is_tracing: true,
+            ..options(
+                Consistency::Quorum as i16,
+                SerialConsistency::LocalSerial as i16,
+            )
+        };
+        let options = bridged.validate().unwrap();
+
+        let mut statement = Statement::new("SELECT * FROM t");
+        options.apply_to(&mut statement);
+        assert_eq!(statement.get_consistency(), Some(Consistency::Quorum));
+        assert_eq!(
+            statement.get_serial_consistency(),
+            Some(SerialConsistency::LocalSerial)
+        );
+        assert_eq!(statement.get_page_size(), 100);
+        assert_eq!(statement.get_timestamp(), Some(1234));
+        assert_eq!(
+            statement.get_request_timeout(),
+            Some(Duration::from_millis(500))
+        );
+        assert!(statement.get_is_idempotent());
+        assert!(statement.get_tracing());
+
+        let mut batch = Batch::new(BatchType::Logged);
+        options.apply_to(&mut batch);
+        assert_eq!(batch.get_consistency(), Some(Consistency::Quorum));
+        assert_eq!(batch.get_timestamp(), Some(1234));
+        assert!(batch.get_is_idempotent());
+        assert!(batch.get_tracing());
+    }
+}
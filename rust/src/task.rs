@@ -1,39 +1,32 @@
 use futures::FutureExt;
+use futures::future::{AbortHandle, Aborted, abortable};
 use std::ffi::c_void;
 use std::fmt::{Debug, Display};
 use std::future::Future;
 use std::panic::AssertUnwindSafe;
-use std::sync::{Arc, LazyLock};
-use tokio::runtime::Runtime;
+use std::sync::Arc;
+use tracing::{Instrument, Span};
 
 use crate::FfiPtr;
 use crate::error_conversion::{
     AlreadyExistsConstructor, AlreadyShutdownExceptionConstructor,
-    DeserializationExceptionConstructor, ErrorToException, ExceptionPtr,
-    FunctionFailureExceptionConstructor, InvalidConfigurationInQueryExceptionConstructor,
-    InvalidQueryConstructor, NoHostAvailableExceptionConstructor,
-    OperationTimedOutExceptionConstructor, PreparedQueryNotFoundExceptionConstructor,
-    RequestInvalidExceptionConstructor, RustExceptionConstructor,
-    SerializationExceptionConstructor, SyntaxErrorExceptionConstructor,
-    TraceRetrievalExceptionConstructor, TruncateExceptionConstructor,
-    UnauthorizedExceptionConstructor,
+    AuthenticationExceptionConstructor, DeserializationExceptionConstructor, ErrorToException,
+    ExceptionPtr, FunctionFailureExceptionConstructor,
+    InvalidConfigurationInQueryExceptionConstructor, InvalidContactPointExceptionConstructor,
+    InvalidKeyspaceExceptionConstructor, InvalidQueryConstructor,
+    IsBootstrappingExceptionConstructor, NoHostAvailableExceptionConstructor,
+    OperationCanceledExceptionConstructor, OperationTimedOutExceptionConstructor,
+    OverloadedExceptionConstructor, PreparedQueryNotFoundExceptionConstructor,
+    ReadFailureExceptionConstructor, ReadTimeoutExceptionConstructor,
+    RequestInvalidExceptionConstructor, ResultMetadataChangedExceptionConstructor,
+    RustExceptionConstructor, SerializationExceptionConstructor, ServerErrorExceptionConstructor,
+    SyntaxErrorExceptionConstructor, TlsExceptionConstructor, TraceRetrievalExceptionConstructor,
+    TruncateExceptionConstructor, UnauthorizedExceptionConstructor,
+    UnavailableExceptionConstructor, WriteFailureExceptionConstructor,
+    WriteTimeoutExceptionConstructor,
 };
-use crate::ffi::{ArcFFI, BridgedOwnedSharedPtr};
-
-/// The global Tokio runtime used to execute async tasks.
-static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
-    // Logger must be initialized for the logs to be emitted. As a good
-    // heuristic to initialize it early, we do it when creating the global
-    // Tokio runtime, which happens lazily on the first use of async tasks.
-    // This has a downside that if any logs are emitted before the first
-    // async task is spawned, they will be lost.
-    // To make this more robust, we could consider initializing the logger at
-    // the driver startup, but that would require a new call from C# to Rust,
-    // issued somehow early during the C# driver part.
-    crate::logging::init_logging();
-
-    Runtime::new().unwrap()
-});
+use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FromArc};
+use crate::runtime::{RuntimeShutDownError, runtime};
 
 /// Opaque type representing a C# TaskCompletionSource<T>.
 enum Tcs {}
@@ -73,20 +66,67 @@ pub struct Tcb {
 pub struct ExceptionConstructors {
     pub already_exists_constructor: AlreadyExistsConstructor,
     pub already_shutdown_exception_constructor: AlreadyShutdownExceptionConstructor,
+    pub authentication_exception_constructor: AuthenticationExceptionConstructor,
     pub deserialization_exception_constructor: DeserializationExceptionConstructor,
     pub function_failure_exception_constructor: FunctionFailureExceptionConstructor,
     pub invalid_configuration_in_query_constructor: InvalidConfigurationInQueryExceptionConstructor,
+    pub invalid_contact_point_exception_constructor: InvalidContactPointExceptionConstructor,
+    pub invalid_keyspace_exception_constructor: InvalidKeyspaceExceptionConstructor,
     pub invalid_query_constructor: InvalidQueryConstructor,
+    pub is_bootstrapping_exception_constructor: IsBootstrappingExceptionConstructor,
     pub no_host_available_exception_constructor: NoHostAvailableExceptionConstructor,
+    pub operation_canceled_exception_constructor: OperationCanceledExceptionConstructor,
     pub operation_timed_out_exception_constructor: OperationTimedOutExceptionConstructor,
+    pub overloaded_exception_constructor: OverloadedExceptionConstructor,
     pub prepared_query_not_found_exception_constructor: PreparedQueryNotFoundExceptionConstructor,
+    pub read_failure_exception_constructor: ReadFailureExceptionConstructor,
+    pub read_timeout_exception_constructor: ReadTimeoutExceptionConstructor,
     pub request_invalid_exception_constructor: RequestInvalidExceptionConstructor,
+    pub result_metadata_changed_exception_constructor: ResultMetadataChangedExceptionConstructor,
     pub rust_exception_constructor: RustExceptionConstructor,
     pub serialization_exception_constructor: SerializationExceptionConstructor,
+    pub server_error_exception_constructor: ServerErrorExceptionConstructor,
     pub syntax_error_exception_constructor: SyntaxErrorExceptionConstructor,
+    pub tls_exception_constructor: TlsExceptionConstructor,
     pub trace_retrieval_exception_constructor: TraceRetrievalExceptionConstructor,
     pub truncate_exception_constructor: TruncateExceptionConstructor,
     pub unauthorized_exception_constructor: UnauthorizedExceptionConstructor,
+    pub unavailable_exception_constructor: UnavailableExceptionConstructor,
+    pub write_failure_exception_constructor: WriteFailureExceptionConstructor,
+    pub write_timeout_exception_constructor: WriteTimeoutExceptionConstructor,
+}
+
+/// Handle to a task spawned with `BridgedFuture::spawn`, which lets C# cancel the task.
+///
+/// Freeing the handle does not affect the task.
+#[derive(Debug)]
+pub struct BridgedTaskHandle {
+    abort_handle: AbortHandle,
+}
+
+impl FFI for BridgedTaskHandle {
+    type Origin = FromArc;
+}
+
+/// Cancels the task, e.g. when the C# `CancellationToken` is triggered.
+///
+/// The task's future is dropped the next time it is polled, which releases everything
+/// it holds (e.g. the session lock and the connection stream of a request),
+/// and the C# task fails with `OperationCanceledException`.
+/// Has no effect if the task has already completed.
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_task_cancel(handle_ptr: BridgedBorrowedSharedPtr<'_, BridgedTaskHandle>) {
+    let Some(handle) = ArcFFI::as_ref(handle_ptr) else {
+        panic!("Null pointer passed to bridged_task_cancel");
+    };
+    handle.abort_handle.abort();
+    tracing::trace!("[FFI] Task cancellation requested");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_task_handle_free(handle_ptr: BridgedOwnedSharedPtr<BridgedTaskHandle>) {
+    ArcFFI::free(handle_ptr);
+    tracing::trace!("[FFI] Task handle freed");
 }
 
 /// A utility struct to bridge Rust tokio futures with C# tasks.
@@ -103,7 +143,9 @@ impl BridgedFuture {
     /// If the future panics, the panic is caught and reported as an exception to the C# side.
     /// The future must return a Result, where the Ok variant is sent back to C# on success,
     /// and the Err variant is sent back as an exception message.
-    pub(crate) fn spawn<F, T, E>(tcb: Tcb, future: F)
+    /// Returns a handle to cancel the task, in which case the task fails with `OperationCanceledException`.
+    /// The task runs within the span that is current when it is spawned, e.g. the span of the bridged request.
+    pub(crate) fn spawn<F, T, E>(tcb: Tcb, future: F) -> BridgedOwnedSharedPtr<BridgedTaskHandle>
     where
         F: Future<Output = Result<T, E>> + Send + 'static,
         T: Send + 'static + ArcFFI, // Must be shareable across FFI boundary. For now we only support ArcFFI.
@@ -118,7 +160,21 @@ impl BridgedFuture {
             constructors,
         } = tcb;
 
-        RUNTIME.spawn(async move {
+        // The task is completed exactly once, from within itself - even if it is cancelled.
+        let (future, abort_handle) = abortable(future.instrument(Span::current()));
+        let task_handle = ArcFFI::into_ptr(Arc::new(BridgedTaskHandle { abort_handle }));
+
+        // Spawning onto a runtime that is shut down would drop the future without completing the task.
+        let spawner = match runtime().spawner() {
+            Ok(spawner) => spawner,
+            Err(err) => {
+                tracing::trace!("[FFI]: Failing task spawned after runtime shutdown");
+                unsafe { fail_task(tcs, err.to_exception(constructors)) };
+                return task_handle;
+            }
+        };
+
+        spawner.spawn(async move {
             // Catch panics in the future to prevent unwinding tokio executor thread's stack.
             let result = AssertUnwindSafe(future).catch_unwind().await;
 
@@ -130,16 +186,23 @@ impl BridgedFuture {
 
             match result {
                 // On success, complete the task with the result.
-                Ok(Ok(res)) => {
+                Ok(Ok(Ok(res))) => {
                     let arced_res = Arc::new(res);
                     unsafe { complete_task(tcs, ArcFFI::into_ptr(arced_res).cast_to_void()) };
                 }
 
                 // On error, fail the task with exception.
-                Ok(Err(err)) => {
+                Ok(Ok(Err(err))) => {
                     let exception_ptr = err.to_exception(constructors);
                     unsafe { fail_task(tcs, exception_ptr) };
                 }
+                // On cancellation, fail the task with OperationCanceledException.
+                Ok(Err(Aborted)) => {
+                    let exception_ptr = constructors
+                        .operation_canceled_exception_constructor
+                        .construct_from_rust("The operation was canceled");
+                    unsafe { fail_task(tcs, exception_ptr) };
+                }
                 // On panic, fail the task with the panic message.
                 Err(panic) => {
                     // Panic payloads can be of any type, but `panic!()` macro only uses &str or String.
@@ -157,6 +220,22 @@ impl BridgedFuture {
                 }
             }
         });
+
+        task_handle
+    }
+
+    /// Fails the C# task right away, without spawning anything, e.g. if the arguments passed
+    /// from C# are invalid. Returns a handle like `spawn`, though cancelling it has no effect.
+    pub(crate) fn fail<E>(tcb: Tcb, error: E) -> BridgedOwnedSharedPtr<BridgedTaskHandle>
+    where
+        E: Debug + ErrorToException,
+    {
+        tracing::trace!("[FFI]: Failing task without spawning it: {:?}", error);
+        let exception_ptr = error.to_exception(tcb.constructors);
+        unsafe { (tcb.fail_task)(tcb.tcs, exception_ptr) };
+
+        let (_, abort_handle) = AbortHandle::new_pair();
+        ArcFFI::into_ptr(Arc::new(BridgedTaskHandle { abort_handle }))
     }
 
     /// Blocks the current thread until the provided future completes, returning its output.
@@ -164,8 +243,9 @@ impl BridgedFuture {
     /// This suits blocking APIs of the C# Driver that need to wait for an async operation to complete.
     /// Although it's inherently inefficient, it's not our choice - the C# Driver's blocking API is what it is.
     /// Use with caution and prefer async APIs whenever possible.
-    pub(crate) fn block_on<T>(future: impl Future<Output = T>) -> T {
-        RUNTIME.block_on(future)
+    /// Fails if the runtime has been shut down.
+    pub(crate) fn block_on<T>(future: impl Future<Output = T>) -> Result<T, RuntimeShutDownError> {
+        runtime().block_on(future)
     }
 }
 
@@ -188,3 +268,112 @@ impl std::fmt::Display for ImpossibleError {
 #[expect(dead_code)]
 /// A result type for futures that cannot fail.
 pub(crate) struct UnfallibleFutureResult<T>(Result<T, ImpossibleError>);
+
+/// Stand-in for a C# `TaskCompletionSource`, used to check how bridged tasks complete in unit tests.
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::error_conversion::testing::{CONSTRUCTORS, TestException};
+
+    /// How a task controlled by a [`tcb`] was completed.
+    #[derive(Debug)]
+    pub(crate) enum TaskOutcome {
+        /// The task completed with a result. The result is leaked, as its type is unknown here.
+        Completed,
+        Failed(TestException),
+    }
+
+    type Sender = mpsc::Sender<TaskOutcome>;
+
+    fn send(tcs: TcsPtr, outcome: TaskOutcome) {
+        let tcs = tcs.0.ptr.expect("Null TCS passed to a test callback");
+        // SAFETY: `tcb` passes a leaked `Box<Sender>` as the TCS, and a task is completed only once.
+        let sender = unsafe { Box::from_raw(tcs.cast::<Sender>().as_ptr()) };
+        // The test may have stopped waiting already.
+        let _ = sender.send(outcome);
+    }
+
+    unsafe extern "C" fn complete_task(tcs: TcsPtr, _result: BridgedOwnedSharedPtr<c_void>) {
+        send(tcs, TaskOutcome::Completed);
+    }
+
+    unsafe extern "C" fn fail_task(tcs: TcsPtr, exception_handle: ExceptionPtr) {
+        send(
+            tcs,
+            TaskOutcome::Failed(TestException::from_ptr(exception_handle)),
+        );
+    }
+
+    /// Creates a TCB whose task reports how it completed to the returned receiver.
+    pub(crate) fn tcb() -> (Tcb, mpsc::Receiver<TaskOutcome>) {
+        let (sender, receiver) = mpsc::channel::<TaskOutcome>();
+        let tcs = NonNull::from(Box::leak(Box::new(sender))).cast::<Tcs>();
+        let tcb = Tcb {
+            tcs: TcsPtr(FfiPtr {
+                ptr: Some(tcs),
+                _phantom: PhantomData,
+            }),
+            complete_task,
+            fail_task,
+            constructors: &CONSTRUCTORS,
+        };
+        (tcb, receiver)
+    }
+
+    /// Waits until the task completes, failing the test if it takes too long.
+    pub(crate) fn wait(receiver: &mpsc::Receiver<TaskOutcome>) -> TaskOutcome {
+        receiver
+            .recv_timeout(Duration::from_secs(10))
+            .expect("Bridged task did not complete in time")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use tokio::sync::oneshot;
+
+    use super::testing::{self, TaskOutcome};
+    use super::*;
+    use crate::session::EmptyBridgedResult;
+
+    #[test]
+    fn cancelled_task_fails_with_operation_canceled() {
+        let (tcb, receiver) = testing::tcb();
+        let handle = BridgedFuture::spawn(
+            tcb,
+            std::future::pending::<Result<EmptyBridgedResult, Infallible>>(),
+        );
+
+        bridged_task_cancel(handle.borrow());
+
+        match testing::wait(&receiver) {
+            TaskOutcome::Failed(exception) => {
+                assert_eq!(exception.kind, "OperationCanceledException")
+            }
+            outcome => panic!("Unexpected outcome: {outcome:?}"),
+        }
+        bridged_task_handle_free(handle);
+    }
+
+    #[test]
+    fn freeing_handle_does_not_cancel_task() {
+        let (tcb, receiver) = testing::tcb();
+        let (finish, finished) = oneshot::channel::<()>();
+        let handle = BridgedFuture::spawn(tcb, async move {
+            let _ = finished.await;
+            Ok::<_, Infallible>(EmptyBridgedResult)
+        });
+
+        bridged_task_handle_free(handle);
+        finish.send(()).unwrap();
+
+        assert!(matches!(testing::wait(&receiver), TaskOutcome::Completed));
+    }
+}
@@ -0,0 +1,331 @@
+use std::sync::Arc;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{verify_server_cert_signed_by_trust_anchor, verify_server_name};
+use rustls::crypto::{WebPkiSupportedAlgorithms, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::server::ParsedCertificate;
+use rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use thiserror::Error;
+
+use crate::error_conversion::{ErrorToException, ExceptionPtr};
+use crate::task::ExceptionConstructors;
+
+/// Errors that may occur while building a TLS context from the options supplied by C#.
+#[derive(Error, Debug)]
+pub(crate) enum TlsConfigError {
+    #[error("No CA certificate was provided")]
+    NoCaCertificates,
+
+    #[error("Failed to parse PEM data: {0}")]
+    Pem(#[from] rustls::pki_types::pem::Error),
+
+    #[error("Client certificate and private key must be provided together")]
+    IncompleteClientIdentity,
+
+    #[error("Invalid server name \"{0}\"")]
+    InvalidServerName(String),
+
+    #[error("Invalid TLS configuration: {0}")]
+    Rustls(#[from] rustls::Error),
+}
+
+impl ErrorToException for TlsConfigError {
+    fn to_exception(&self, ctors: &ExceptionConstructors) -> ExceptionPtr {
+        ctors
+            .tls_exception_constructor
+            .construct_from_rust(&self.to_string())
+    }
+}
+
+/// TLS options supplied by C#, with certificates and keys in PEM format.
+pub(crate) struct TlsOptions<'a> {
+    pub(crate) ca_certs_pem: &'a [u8],
+    pub(crate) client_cert_pem: &'a [u8],
+    pub(crate) client_key_pem: &'a [u8],
+    pub(crate) verify_hostname: bool,
+    pub(crate) server_name: Option<&'a str>,
+}
+
+impl TlsOptions<'_> {
+    /// Builds a rustls client config, which can be passed to `SessionBuilder::tls_context`.
+    pub(crate) fn build_client_config(&self) -> Result<Arc<ClientConfig>, TlsConfigError> {
+        let mut roots = RootCertStore::empty();
+        for cert in CertificateDer::pem_slice_iter(self.ca_certs_pem) {
+            roots.add(cert?)?;
+        }
+        if roots.is_empty() {
+            return Err(TlsConfigError::NoCaCertificates);
+        }
+
+        let server_name = self
+            .server_name
+            .map(|name| {
+                ServerName::try_from(name.to_owned())
+                    .map_err(|_| TlsConfigError::InvalidServerName(name.to_owned()))
+            })
+            .transpose()?;
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let verifier = BridgedServerCertVerifier {
+            roots,
+            supported_algs: provider.signature_verification_algorithms,
+            verify_hostname: self.verify_hostname,
+            server_name,
+        };
+
+        let builder = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier));
+
+        let config = match (self.client_cert_pem, self.client_key_pem) {
+            ([], []) => builder.with_no_client_auth(),
+            ([], _) | (_, []) => return Err(TlsConfigError::IncompleteClientIdentity),
+            (cert_pem, key_pem) => {
+                let certs = CertificateDer::pem_slice_iter(cert_pem).collect::<Result<_, _>>()?;
+                let key = PrivateKeyDer::from_pem_slice(key_pem)?;
+                builder.with_client_auth_cert(certs, key)?
+            }
+        };
+
+        Ok(Arc::new(config))
+    }
+}
+
+/// Verifies server certificates against the CA certificates supplied by C#.
+///
+/// The certificate chain is always verified. The name in the certificate is checked only
+/// if hostname verification is enabled - against the configured server name if there is one,
+/// or against the node's address otherwise. The Rust driver connects to nodes by their
+/// IP addresses, so a configured server name is the only way to verify DNS names.
+#[derive(Debug)]
+struct BridgedServerCertVerifier {
+    roots: RootCertStore,
+    supported_algs: WebPkiSupportedAlgorithms,
+    verify_hostname: bool,
+    server_name: Option<ServerName<'static>>,
+}
+
+impl ServerCertVerifier for BridgedServerCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let cert = ParsedCertificate::try_from(end_entity)?;
+        verify_server_cert_signed_by_trust_anchor(
+            &cert,
+            &self.roots,
+            intermediates,
+            now,
+            self.supported_algs.all,
+        )?;
+
+        if self.verify_hostname {
+            let server_name = self.server_name.as_ref().unwrap_or(server_name);
+            verify_server_name(&cert, server_name)?;
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.supported_algs)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.supported_algs)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.supported_algs.supported_schemes()
+    }
+}
+
+/// Returns the TLS error behind an I/O error, if the I/O error was caused by a failed TLS handshake.
+pub(crate) fn as_tls_error(io_err: &std::io::Error) -> Option<&rustls::Error> {
+    io_err.get_ref()?.downcast_ref::<rustls::Error>()
+}
+
+/// Self-signed TLS listener for tests of the TLS setup.
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    use rcgen::CertifiedKey;
+    use rustls::ServerConfig;
+    use rustls::pki_types::PrivatePkcs8KeyDer;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+    use tokio_rustls::TlsAcceptor;
+
+    pub(crate) const SERVER_NAME: &str = "node1.scylla.test";
+
+    /// Self-signed certificate valid only for `SERVER_NAME`.
+    pub(crate) fn self_signed_cert() -> CertifiedKey {
+        rcgen::generate_simple_self_signed([SERVER_NAME.to_owned()]).unwrap()
+    }
+
+    /// Starts a TLS listener on localhost serving `cert` to a single client.
+    /// The listener task returns the SNI sent by the client if the handshake succeeds.
+    pub(crate) async fn tls_listener(
+        cert: &CertifiedKey,
+    ) -> (SocketAddr, JoinHandle<std::io::Result<Option<String>>>) {
+        let key = PrivatePkcs8KeyDer::from(cert.key_pair.serialize_der());
+        let config =
+            ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .unwrap()
+                .with_no_client_auth()
+                .with_single_cert(vec![cert.cert.der().clone()], key.into())
+                .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let mut stream = acceptor.accept(stream).await?;
+            let sni = stream.get_ref().1.server_name().map(str::to_owned);
+            stream.write_all(b"ok").await?;
+            stream.shutdown().await?;
+            Ok(sni)
+        });
+        (address, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpStream;
+    use tokio_rustls::TlsConnector;
+
+    use super::testing::{SERVER_NAME, self_signed_cert, tls_listener};
+    use super::*;
+
+    /// Connects to `address` by its IP address, sending `options.server_name` as SNI if set.
+    /// Sessions connecting through the driver are tested in `session_config`.
+    async fn connect(options: &TlsOptions<'_>, address: SocketAddr) -> std::io::Result<()> {
+        let connector = TlsConnector::from(options.build_client_config().unwrap());
+        let server_name = match options.server_name {
+            Some(name) => ServerName::try_from(name.to_owned()).unwrap(),
+            None => ServerName::IpAddress(address.ip().into()),
+        };
+        let stream = TcpStream::connect(address).await?;
+        let mut stream = connector.connect(server_name, stream).await?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await?;
+        assert_eq!(response, b"ok");
+        Ok(())
+    }
+
+    fn options<'a>(ca_certs_pem: &'a [u8], server_name: Option<&'a str>) -> TlsOptions<'a> {
+        TlsOptions {
+            ca_certs_pem,
+            client_cert_pem: &[],
+            client_key_pem: &[],
+            verify_hostname: true,
+            server_name,
+        }
+    }
+
+    #[tokio::test]
+    async fn handshake_accepts_trusted_certificate_and_sends_sni() {
+        let cert = self_signed_cert();
+        let ca_pem = cert.cert.pem();
+        let (address, server) = tls_listener(&cert).await;
+
+        connect(&options(ca_pem.as_bytes(), Some(SERVER_NAME)), address)
+            .await
+            .unwrap();
+        let sni = server.await.unwrap().unwrap();
+        assert_eq!(sni.as_deref(), Some(SERVER_NAME));
+    }
+
+    #[tokio::test]
+    async fn handshake_rejects_untrusted_certificate() {
+        let cert = self_signed_cert();
+        let other_ca_pem = self_signed_cert().cert.pem();
+        let (address, server) = tls_listener(&cert).await;
+
+        let err = connect(
+            &options(other_ca_pem.as_bytes(), Some(SERVER_NAME)),
+            address,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            as_tls_error(&err),
+            Some(rustls::Error::InvalidCertificate(_))
+        ));
+        assert!(server.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn handshake_verifies_certificate_against_node_address_without_server_name() {
+        let cert = self_signed_cert();
+        let ca_pem = cert.cert.pem();
+
+        // The certificate is issued for SERVER_NAME, not for 127.0.0.1.
+        let (address, server) = tls_listener(&cert).await;
+        let err = connect(&options(ca_pem.as_bytes(), None), address)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            as_tls_error(&err),
+            Some(rustls::Error::InvalidCertificate(_))
+        ));
+        assert!(server.await.unwrap().is_err());
+
+        // Only the certificate chain is verified, and no SNI is sent for an IP address.
+        let (address, server) = tls_listener(&cert).await;
+        let options = TlsOptions {
+            verify_hostname: false,
+            ..options(ca_pem.as_bytes(), None)
+        };
+        connect(&options, address).await.unwrap();
+        assert_eq!(server.await.unwrap().unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        let ca_pem = self_signed_cert().cert.pem();
+
+        assert!(matches!(
+            options(&[], None).build_client_config(),
+            Err(TlsConfigError::NoCaCertificates)
+        ));
+        assert!(matches!(
+            options(ca_pem.as_bytes(), Some("not a server name")).build_client_config(),
+            Err(TlsConfigError::InvalidServerName(_))
+        ));
+        let only_cert = TlsOptions {
+            client_cert_pem: ca_pem.as_bytes(),
+            ..options(ca_pem.as_bytes(), None)
+        };
+        assert!(matches!(
+            only_cert.build_client_config(),
+            Err(TlsConfigError::IncompleteClientIdentity)
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use scylla::observability::tracing::{TracingEvent, TracingInfo};
+
+use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIStr, FromArc};
+
+/// Trace of a request, retrieved from the `system_traces` keyspace by `session_get_tracing_info`.
+///
+/// Fields missing from the trace, e.g. because it is not complete yet, are None.
+#[derive(Debug)]
+pub struct BridgedTracingInfo {
+    coordinator: Option<String>,
+    client: Option<String>,
+    request: Option<String>,
+    command: Option<String>,
+    duration_us: Option<i32>,
+    started_at_ms: Option<i64>,
+    parameters: Vec<(String, String)>,
+    events: Vec<BridgedTracingEventInfo>,
+}
+
+impl FFI for BridgedTracingInfo {
+    type Origin = FromArc;
+}
+
+#[derive(Debug)]
+struct BridgedTracingEventInfo {
+    timestamp_ms: Option<i64>,
+    activity: Option<String>,
+    source: Option<String>,
+    source_elapsed_us: Option<i32>,
+    thread: Option<String>,
+}
+
+impl From<TracingInfo> for BridgedTracingInfo {
+    fn from(info: TracingInfo) -> Self {
+        BridgedTracingInfo {
+            coordinator: info.coordinator.map(|address| address.to_string()),
+            client: info.client.map(|address| address.to_string()),
+            request: info.request,
+            command: info.command,
+            duration_us: info.duration,
+            started_at_ms: info.started_at.map(|timestamp| timestamp.0),
+            parameters: info.parameters.into_iter().flatten().collect(),
+            events: info.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TracingEvent> for BridgedTracingEventInfo {
+    fn from(event: TracingEvent) -> Self {
+        BridgedTracingEventInfo {
+            // Event ids are time-based UUIDs, generated when the events occur.
+            timestamp_ms: event.event_id.get_timestamp().map(|timestamp| {
+                let (seconds, nanos) = timestamp.to_unix();
+                seconds as i64 * 1000 + i64::from(nanos / 1_000_000)
+            }),
+            activity: event.activity,
+            source: event.source.map(|address| address.to_string()),
+            source_elapsed_us: event.source_elapsed,
+            thread: event.thread,
+        }
+    }
+}
+
+/// General fields of a trace. Strings which are missing in the trace are empty,
+/// and numbers are -1.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedTracingSession<'info> {
+    /// IP address of the coordinator.
+    coordinator: FFIStr<'info>,
+    /// IP address of the client which sent the request.
+    client: FFIStr<'info>,
+    /// Type of the request, e.g. `Execute CQL3 query`.
+    request: FFIStr<'info>,
+    command: FFIStr<'info>,
+    duration_us: i32,
+    /// Start of the request, in milliseconds since the Unix epoch.
+    started_at_ms: i64,
+}
+
+/// A single event of a trace. Strings which are missing in the trace are empty,
+/// and numbers are -1.
+/// All changes to this struct must be mirrored in C# code in the exact same order.
+#[repr(C)]
+pub struct BridgedTracingEvent<'info> {
+    /// Time of the event, in milliseconds since the Unix epoch.
+    timestamp_ms: i64,
+    activity: FFIStr<'info>,
+    /// IP address of the node on which the event occurred.
+    source: FFIStr<'info>,
+    /// Time since the request reached the source node, in microseconds.
+    source_elapsed_us: i32,
+    thread: FFIStr<'info>,
+}
+
+fn ffi_str_or_empty(s: &Option<String>) -> FFIStr<'_> {
+    FFIStr::new(s.as_deref().unwrap_or_default())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_free(info_ptr: BridgedOwnedSharedPtr<BridgedTracingInfo>) {
+    ArcFFI::free(info_ptr);
+    tracing::trace!("[FFI] Tracing info freed");
+}
+
+/// Returns the general fields of the trace.
+/// Its strings are valid as long as the tracing info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_get_session<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedTracingInfo>,
+) -> BridgedTracingSession<'info> {
+    let Some(info) = ArcFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to tracing_info_get_session");
+    };
+    BridgedTracingSession {
+        coordinator: ffi_str_or_empty(&info.coordinator),
+        client: ffi_str_or_empty(&info.client),
+        request: ffi_str_or_empty(&info.request),
+        command: ffi_str_or_empty(&info.command),
+        duration_us: info.duration_us.unwrap_or(-1),
+        started_at_ms: info.started_at_ms.unwrap_or(-1),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_get_parameters_count(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedTracingInfo>,
+) -> usize {
+    let Some(info) = ArcFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to tracing_info_get_parameters_count");
+    };
+    info.parameters.len()
+}
+
+/// Writes the name and the value of the request parameter at `index`.
+/// They are valid as long as the tracing info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_get_parameter<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedTracingInfo>,
+    index: usize,
+    out_name: *mut FFIStr<'info>,
+    out_value: *mut FFIStr<'info>,
+) {
+    if out_name.is_null() || out_value.is_null() {
+        panic!("Null pointer passed to tracing_info_get_parameter");
+    }
+    let Some(info) = ArcFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to tracing_info_get_parameter");
+    };
+    let Some((name, value)) = info.parameters.get(index) else {
+        panic!("Parameter index out of range in tracing_info_get_parameter");
+    };
+    unsafe {
+        out_name.write(FFIStr::new(name));
+        out_value.write(FFIStr::new(value));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_get_events_count(
+    info_ptr: BridgedBorrowedSharedPtr<'_, BridgedTracingInfo>,
+) -> usize {
+    let Some(info) = ArcFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to tracing_info_get_events_count");
+    };
+    info.events.len()
+}
+
+/// Returns the event at `index`, in the order the events occurred.
+/// Its strings are valid as long as the tracing info is alive.
+#[unsafe(no_mangle)]
+pub extern "C" fn tracing_info_get_event<'info>(
+    info_ptr: BridgedBorrowedSharedPtr<'info, BridgedTracingInfo>,
+    index: usize,
+) -> BridgedTracingEvent<'info> {
+    let Some(info) = ArcFFI::as_ref(info_ptr) else {
+        panic!("Null pointer passed to tracing_info_get_event");
+    };
+    let Some(event) = info.events.get(index) else {
+        panic!("Event index out of range in tracing_info_get_event");
+    };
+    BridgedTracingEvent {
+        timestamp_ms: event.timestamp_ms.unwrap_or(-1),
+        activity: ffi_str_or_empty(&event.activity),
+        source: ffi_str_or_empty(&event.source),
+        source_elapsed_us: event.source_elapsed_us.unwrap_or(-1),
+        thread: ffi_str_or_empty(&event.thread),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn event_time_is_taken_from_its_id() {
+        // Time-based UUID of 2024-01-01T00:00:00.123Z, in 100ns intervals since 1582-10-15.
+        let ticks: u64 = 17_040_672_001_230_000 + 0x01B2_1DD2_1381_4000;
+        let event_id = Uuid::from_fields(
+            ticks as u32,
+            (ticks >> 32) as u16,
+            ((ticks >> 48) as u16 & 0x0fff) | 0x1000,
+            &[0x80, 0, 1, 2, 3, 4, 5, 6],
+        );
+        let event = BridgedTracingEventInfo::from(TracingEvent {
+            event_id,
+            activity: Some("Parsing a statement".to_owned()),
+            source: None,
+            source_elapsed: Some(42),
+            thread: None,
+        });
+        assert_eq!(event.timestamp_ms, Some(1_704_067_200_123));
+        assert_eq!(event.source_elapsed_us, Some(42));
+
+        let event = BridgedTracingEventInfo::from(TracingEvent {
+            event_id: Uuid::nil(),
+            activity: None,
+            source: None,
+            source_elapsed: None,
+            thread: None,
+        });
+        assert_eq!(event.timestamp_ms, None);
+    }
+}
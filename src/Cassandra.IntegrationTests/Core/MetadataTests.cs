@@ -0,0 +1,246 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Concurrent;
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    [Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class MetadataTests : SharedClusterTest
+    {
+        private const string TableName = "metadata_rows";
+        private const string ViewName = "metadata_rows_by_value";
+        private const string UdtName = "metadata_address";
+
+        public MetadataTests() : base(2)
+        {
+        }
+
+        protected override string[] SetupQueries => new[]
+        {
+            $"CREATE TYPE {UdtName} (street text, numbers list<int>)",
+            $"CREATE TABLE {TableName} (id int, ck1 text, ck2 int, value text, shared int static, address frozen<{UdtName}>, " +
+                "PRIMARY KEY (id, ck1, ck2))",
+            $"CREATE MATERIALIZED VIEW {ViewName} AS SELECT * FROM {TableName} " +
+                "WHERE value IS NOT NULL AND id IS NOT NULL AND ck1 IS NOT NULL AND ck2 IS NOT NULL " +
+                "PRIMARY KEY (value, id, ck1, ck2)",
+        };
+
+        /// <summary>
+        /// The schema snapshot is refreshed in the background after schema changes.
+        /// </summary>
+        private void WaitForSchema(Func<bool> isUpToDate)
+        {
+            TestHelper.WaitUntil(isUpToDate, 500, 20);
+        }
+
+        [Test]
+        public void Metadata_AllHosts_Returns_All_Nodes()
+        {
+            var hosts = Cluster.AllHosts();
+
+            Assert.AreEqual(AmountOfNodes, hosts.Count);
+            Assert.AreEqual(AmountOfNodes, hosts.Select(h => h.HostId).Distinct().Count());
+            foreach (var host in hosts)
+            {
+                Assert.True(host.IsUp);
+                Assert.AreNotEqual(Guid.Empty, host.HostId);
+                Assert.NotNull(host.Datacenter);
+                Assert.NotNull(host.Rack);
+                Assert.NotNull(host.CassandraVersion);
+            }
+        }
+
+        [Test]
+        public void Metadata_GetHost_Finds_Hosts_By_Address()
+        {
+            var host = Cluster.AllHosts().First();
+
+            var found = Cluster.GetHost(host.Address);
+            Assert.NotNull(found);
+            Assert.AreEqual(host.HostId, found.HostId);
+            Assert.Null(Cluster.GetHost(new IPEndPoint(IPAddress.Parse("127.100.100.100"), 9042)));
+        }
+
+        [Test]
+        public void Metadata_Has_No_Hosts_Once_Sessions_Are_Shut_Down()
+        {
+            var cluster = GetNewTemporaryCluster();
+            var session = cluster.Connect();
+            Assert.AreEqual(AmountOfNodes, cluster.AllHosts().Count);
+
+            session.Dispose();
+            CollectionAssert.IsEmpty(cluster.AllHosts());
+        }
+    
+        [Test]
+        public void Metadata_GetKeyspace_Returns_Replication()
+        {
+            var keyspace = Cluster.Metadata.GetKeyspace(KeyspaceName);
+
+            Assert.NotNull(keyspace);
+            Assert.AreEqual(KeyspaceName, keyspace.Name);
+            Assert.True(keyspace.StrategyClass.EndsWith("SimpleStrategy"), keyspace.StrategyClass);
+            Assert.AreEqual(1, keyspace.Replication["replication_factor"]);
+            CollectionAssert.Contains(Cluster.Metadata.GetKeyspaces(), KeyspaceName);
+            Assert.Null(Cluster.Metadata.GetKeyspace("ks_that_does_not_exist"));
+        }
+
+        [Test]
+        public void Metadata_GetTable_Returns_Columns_And_Keys()
+        {
+            WaitForSchema(() => Cluster.Metadata.GetTable(KeyspaceName, TableName) != null);
+            var table = Cluster.Metadata.GetTable(KeyspaceName, TableName);
+
+            Assert.NotNull(table);
+            Assert.AreEqual(TableName, table.Name);
+            CollectionAssert.AreEquivalent(
+                new[] { "id", "ck1", "ck2", "value", "shared", "address" },
+                table.TableColumns.Select(c => c.Name));
+            CollectionAssert.AreEqual(new[] { "id" }, table.PartitionKeys.Select(c => c.Name));
+            CollectionAssert.AreEqual(new[] { "ck1", "ck2" }, table.ClusteringKeys.Select(c => c.Item1.Name));
+
+            var columns = table.ColumnsByName;
+            Assert.AreEqual(KeyType.Partition, columns["id"].KeyType);
+            Assert.AreEqual(KeyType.Clustering, columns["ck1"].KeyType);
+            Assert.AreEqual(KeyType.None, columns["value"].KeyType);
+            Assert.AreEqual(ColumnTypeCode.Varchar, columns["value"].TypeCode);
+            Assert.AreEqual(ColumnTypeCode.Int, columns["ck2"].TypeCode);
+            Assert.True(columns["shared"].IsStatic);
+            Assert.False(columns["value"].IsStatic);
+            Assert.AreEqual(ColumnTypeCode.Udt, columns["address"].TypeCode);
+            Assert.True(columns["address"].IsFrozen);
+            Assert.AreEqual(KeyspaceName, columns["value"].Keyspace);
+            Assert.AreEqual(TableName, columns["value"].Table);
+        }
+
+        [Test]
+        public void Metadata_GetTables_Lists_Tables_Of_A_Keyspace()
+        {
+            WaitForSchema(() => Cluster.Metadata.GetTables(KeyspaceName).Contains(TableName));
+
+            CollectionAssert.Contains(Cluster.Metadata.GetTables(KeyspaceName), TableName);
+            CollectionAssert.IsEmpty(Cluster.Metadata.GetTables("ks_that_does_not_exist"));
+            Assert.Null(Cluster.Metadata.GetTable(KeyspaceName, "table_that_does_not_exist"));
+
+            var keyspace = Cluster.Metadata.GetKeyspace(KeyspaceName);
+            CollectionAssert.Contains(keyspace.GetTablesNames(), TableName);
+            Assert.AreEqual(TableName, keyspace.GetTableMetadata(TableName).Name);
+            CollectionAssert.Contains(keyspace.GetTablesMetadata().Select(t => t.Name), TableName);
+        }
+
+        [Test]
+        public void Metadata_GetMaterializedView_Returns_View_Columns()
+        {
+            WaitForSchema(() => Cluster.Metadata.GetMaterializedView(KeyspaceName, ViewName) != null);
+            var view = Cluster.Metadata.GetMaterializedView(KeyspaceName, ViewName);
+
+            Assert.NotNull(view);
+            Assert.AreEqual(ViewName, view.Name);
+            CollectionAssert.AreEqual(new[] { "value" }, view.PartitionKeys.Select(c => c.Name));
+            CollectionAssert.AreEqual(new[] { "id", "ck1", "ck2" }, view.ClusteringKeys.Select(c => c.Item1.Name));
+            Assert.AreEqual(ViewName, Cluster.Metadata.GetKeyspace(KeyspaceName).GetMaterializedViewMetadata(ViewName).Name);
+        }
+
+        [Test]
+        public async Task Metadata_GetUdtDefinition_Returns_Fields()
+        {
+            WaitForSchema(() => Cluster.Metadata.GetUdtDefinition(KeyspaceName, UdtName) != null);
+            var udt = await Cluster.Metadata.GetUdtDefinitionAsync(KeyspaceName, UdtName).ConfigureAwait(false);
+
+            Assert.NotNull(udt);
+            CollectionAssert.AreEqual(new[] { "street", "numbers" }, udt.Fields.Select(f => f.Name));
+            Assert.AreEqual(ColumnTypeCode.Varchar, udt.Fields[0].TypeCode);
+            Assert.AreEqual(ColumnTypeCode.List, udt.Fields[1].TypeCode);
+            Assert.AreEqual(ColumnTypeCode.Int, ((ListColumnInfo)udt.Fields[1].TypeInfo).ValueTypeCode);
+            Assert.Null(Cluster.Metadata.GetUdtDefinition(KeyspaceName, "udt_that_does_not_exist"));
+        }
+    
+        [Test]
+        public void Metadata_GetReplicas_Follows_Replication_Factor()
+        {
+            var keyspace = TestUtils.GetUniqueKeyspaceName().ToLowerInvariant();
+            Session.CreateKeyspace(keyspace, new Dictionary<string, string>
+            {
+                { "class", ReplicationStrategies.SimpleStrategy },
+                { "replication_factor", AmountOfNodes.ToString() },
+            });
+            WaitForSchema(() => Cluster.Metadata.GetKeyspace(keyspace) != null);
+            var routingKey = new byte[] { 0, 0, 0, 1 };
+
+            var replicas = Cluster.Metadata.GetReplicas(keyspace, routingKey);
+            Assert.AreEqual(AmountOfNodes, replicas.Count);
+            CollectionAssert.AreEquivalent(
+                Cluster.AllHosts().Select(h => h.HostId),
+                replicas.Select(r => r.Host.HostId));
+
+            var single = Cluster.Metadata.GetReplicas(KeyspaceName, routingKey);
+            Assert.AreEqual(1, single.Count);
+            Assert.True(single.First().Host.IsUp);
+        }
+
+        [Test]
+        public void Metadata_GetReplicas_Without_Keyspace_Returns_Token_Owner()
+        {
+            var routingKey = new byte[] { 0, 0, 0, 1 };
+
+            var owner = Cluster.GetReplicas(routingKey);
+            Assert.AreEqual(1, owner.Count);
+            // With SimpleStrategy, the first replica is the node owning the token.
+            Assert.AreEqual(owner.First().Host.HostId, Cluster.GetReplicas(KeyspaceName, routingKey).First().Host.HostId);
+        }
+
+        [Test]
+        public void Metadata_GetReplicas_Of_Table_Matches_Keyspace_Replicas_Without_Tablets()
+        {
+            var routingKey = new byte[] { 0, 0, 0, 1 };
+
+            var replicas = Cluster.Metadata.GetReplicas(KeyspaceName, TableName, routingKey);
+            CollectionAssert.AreEqual(
+                Cluster.Metadata.GetReplicas(KeyspaceName, routingKey).Select(r => r.Host.HostId),
+                replicas.Select(r => r.Host.HostId));
+        }
+
+        [Test]
+        public void Metadata_SchemaChangedEvent_Reports_Table_Changes()
+        {
+            const string table = "metadata_events";
+            var events = new ConcurrentQueue<SchemaChangedEventArgs>();
+            Cluster.Metadata.SchemaChangedEvent += (sender, e) => events.Enqueue(e);
+
+            Session.Execute($"CREATE TABLE {KeyspaceName}.{table} (id int PRIMARY KEY)");
+            TestHelper.WaitUntil(() => events.Any(e =>
+                e.What == SchemaChangedEventArgs.Kind.Created && e.Keyspace == KeyspaceName && e.Table == table), 500, 20);
+
+            Session.Execute($"DROP TABLE {KeyspaceName}.{table}");
+            TestHelper.WaitUntil(() => events.Any(e =>
+                e.What == SchemaChangedEventArgs.Kind.Dropped && e.Keyspace == KeyspaceName && e.Table == table), 500, 20);
+
+            Assert.True(events.Any(e => e.What == SchemaChangedEventArgs.Kind.Created && e.Table == table));
+            Assert.True(events.Any(e => e.What == SchemaChangedEventArgs.Kind.Dropped && e.Table == table));
+        }
+    }
+}
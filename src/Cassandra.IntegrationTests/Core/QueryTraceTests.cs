@@ -0,0 +1,82 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    [Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class QueryTraceTests : SharedClusterTest
+    {
+        private const string TableName = "traced_rows";
+
+        public QueryTraceTests() : base(1)
+        {
+        }
+
+        protected override string[] SetupQueries =>
+            new[] { $"CREATE TABLE {TableName} (id int PRIMARY KEY)" }
+                .Concat(Enumerable.Range(0, 10).Select(i => $"INSERT INTO {TableName} (id) VALUES ({i})"))
+                .ToArray();
+
+        [Test]
+        public void QueryTrace_Is_Not_Set_When_Tracing_Is_Disabled()
+        {
+            var rs = Session.Execute(new SimpleStatement($"SELECT id FROM {TableName}"));
+            Assert.Null(rs.Info.QueryTrace);
+        }
+
+        [Test]
+        public async Task QueryTrace_Is_Loaded_For_Traced_Requests()
+        {
+            var rs = await Session.ExecuteAsync(new SimpleStatement($"SELECT id FROM {TableName}").EnableTracing()).ConfigureAwait(false);
+
+            Assert.NotNull(rs.Info.QueryTrace);
+            Assert.AreEqual(rs.Info.TracingId, rs.Info.QueryTrace.TraceId);
+
+            var trace = await rs.Info.GetQueryTraceAsync().ConfigureAwait(false);
+            Assert.NotNull(trace.Coordinator);
+            Assert.NotNull(trace.RequestType);
+            Assert.Greater(trace.DurationMicros, 0);
+            Assert.Greater(trace.Events.Count, 0);
+            Assert.True(trace.Events.All(e => e.Source != null));
+        }
+
+        [Test]
+        public void QueryTrace_Is_Loaded_Synchronously_On_First_Access()
+        {
+            var rs = Session.Execute(new SimpleStatement($"SELECT id FROM {TableName}").EnableTracing());
+            Assert.NotNull(rs.Info.QueryTrace.RequestType);
+            Assert.Greater(rs.Info.QueryTrace.Events.Count, 0);
+        }
+
+        [Test]
+        public async Task QueryTrace_Is_Loaded_For_Paged_Requests()
+        {
+            var rs = await Session.ExecuteAsync(new SimpleStatement($"SELECT id FROM {TableName}").SetPageSize(3).EnableTracing()).ConfigureAwait(false);
+
+            Assert.NotNull(rs.Info.QueryTrace);
+            var trace = await rs.Info.GetQueryTraceAsync().ConfigureAwait(false);
+            Assert.Greater(trace.Events.Count, 0);
+            Assert.AreEqual(10, rs.Count());
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    [Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class RowSetPagingTests : SharedClusterTest
+    {
+        private const string TableName = "paged_rows";
+        private const int RowsCount = 25;
+        private const int PageSize = 10;
+
+        public RowSetPagingTests() : base(1)
+        {
+        }
+
+        protected override string[] SetupQueries =>
+            new[] { $"CREATE TABLE {TableName} (id int PRIMARY KEY, value text)" }
+                .Concat(Enumerable.Range(0, RowsCount).Select(i => $"INSERT INTO {TableName} (id, value) VALUES ({i}, 'value{i}')"))
+                .ToArray();
+
+        private RowSet ExecutePaged()
+        {
+            return Session.Execute(new SimpleStatement($"SELECT id, value FROM {TableName}").SetPageSize(PageSize));
+        }
+
+        [Test]
+        public async Task RowSet_Async_Enumeration_Fetches_All_Pages()
+        {
+            var rs = ExecutePaged();
+
+            var ids = new List<int>();
+            await foreach (var row in rs)
+            {
+                ids.Add(row.GetValue<int>("id"));
+            }
+
+            CollectionAssert.AreEquivalent(Enumerable.Range(0, RowsCount), ids);
+            Assert.True(rs.IsExhausted());
+        }
+
+        [Test]
+        public async Task RowSet_FetchMoreResultsAsync_Buffers_A_Page()
+        {
+            var rs = ExecutePaged();
+            Assert.AreEqual(0, rs.GetAvailableWithoutFetching());
+
+            await rs.FetchMoreResultsAsync().ConfigureAwait(false);
+            // The first row of the next page may be buffered too, if the page has already arrived.
+            var buffered = rs.GetAvailableWithoutFetching();
+            Assert.GreaterOrEqual(buffered, PageSize);
+
+            // Fetching again does nothing while there are buffered rows.
+            await rs.FetchMoreResultsAsync().ConfigureAwait(false);
+            Assert.AreEqual(buffered, rs.GetAvailableWithoutFetching());
+
+            Assert.AreEqual(buffered, rs.Take(buffered).Count());
+            Assert.AreEqual(0, rs.GetAvailableWithoutFetching());
+            Assert.AreEqual(RowsCount - buffered, rs.Count());
+        }
+
+        [Test]
+        public void RowSet_PagingState_Fetches_Following_Pages_Manually()
+        {
+            var ids = new List<int>();
+            byte[] pagingState = null;
+            var pages = 0;
+            do
+            {
+                var statement = new SimpleStatement($"SELECT id, value FROM {TableName}")
+                    .SetPageSize(PageSize)
+                    .SetAutoPage(false)
+                    .SetPagingState(pagingState);
+                var rs = Session.Execute(statement);
+                ids.AddRange(rs.Select(row => row.GetValue<int>("id")));
+                pagingState = rs.PagingState;
+                pages++;
+            } while (pagingState != null);
+
+            CollectionAssert.AreEquivalent(Enumerable.Range(0, RowsCount), ids);
+            Assert.AreEqual((RowsCount + PageSize - 1) / PageSize, pages);
+        }
+
+        [Test]
+        public void RowSet_PagingState_Can_Be_Replaced()
+        {
+            var rs = Session.Execute(new SimpleStatement($"SELECT id, value FROM {TableName}")
+                .SetPageSize(PageSize)
+                .SetAutoPage(false));
+            Assert.NotNull(rs.PagingState);
+
+            rs.PagingState = null;
+            Assert.Null(rs.PagingState);
+
+            var pagingState = new byte[] { 1, 2, 3 };
+            rs.PagingState = pagingState;
+            CollectionAssert.AreEqual(pagingState, rs.PagingState);
+        }
+
+        [Test]
+        public void RowSet_Fails_When_Columns_Change_Between_Pages()
+        {
+            var tableName = "altered_" + TestUtils.GetUniqueTableName().ToLowerInvariant();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value text)");
+            for (var i = 0; i < RowsCount; i++)
+            {
+                Session.Execute($"INSERT INTO {tableName} (id, value) VALUES ({i}, 'value{i}')");
+            }
+
+            var rs = Session.Execute(new SimpleStatement($"SELECT * FROM {tableName}").SetPageSize(PageSize));
+            using var rows = rs.GetEnumerator();
+            Assert.True(rows.MoveNext());
+
+            Session.Execute($"ALTER TABLE {tableName} ADD added int");
+
+            // Rows of the following pages have an additional column, so they must not be
+            // deserialized with the columns of the first page.
+            Assert.Throws<ResultMetadataChangedException>(() =>
+            {
+                while (rows.MoveNext())
+                {
+                }
+            });
+        }
+
+        [Test]
+        public void RowSet_Execution_Info_Reports_The_Coordinator_Of_Paged_Results()
+        {
+            var rs = ExecutePaged();
+
+            Assert.NotNull(rs.Info.QueriedHost);
+            CollectionAssert.Contains(rs.Info.TriedHosts, rs.Info.QueriedHost);
+            Assert.NotNull(rs.Info.Latency);
+            Assert.Null(rs.Info.TracingId);
+        }
+
+        [Test]
+        public void RowSet_Execution_Info_Reports_The_Tracing_Id_Of_Paged_Results()
+        {
+            var rs = Session.Execute(new SimpleStatement($"SELECT id, value FROM {TableName}").SetPageSize(PageSize).EnableTracing());
+
+            Assert.NotNull(rs.Info.TracingId);
+        }
+
+        [Test]
+        public void RowSet_Deserializes_All_Values_Of_A_Page()
+        {
+            var tableName = "values_" + TestUtils.GetUniqueTableName().ToLowerInvariant();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, text_value text, list_value list<bigint>, blob_value blob)");
+            Session.Execute($"INSERT INTO {tableName} (id, text_value, list_value, blob_value) VALUES (1, 'one', [1, 2], 0x0102)");
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (2)");
+
+            var rows = Session.Execute($"SELECT id, text_value, list_value, blob_value FROM {tableName}")
+                .ToDictionary(row => row.GetValue<int>("id"));
+
+            Assert.AreEqual(2, rows.Count);
+            Assert.AreEqual("one", rows[1].GetValue<string>("text_value"));
+            CollectionAssert.AreEqual(new long[] { 1, 2 }, rows[1].GetValue<IEnumerable<long>>("list_value"));
+            CollectionAssert.AreEqual(new byte[] { 1, 2 }, rows[1].GetValue<byte[]>("blob_value"));
+            Assert.True(rows[2].IsNull("text_value"));
+            Assert.True(rows[2].IsNull("list_value"));
+            Assert.True(rows[2].IsNull("blob_value"));
+        }
+    }
+}
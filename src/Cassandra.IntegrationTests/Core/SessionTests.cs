@@ -14,8 +14,14 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Collections.Generic;
 using System.Diagnostics;
+using System.Linq;
+using System.Threading;
+using System.Threading.Tasks;
 
+using Cassandra.IntegrationTests.TestBase;
 using Cassandra.Tests;
 
 using NUnit.Framework;
@@ -34,7 +40,7 @@ namespace Cassandra.IntegrationTests.Core
         public void Session_Keyspace_Does_Not_Exist_On_Connect_Throws()
         {
             var localCluster = GetNewTemporaryCluster();
-            var ex = Assert.Throws<InvalidQueryException>(() => localCluster.Connect("THIS_KEYSPACE_DOES_NOT_EXIST"));
+            var ex = Assert.Throws<InvalidKeyspaceException>(() => localCluster.Connect("THIS_KEYSPACE_DOES_NOT_EXIST"));
             Assert.True(ex.Message.ToLower().Contains("keyspace"));
         }
 
@@ -53,7 +59,7 @@ namespace Cassandra.IntegrationTests.Core
         public void Session_Keyspace_Connect_Case_Sensitive()
         {
             var localCluster = GetNewTemporaryCluster();
-            Assert.Throws<InvalidQueryException>(() => localCluster.Connect("SYSTEM"));
+            Assert.Throws<InvalidKeyspaceException>(() => localCluster.Connect("SYSTEM"));
         }
 
         [Test]
@@ -118,8 +124,77 @@ namespace Cassandra.IntegrationTests.Core
         {
             var localCluster = GetNewTemporaryCluster();
             var localSession = localCluster.Connect();
-            var ex = Assert.Throws<InvalidQueryException>(() => localSession.ChangeKeyspace("THIS_KEYSPACE_DOES_NOT_EXIST_EITHER"));
+            var ex = Assert.Throws<InvalidKeyspaceException>(() => localSession.ChangeKeyspace("THIS_KEYSPACE_DOES_NOT_EXIST_EITHER"));
             Assert.True(ex.Message.ToLower().Contains("keyspace"));
         }
+
+        [Test]
+        public void Session_Execute_Throws_Unavailable_When_Replicas_Are_Missing()
+        {
+            var keyspace = TestUtils.GetUniqueKeyspaceName().ToLowerInvariant();
+            // More replicas than nodes, so that ALL can never be reached.
+            Session.CreateKeyspace(keyspace, new Dictionary<string, string>
+            {
+                { "class", ReplicationStrategies.SimpleStrategy },
+                { "replication_factor", "4" },
+            });
+            Session.Execute($"CREATE TABLE {keyspace}.unavailable_rows (id int PRIMARY KEY)");
+
+            var ex = Assert.Throws<UnavailableException>(() => Session.Execute(
+                new SimpleStatement($"INSERT INTO {keyspace}.unavailable_rows (id) VALUES (1)")
+                    .SetConsistencyLevel(ConsistencyLevel.All)));
+            Assert.AreEqual(ConsistencyLevel.All, ex.Consistency);
+            Assert.AreEqual(4, ex.RequiredReplicas);
+            Assert.AreEqual(3, ex.AliveReplicas);
+        }
+
+        [Test]
+        public void Diagnostics_SetNativeLogFilter_Invalid_Filter_Throws()
+        {
+            var ex = Assert.Throws<RustException>(() => Diagnostics.SetNativeLogFilter("scylla=notalevel"));
+            Assert.True(ex.Message.Contains("Invalid log filter"));
+        }
+
+        [Test]
+        public void DriverRuntime_Cannot_Be_Configured_Once_Running()
+        {
+            // The runtime has been started by the session of the fixture.
+            Assert.NotNull(Session);
+            var ex = Assert.Throws<RustException>(() => DriverRuntime.Configure(new DriverRuntimeOptions().SetWorkerThreads(2)));
+            Assert.True(ex.Message.Contains("already running"));
+        }
+
+        [Test]
+        public void Session_ExecuteAsync_With_Cancelled_Token_Throws()
+        {
+            using var cts = new CancellationTokenSource();
+            cts.Cancel();
+
+            Assert.CatchAsync<OperationCanceledException>(() =>
+                Session.ExecuteAsync(new SimpleStatement("SELECT * FROM system.local WHERE key='local'"), cts.Token));
+        }
+
+        [Test]
+        public void Session_ExecuteAsync_Bound_Statement_With_Cancelled_Token_Throws()
+        {
+            var ps = Session.Prepare("SELECT * FROM system.local WHERE key=?");
+            using var cts = new CancellationTokenSource();
+            cts.Cancel();
+
+            Assert.CatchAsync<OperationCanceledException>(() => Session.ExecuteAsync(ps.Bind("local"), cts.Token));
+        }
+
+        [Test]
+        public async Task Session_ExecuteAsync_Completes_When_Token_Is_Not_Cancelled()
+        {
+            using var cts = new CancellationTokenSource();
+
+            var rs = await Session.ExecuteAsync(new SimpleStatement("SELECT * FROM system.local WHERE key='local'"), cts.Token)
+                .ConfigureAwait(false);
+            Assert.AreEqual(1, rs.Count());
+
+            // Cancelling after the request has completed has no effect.
+            cts.Cancel();
+        }
     }
 }
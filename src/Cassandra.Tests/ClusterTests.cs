@@ -25,6 +25,7 @@ using Cassandra.ExecutionProfiles;
 using Moq;
 using NUnit.Framework;
 using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
 
 namespace Cassandra.Tests
 {
@@ -42,6 +43,33 @@ namespace Cassandra.Tests
             Assert.Throws<NoHostAvailableException>(() => cluster.Connect("sample_ks"));
         }
 
+        [Test]
+        public void ClusterConnectThrowsInvalidContactPoint_When_HostNameDoesNotResolve()
+        {
+            // The .invalid top-level domain never resolves.
+            var cluster = Cluster.Builder()
+             .AddContactPoint("scylla.invalid")
+             .Build();
+            Assert.Throws<InvalidContactPointException>(() => cluster.Connect());
+        }
+
+        [Test]
+        public void ClusterConnectReportsEachUnreachableContactPoint()
+        {
+            var cluster = Cluster.Builder()
+             .AddContactPoint("127.100.100.100")
+             .AddContactPoint("127.100.100.101")
+             .Build();
+            var ex = Assert.Throws<NoHostAvailableException>(() => cluster.Connect());
+            CollectionAssert.AreEquivalent(
+                new[]
+                {
+                    new IPEndPoint(IPAddress.Parse("127.100.100.100"), ProtocolOptions.DefaultPort),
+                    new IPEndPoint(IPAddress.Parse("127.100.100.101"), ProtocolOptions.DefaultPort),
+                },
+                ex.Errors.Keys);
+        }
+
         [Test]
         public void ClusterIsDisposableAfterInitError()
         {
@@ -52,5 +80,81 @@ namespace Cassandra.Tests
             Assert.Throws<NoHostAvailableException>(() => cluster.Connect());
             Assert.DoesNotThrow(cluster.Dispose);
         }
+
+        [Test]
+        public void BuilderStatementIsNotIncludedInTracesByDefault()
+        {
+            Assert.False(Cluster.Builder().AddContactPoint("127.0.0.1").GetConfiguration().IncludeStatementInTraces);
+
+            var config = Cluster.Builder()
+                .AddContactPoint("127.0.0.1")
+                .WithStatementInTraces(true)
+                .GetConfiguration();
+            Assert.True(config.IncludeStatementInTraces);
+        }
+
+        [Test]
+        public void ClusterEvents_Should_RaiseHostAddedAndHostRemoved()
+        {
+            var cluster = Cluster.Builder().AddContactPoint("127.0.0.1").Build();
+            var host = new Host(new IPEndPoint(IPAddress.Parse("127.0.0.2"), 9042), Guid.NewGuid(), "dc1", "rack1", null, true);
+            var added = new List<Host>();
+            var removed = new List<Host>();
+            cluster.HostAdded += added.Add;
+            cluster.HostRemoved += removed.Add;
+
+            ((IClusterEventListener)cluster).OnHostAdded(host);
+            ((IClusterEventListener)cluster).OnHostRemoved(host);
+
+            Assert.AreEqual(new[] { host }, added.ToArray());
+            Assert.AreEqual(new[] { host }, removed.ToArray());
+        }
+
+        [Test]
+        public void ClusterEvents_Should_RaiseMetadataEvents()
+        {
+            var cluster = Cluster.Builder().AddContactPoint("127.0.0.1").Build();
+            var address = new IPEndPoint(IPAddress.Parse("127.0.0.2"), 9042);
+            var host = new Host(address, Guid.NewGuid(), "dc1", "rack1", null, false);
+            var hostEvents = new List<HostsEventArgs>();
+            var schemaEvents = new List<SchemaChangedEventArgs>();
+            cluster.Metadata.HostsEvent += (sender, e) => hostEvents.Add(e);
+            cluster.Metadata.SchemaChangedEvent += (sender, e) => schemaEvents.Add(e);
+            var schemaChange = new SchemaChangedEventArgs { Keyspace = "ks1", Table = "tbl1", What = SchemaChangedEventArgs.Kind.Created };
+
+            ((IClusterEventListener)cluster).OnHostDown(host);
+            ((IClusterEventListener)cluster).OnHostUp(host);
+            ((IClusterEventListener)cluster).OnSchemaChanged(schemaChange);
+
+            Assert.AreEqual(2, hostEvents.Count);
+            Assert.AreEqual(address, hostEvents[0].Address);
+            Assert.AreEqual(HostsEventArgs.Kind.Down, hostEvents[0].What);
+            Assert.AreEqual(HostsEventArgs.Kind.Up, hostEvents[1].What);
+            Assert.AreEqual(new[] { schemaChange }, schemaEvents.ToArray());
+        }
+
+        [Test]
+        public void ClusterEventBridge_Should_MapSchemaChangeTargets()
+        {
+            var keyspace = ClusterEventBridge.ToSchemaChangedEventArgs(0, 0, "ks1", "");
+            Assert.AreEqual("ks1", keyspace.Keyspace);
+            Assert.IsNull(keyspace.Table);
+            Assert.IsNull(keyspace.Type);
+            Assert.AreEqual(SchemaChangedEventArgs.Kind.Created, keyspace.What);
+
+            var table = ClusterEventBridge.ToSchemaChangedEventArgs(1, 1, "ks1", "tbl1");
+            Assert.AreEqual("tbl1", table.Table);
+            Assert.AreEqual(SchemaChangedEventArgs.Kind.Dropped, table.What);
+
+            var udt = ClusterEventBridge.ToSchemaChangedEventArgs(2, 2, "ks1", "udt1");
+            Assert.IsNull(udt.Table);
+            Assert.AreEqual("udt1", udt.Type);
+            Assert.AreEqual(SchemaChangedEventArgs.Kind.Updated, udt.What);
+
+            var view = ClusterEventBridge.ToSchemaChangedEventArgs(0, 3, "ks1", "view1");
+            Assert.AreEqual("view1", view.Table);
+
+            Assert.IsNull(ClusterEventBridge.ToSchemaChangedEventArgs(0, 4, "ks1", "unknown"));
+        }
     }
 }
@@ -0,0 +1,68 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class DriverRuntimeOptionsTests
+    {
+        [Test]
+        public void DriverRuntimeOptions_Defaults_Keep_Native_Defaults()
+        {
+            var options = new DriverRuntimeOptions();
+            Assert.AreEqual(0, options.WorkerThreads);
+            Assert.AreEqual(0, options.MaxBlockingThreads);
+            Assert.AreEqual(0, options.ThreadStackSize);
+            Assert.Null(options.ThreadNamePrefix);
+            Assert.False(options.UseSingleThread);
+        }
+
+        [Test]
+        public void DriverRuntimeOptions_Setters_Set_Options()
+        {
+            var options = new DriverRuntimeOptions()
+                .SetWorkerThreads(4)
+                .SetMaxBlockingThreads(16)
+                .SetThreadStackSize(1 << 20)
+                .SetThreadNamePrefix("cassandra")
+                .SetUseSingleThread(true);
+            Assert.AreEqual(4, options.WorkerThreads);
+            Assert.AreEqual(16, options.MaxBlockingThreads);
+            Assert.AreEqual(1 << 20, options.ThreadStackSize);
+            Assert.AreEqual("cassandra", options.ThreadNamePrefix);
+            Assert.True(options.UseSingleThread);
+        }
+
+        [Test]
+        public void DriverRuntimeOptions_Negative_Values_Throw()
+        {
+            var options = new DriverRuntimeOptions();
+            Assert.Throws<ArgumentOutOfRangeException>(() => options.SetWorkerThreads(-1));
+            Assert.Throws<ArgumentOutOfRangeException>(() => options.SetMaxBlockingThreads(-1));
+            Assert.Throws<ArgumentOutOfRangeException>(() => options.SetThreadStackSize(-1));
+        }
+
+        [Test]
+        public void DriverRuntime_Configure_Null_Options_Throws()
+        {
+            Assert.Throws<ArgumentNullException>(() => DriverRuntime.Configure(null));
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Net;
+using System.Reflection;
+using System.Runtime.InteropServices;
+using System.Text;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    /// <summary>
+    /// Calls the exception constructors passed to Rust the way Rust does, through their native entry points.
+    /// </summary>
+    [TestFixture]
+    public class ExceptionsFromRustTests
+    {
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr MessageConstructor(FFIString message);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr UnavailableConstructor(short consistency, int required, int alive);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr ReadTimeoutConstructor(short consistency, int received, int required, byte dataPresent);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr WriteTimeoutConstructor(short consistency, int received, int required, FFIString writeType);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr ReadFailureConstructor(short consistency, int received, int required, int failures, byte dataPresent);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr WriteFailureConstructor(short consistency, int received, int required, int failures, FFIString writeType);
+
+        [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+        private delegate IntPtr NoHostAvailableConstructor(FFIString message, IntPtr hostErrors, nuint hostErrorsLen);
+
+        private static T GetConstructor<T>(Type exceptionType, string methodName) where T : Delegate
+        {
+            var method = exceptionType.GetMethod(methodName, BindingFlags.Static | BindingFlags.NonPublic);
+            Assert.NotNull(method, $"{exceptionType.Name}.{methodName} not found");
+            return Marshal.GetDelegateForFunctionPointer<T>(method.MethodHandle.GetFunctionPointer());
+        }
+
+        /// <summary>
+        /// Takes the exception out of the handle returned by a constructor, as C# does when throwing it.
+        /// </summary>
+        private static T TakeException<T>(IntPtr exceptionHandle) where T : Exception
+        {
+            var handle = GCHandle.FromIntPtr(exceptionHandle);
+            try
+            {
+                return (T)handle.Target;
+            }
+            finally
+            {
+                handle.Free();
+            }
+        }
+
+        /// <summary>
+        /// Passes the string as Rust does, valid only for the duration of the call.
+        /// </summary>
+        private static IntPtr WithFFIString(string value, Func<FFIString, IntPtr> call)
+        {
+            var ptr = Marshal.StringToCoTaskMemUTF8(value);
+            try
+            {
+                return call(new FFIString(ptr, (nuint)Encoding.UTF8.GetByteCount(value)));
+            }
+            finally
+            {
+                Marshal.FreeCoTaskMem(ptr);
+            }
+        }
+
+        [Test]
+        public void UnavailableException_Should_ExposeReplicaCounts()
+        {
+            var construct = GetConstructor<UnavailableConstructor>(
+                typeof(UnavailableException), nameof(UnavailableException.UnavailableExceptionFromRust));
+
+            var ex = TakeException<UnavailableException>(construct((short)ConsistencyLevel.Quorum, 3, 1));
+
+            Assert.AreEqual(ConsistencyLevel.Quorum, ex.Consistency);
+            Assert.AreEqual(3, ex.RequiredReplicas);
+            Assert.AreEqual(1, ex.AliveReplicas);
+        }
+
+        [Test]
+        public void ReadTimeoutException_Should_ExposeAcknowledgements()
+        {
+            var construct = GetConstructor<ReadTimeoutConstructor>(
+                typeof(ReadTimeoutException), nameof(ReadTimeoutException.ReadTimeoutExceptionFromRust));
+
+            var ex = TakeException<ReadTimeoutException>(construct((short)ConsistencyLevel.LocalQuorum, 1, 2, 1));
+
+            Assert.AreEqual(ConsistencyLevel.LocalQuorum, ex.ConsistencyLevel);
+            Assert.AreEqual(1, ex.ReceivedAcknowledgements);
+            Assert.AreEqual(2, ex.RequiredAcknowledgements);
+            Assert.True(ex.WasDataRetrieved);
+        }
+
+        [Test]
+        public void WriteTimeoutException_Should_ExposeWriteType()
+        {
+            var construct = GetConstructor<WriteTimeoutConstructor>(
+                typeof(WriteTimeoutException), nameof(WriteTimeoutException.WriteTimeoutExceptionFromRust));
+
+            var ex = TakeException<WriteTimeoutException>(
+                WithFFIString("BATCH_LOG", writeType => construct((short)ConsistencyLevel.One, 0, 1, writeType)));
+
+            Assert.AreEqual(ConsistencyLevel.One, ex.ConsistencyLevel);
+            Assert.AreEqual(0, ex.ReceivedAcknowledgements);
+            Assert.AreEqual(1, ex.RequiredAcknowledgements);
+            Assert.AreEqual("BATCH_LOG", ex.WriteType);
+        }
+
+        [Test]
+        public void ReadFailureException_Should_ExposeFailures()
+        {
+            var construct = GetConstructor<ReadFailureConstructor>(
+                typeof(ReadFailureException), nameof(ReadFailureException.ReadFailureExceptionFromRust));
+
+            var ex = TakeException<ReadFailureException>(construct((short)ConsistencyLevel.All, 1, 3, 2, 0));
+
+            Assert.AreEqual(ConsistencyLevel.All, ex.ConsistencyLevel);
+            Assert.AreEqual(1, ex.ReceivedAcknowledgements);
+            Assert.AreEqual(3, ex.RequiredAcknowledgements);
+            Assert.AreEqual(2, ex.Failures);
+            Assert.False(ex.WasDataRetrieved);
+        }
+
+        [Test]
+        public void WriteFailureException_Should_ExposeFailuresAndWriteType()
+        {
+            var construct = GetConstructor<WriteFailureConstructor>(
+                typeof(WriteFailureException), nameof(WriteFailureException.WriteFailureExceptionFromRust));
+
+            var ex = TakeException<WriteFailureException>(
+                WithFFIString("SIMPLE", writeType => construct((short)ConsistencyLevel.Two, 1, 2, 1, writeType)));
+
+            Assert.AreEqual(ConsistencyLevel.Two, ex.ConsistencyLevel);
+            Assert.AreEqual(1, ex.ReceivedAcknowledgements);
+            Assert.AreEqual(2, ex.RequiredAcknowledgements);
+            Assert.AreEqual(1, ex.Failures);
+            Assert.AreEqual("SIMPLE", ex.WriteType);
+        }
+
+        [Test]
+        public void MessageExceptions_Should_KeepServerMessage()
+        {
+            var constructors = new (Type Type, string Method)[]
+            {
+                (typeof(OverloadedException), nameof(OverloadedException.OverloadedExceptionFromRust)),
+                (typeof(IsBootstrappingException), nameof(IsBootstrappingException.IsBootstrappingExceptionFromRust)),
+                (typeof(ServerErrorException), nameof(ServerErrorException.ServerErrorExceptionFromRust)),
+            };
+            foreach (var (type, method) in constructors)
+            {
+                var construct = GetConstructor<MessageConstructor>(type, method);
+
+                var ex = TakeException<Exception>(WithFFIString("server message", construct.Invoke));
+
+                Assert.IsInstanceOf(type, ex);
+                Assert.AreEqual("server message", ex.Message);
+            }
+        }
+
+        [Test]
+        public void NoHostAvailableException_Should_SkipHostErrorsWithInvalidAddress()
+        {
+            var construct = GetConstructor<NoHostAvailableConstructor>(
+                typeof(NoHostAvailableException), nameof(NoHostAvailableException.NoHostAvailableExceptionFromRust));
+            var hostExceptions = new Exception[] { new InvalidQueryException("first"), new InvalidQueryException("second") };
+            var addresses = new[] { "127.0.0.1", "not an address" };
+
+            // Layout of BridgedHostError: address pointer and length, port, exception handle.
+            var entrySize = 4 * IntPtr.Size;
+            var entries = Marshal.AllocHGlobal(entrySize * addresses.Length);
+            var addressPtrs = new IntPtr[addresses.Length];
+            try
+            {
+                for (var i = 0; i < addresses.Length; i++)
+                {
+                    var entry = entries + i * entrySize;
+                    addressPtrs[i] = Marshal.StringToCoTaskMemUTF8(addresses[i]);
+                    Marshal.WriteIntPtr(entry, addressPtrs[i]);
+                    Marshal.WriteIntPtr(entry, IntPtr.Size, (IntPtr)Encoding.UTF8.GetByteCount(addresses[i]));
+                    Marshal.WriteInt16(entry, 2 * IntPtr.Size, 9042);
+                    Marshal.WriteIntPtr(entry, 3 * IntPtr.Size, GCHandle.ToIntPtr(GCHandle.Alloc(hostExceptions[i])));
+                }
+
+                var ex = TakeException<NoHostAvailableException>(
+                    WithFFIString("unused", message => construct(message, entries, (nuint)addresses.Length)));
+
+                Assert.AreEqual(1, ex.Errors.Count);
+                Assert.AreSame(hostExceptions[0], ex.Errors[new IPEndPoint(IPAddress.Loopback, 9042)]);
+            }
+            finally
+            {
+                foreach (var addressPtr in addressPtrs)
+                {
+                    Marshal.FreeCoTaskMem(addressPtr);
+                }
+                Marshal.FreeHGlobal(entries);
+            }
+        }
+
+        [Test]
+        public void NoHostAvailableException_Should_UseMessage_When_NoHostWasTried()
+        {
+            var construct = GetConstructor<NoHostAvailableConstructor>(
+                typeof(NoHostAvailableException), nameof(NoHostAvailableException.NoHostAvailableExceptionFromRust));
+
+            var ex = TakeException<NoHostAvailableException>(
+                WithFFIString("no connection", message => construct(message, IntPtr.Zero, 0)));
+
+            Assert.AreEqual("no connection", ex.Message);
+            Assert.AreEqual(0, ex.Errors.Count);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using System.Net;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class ExecutionInfoTests
+    {
+        private static readonly IPEndPoint First = new IPEndPoint(IPAddress.Parse("127.0.0.1"), 9042);
+        private static readonly IPEndPoint Second = new IPEndPoint(IPAddress.Parse("127.0.0.2"), 9042);
+
+        [Test]
+        public void QueriedHost_Defaults_To_The_Last_Tried_Host()
+        {
+            var info = new ExecutionInfo();
+            info.SetTriedHosts(new List<IPEndPoint> { First, Second });
+            Assert.AreEqual(Second, info.QueriedHost);
+        }
+
+        [Test]
+        public void QueriedHost_Is_The_Coordinator_Reported_By_The_Response()
+        {
+            var info = new ExecutionInfo();
+            info.SetTriedHosts(new List<IPEndPoint> { First, Second });
+            info.SetQueriedHost(First);
+            Assert.AreEqual(First, info.QueriedHost);
+        }
+
+        [Test]
+        public void Latency_And_TracingId_Are_Unknown_By_Default()
+        {
+            var info = new ExecutionInfo();
+            Assert.Null(info.Latency);
+            Assert.Null(info.TracingId);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Net;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class HostTests
+    {
+        [TestCase("3.0.8", "3.0.8")]
+        [TestCase("6.0.0-SNAPSHOT", "6.0.0")]
+        [TestCase("2025.1.0", "2025.1.0")]
+        public void ParseReleaseVersion_Parses_Versions(string releaseVersion, string expected)
+        {
+            Assert.AreEqual(Version.Parse(expected), Host.ParseReleaseVersion(releaseVersion));
+        }
+
+        [TestCase("")]
+        [TestCase(null)]
+        [TestCase("unknown")]
+        public void ParseReleaseVersion_Returns_Null_For_Unknown_Versions(string releaseVersion)
+        {
+            Assert.Null(Host.ParseReleaseVersion(releaseVersion));
+        }
+
+        [Test]
+        public void Host_From_Cluster_State_Keeps_Node_Details()
+        {
+            var address = new IPEndPoint(IPAddress.Parse("127.0.0.1"), 9042);
+            var hostId = Guid.NewGuid();
+            var host = new Host(address, hostId, "dc1", "rack1", new Version(6, 0), false);
+
+            Assert.AreEqual(address, host.Address);
+            Assert.AreEqual(hostId, host.HostId);
+            Assert.AreEqual("dc1", host.Datacenter);
+            Assert.AreEqual("rack1", host.Rack);
+            Assert.AreEqual(new Version(6, 0), host.CassandraVersion);
+            Assert.False(host.IsUp);
+        }
+
+        [Test]
+        public void Metadata_Has_No_Hosts_Before_Connecting()
+        {
+            var cluster = Cluster.Builder().AddContactPoint("127.0.0.1").Build();
+            CollectionAssert.IsEmpty(cluster.AllHosts());
+            Assert.Null(cluster.GetHost(new IPEndPoint(IPAddress.Parse("127.0.0.1"), 9042)));
+        }
+    }
+}
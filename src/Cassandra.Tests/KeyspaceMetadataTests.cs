@@ -0,0 +1,83 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class KeyspaceMetadataTests
+    {
+        [Test]
+        public void Ctor_Should_ParseReplicationFactors()
+        {
+            var ks = new KeyspaceMetadata(
+                null,
+                "ks1",
+                true,
+                "org.apache.cassandra.locator.NetworkTopologyStrategy",
+                new Dictionary<string, string> { { "dc1", "2" }, { "dc2", "1" } },
+                null);
+
+            Assert.AreEqual(2, ks.Replication.Count);
+            Assert.AreEqual(2, ks.Replication["dc1"]);
+            Assert.AreEqual(1, ks.Replication["dc2"]);
+        }
+
+        [Test]
+        public void Ctor_Should_SkipNonNumericReplicationOptions()
+        {
+            var ks = new KeyspaceMetadata(
+                null,
+                "ks1",
+                true,
+                "org.apache.cassandra.locator.EverywhereStrategy",
+                new Dictionary<string, string> { { "replication_factor", "3" }, { "custom", "value" } },
+                null);
+
+            Assert.AreEqual(1, ks.Replication.Count);
+            Assert.AreEqual(3, ks.Replication["replication_factor"]);
+        }
+
+        [Test]
+        public void Ctor_Should_InitializeReplicationWithNull_When_NullReplicationOptionsArePassed()
+        {
+            var ks = new KeyspaceMetadata(null, "name", false, null, null, null, true);
+
+            Assert.IsNull(ks.Replication);
+            Assert.IsNull(ks.StrategyClass);
+            Assert.True(ks.IsVirtual);
+        }
+
+        [Test]
+        public void AsCqlQuery_Should_IncludeReplicationOptions()
+        {
+            var ks = new KeyspaceMetadata(
+                null,
+                "ks1",
+                false,
+                "SimpleStrategy",
+                new Dictionary<string, string> { { "replication_factor", "2" } },
+                null);
+
+            Assert.AreEqual(
+                "CREATE KEYSPACE \"ks1\" WITH REPLICATION = { 'class' : 'SimpleStrategy', 'replication_factor': '2' } AND DURABLE_WRITES = False;",
+                ks.AsCqlQuery());
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class MetadataTests
+    {
+        [Test]
+        public void GetReplicas_Should_ReturnEmpty_When_NotConnected()
+        {
+            var cluster = Cluster.Builder().AddContactPoint("127.0.0.1").Build();
+
+            CollectionAssert.IsEmpty(cluster.GetReplicas("ks", new byte[] { 0, 0, 0, 1 }));
+            CollectionAssert.IsEmpty(cluster.GetReplicas(new byte[] { 0, 0, 0, 1 }));
+        }
+
+        [Test]
+        public void GetReplicas_Should_Throw_When_PartitionKeyIsNull()
+        {
+            var cluster = Cluster.Builder().AddContactPoint("127.0.0.1").Build();
+
+            Assert.Throws<ArgumentNullException>(() => cluster.Metadata.GetReplicas("ks", null));
+        }
+    }
+}
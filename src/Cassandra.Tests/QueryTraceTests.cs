@@ -0,0 +1,57 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Moq;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class QueryTraceTests
+    {
+        private static ISession MockSession()
+        {
+            var cluster = new Mock<ICluster>();
+            cluster.Setup(c => c.Configuration).Returns(new Configuration());
+            var session = new Mock<ISession>();
+            session.Setup(s => s.Cluster).Returns(cluster.Object);
+            return session.Object;
+        }
+
+        [Test]
+        public void QueryTrace_Keeps_Trace_Id()
+        {
+            var traceId = Guid.NewGuid();
+            var trace = new QueryTrace(traceId, MockSession());
+            Assert.AreEqual(traceId, trace.TraceId);
+        }
+
+        [Test]
+        public void QueryTrace_Requires_A_Session()
+        {
+            Assert.Throws<ArgumentNullException>(() => new QueryTrace(Guid.NewGuid(), null));
+        }
+
+        [Test]
+        public void QueryTrace_Can_Not_Be_Loaded_Through_Other_Sessions()
+        {
+            var trace = new QueryTrace(Guid.NewGuid(), MockSession());
+            Assert.ThrowsAsync<NotSupportedException>(() => trace.LoadAsync());
+        }
+    }
+}
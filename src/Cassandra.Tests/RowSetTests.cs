@@ -0,0 +1,38 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class RowSetTests
+    {
+        [Test]
+        public void EmptyRowSet_Should_BeAutoPagedAndExhausted()
+        {
+            var rs = new RowSet();
+
+            Assert.True(rs.AutoPage);
+            Assert.True(rs.IsExhausted());
+            Assert.True(rs.IsFullyFetched);
+
+            rs.PagingState = new byte[] { 1 };
+            Assert.False(rs.IsFullyFetched);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Microsoft.Extensions.Logging;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class RustLoggingTests
+    {
+        [TestCase((byte)0, LogLevel.Trace)]
+        [TestCase((byte)1, LogLevel.Debug)]
+        [TestCase((byte)2, LogLevel.Information)]
+        [TestCase((byte)3, LogLevel.Warning)]
+        [TestCase((byte)4, LogLevel.Error)]
+        public void RustLogging_Maps_Rust_Levels(byte level, LogLevel expected)
+        {
+            Assert.AreEqual(expected, RustLogging.ToLogLevel(level));
+        }
+
+        [Test]
+        public void RustLogging_Appends_Fields_To_Message()
+        {
+            Assert.AreEqual("Connected", RustLogging.FormatMessage("Connected", ReadOnlySpan<(string, string)>.Empty));
+            Assert.AreEqual(
+                "Connected node=127.0.0.1:9042 shard=3",
+                RustLogging.FormatMessage("Connected", new[] { ("node", "127.0.0.1:9042"), ("shard", "3") }));
+        }
+
+        [Test]
+        public void Diagnostics_SetNativeLogFilter_Null_Throws()
+        {
+            Assert.Throws<ArgumentNullException>(() => Diagnostics.SetNativeLogFilter(null));
+        }
+    }
+}
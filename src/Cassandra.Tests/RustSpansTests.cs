@@ -0,0 +1,88 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    [NonParallelizable]
+    public class RustSpansTests
+    {
+        private const string TraceId = "4bf92f3577b34da6a3ce929d0e0e4736";
+        private const string ParentSpanId = "00f067aa0ba902b7";
+        private const string TraceParent = "00-" + TraceId + "-" + ParentSpanId + "-01";
+
+        private List<Activity> _stopped;
+        private ActivityListener _listener;
+
+        [SetUp]
+        public void SetUp()
+        {
+            _stopped = new List<Activity>();
+            _listener = new ActivityListener
+            {
+                ShouldListenTo = source => source.Name == RustSpans.ActivitySourceName,
+                Sample = (ref ActivityCreationOptions<ActivityContext> _) => ActivitySamplingResult.AllDataAndRecorded,
+                ActivityStopped = activity => _stopped.Add(activity),
+            };
+            ActivitySource.AddActivityListener(_listener);
+        }
+
+        [TearDown]
+        public void TearDown()
+        {
+            _listener.Dispose();
+        }
+
+        [Test]
+        public void Report_Should_StopChildActivityOfParent()
+        {
+            var start = new DateTime(2025, 1, 1, 12, 0, 0, DateTimeKind.Utc);
+            var end = start.AddMilliseconds(15);
+
+            RustSpans.Report("query ks", TraceParent, start, end, true, new[]
+            {
+                new KeyValuePair<string, string>("db.system", "cassandra"),
+                new KeyValuePair<string, string>("server.address", "127.0.0.1"),
+            });
+
+            Assert.AreEqual(1, _stopped.Count);
+            var activity = _stopped[0];
+            Assert.AreEqual("query ks", activity.DisplayName);
+            Assert.AreEqual(ActivityKind.Client, activity.Kind);
+            Assert.AreEqual(TraceId, activity.TraceId.ToHexString());
+            Assert.AreEqual(ParentSpanId, activity.ParentSpanId.ToHexString());
+            Assert.AreEqual(start, activity.StartTimeUtc);
+            Assert.AreEqual(TimeSpan.FromMilliseconds(15), activity.Duration);
+            Assert.AreEqual(ActivityStatusCode.Error, activity.Status);
+            Assert.AreEqual("cassandra", activity.GetTagItem("db.system"));
+            Assert.AreEqual("127.0.0.1", activity.GetTagItem("server.address"));
+        }
+
+        [Test]
+        public void Report_Should_IgnoreInvalidParent()
+        {
+            RustSpans.Report("query", "not a traceparent", DateTime.UtcNow, DateTime.UtcNow, false, Array.Empty<KeyValuePair<string, string>>());
+
+            Assert.AreEqual(0, _stopped.Count);
+        }
+    }
+}
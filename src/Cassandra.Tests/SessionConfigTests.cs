@@ -0,0 +1,43 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    [TestFixture]
+    public class SessionConfigTests
+    {
+        [TestCase("127.0.0.1", "127.0.0.1:9042")]
+        [TestCase("scylla.local", "scylla.local:9042")]
+        [TestCase("::1", "[::1]:9042")]
+        [TestCase("fe80::1", "[fe80::1]:9042")]
+        [TestCase("[::1]", "[::1]:9042")]
+        public void WithPort_Should_AppendConfiguredPort_When_ContactPointHasNone(string contactPoint, string expected)
+        {
+            Assert.AreEqual(expected, SessionConfig.WithPort(contactPoint, 9042));
+        }
+
+        [TestCase("127.0.0.1:19042")]
+        [TestCase("scylla.local:19042")]
+        [TestCase("[::1]:19042")]
+        public void WithPort_Should_KeepExplicitPort(string contactPoint)
+        {
+            Assert.AreEqual(contactPoint, SessionConfig.WithPort(contactPoint, 9042));
+        }
+    }
+}
@@ -120,5 +120,21 @@ namespace Cassandra.Tests
             Assert.AreEqual(values.Description, actualValues["description"]);
             Assert.AreEqual(values.Time, actualValues["time"]);
         }
+
+        [Test]
+        public void StatementOptions_Should_PassRequestTimeout()
+        {
+            var stmt = new SimpleStatement(Query).SetRequestTimeoutMillis(1500);
+            var options = StatementOptions.FromStatement(stmt, new QueryOptions());
+            Assert.AreEqual(1500, options.request_timeout_ms);
+        }
+
+        [Test]
+        public void StatementOptions_Should_KeepSessionRequestTimeout_When_NotSet()
+        {
+            var stmt = new SimpleStatement(Query).SetReadTimeoutMillis(1500);
+            var options = StatementOptions.FromStatement(stmt, new QueryOptions());
+            Assert.AreEqual(0, options.request_timeout_ms);
+        }
     }
 }
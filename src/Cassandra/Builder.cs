@@ -70,6 +70,7 @@ namespace Cassandra
         private MonitorReportingOptions _monitorReportingOptions = new MonitorReportingOptions();
         private string _sessionName;
         private bool? _keepContactPointsUnresolved;
+        private bool _includeStatementInTraces;
         private bool? _allowBetaProtocolVersions;
 
         public Builder()
@@ -180,7 +181,8 @@ namespace Cassandra
                 typeSerializerDefinitions,
                 _keepContactPointsUnresolved,
                 _allowBetaProtocolVersions,
-                requestTracker: _requestTracker);
+                requestTracker: _requestTracker,
+                includeStatementInTraces: _includeStatementInTraces);
 
             return config;
         }
@@ -958,6 +960,21 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Determines whether the CQL statement is recorded in the tracing spans of requests,
+        /// as the <c>db.statement</c> attribute.
+        /// <para>
+        /// Disabled by default, as statements may contain sensitive data.
+        /// </para>
+        /// </summary>
+        /// <param name="includeStatementInTraces">Whether to record statements in tracing spans.</param>
+        /// <returns>This builder</returns>
+        public Builder WithStatementInTraces(bool includeStatementInTraces)
+        {
+            _includeStatementInTraces = includeStatementInTraces;
+            return this;
+        }
+
         /// <summary>
         /// <para>
         /// Adds Execution Profiles to the Cluster instance.
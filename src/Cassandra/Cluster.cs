@@ -32,7 +32,7 @@ using Cassandra.Tasks;
 namespace Cassandra
 {
     /// <inheritdoc cref="ICluster" />
-    public class Cluster : ICluster
+    public class Cluster : ICluster, IClusterEventListener
     {
         private const string DefaultVersionString = "N/A";
         private const string DefaultProductString = "ScyllaDB C# Driver";
@@ -44,14 +44,16 @@ namespace Cassandra
         private readonly Metadata _metadata;
         private readonly IEnumerable<string> _contactPoints;
 
-        // Disable unused event warnings, because they are part of the public API, so we can't remove them.
-#pragma warning disable CS0067
+        // Cluster events are received through a single session, replaced once it is shut down.
+        private readonly object _eventSubscriptionLock = new object();
+        private Session _eventSession;
+        private ClusterEventSubscription _eventSubscription;
+
         /// <inheritdoc />
         public event Action<Host> HostAdded;
 
         /// <inheritdoc />
         public event Action<Host> HostRemoved;
-#pragma warning restore CS0067
 
         /// <summary>
         ///  Build a new cluster based on the provided initializer. <p> Note that for
@@ -137,7 +139,7 @@ namespace Cassandra
         private Cluster(IEnumerable<object> contactPoints, Configuration configuration)
         {
             Configuration = configuration;
-            _metadata = new Metadata(configuration);
+            _metadata = new Metadata(configuration, _connectedSessions);
             var protocolVersion = _maxProtocolVersion;
             if (Configuration.ProtocolOptions.MaxProtocolVersionValue != null &&
                 Configuration.ProtocolOptions.MaxProtocolVersionValue.Value.IsSupported(configuration))
@@ -185,17 +187,71 @@ namespace Cassandra
         /// <param name="keyspace">Case-sensitive keyspace name to use</param>
         public async Task<ISession> ConnectAsync(string keyspace)
         {
-            // TODO: Handle multiple contact points (join into a comma-separated list
-            // and then split on the Rust side).
-            string firstContactPoint = _contactPoints.First();
-
-            var session = await Session.CreateAsync(this, firstContactPoint, keyspace).ConfigureAwait(false);
+            var session = await Session.CreateAsync(this, _contactPoints, keyspace).ConfigureAwait(false);
 
             _connectedSessions.Add(session);
             Logger.Info("Session connected ({0})", session.GetHashCode());
+            SubscribeToClusterEvents((Session)session);
             return session;
         }
 
+        /// <summary>
+        /// Starts receiving cluster events through the given session,
+        /// unless they are already received through another session which is not shut down.
+        /// </summary>
+        private void SubscribeToClusterEvents(Session session)
+        {
+            lock (_eventSubscriptionLock)
+            {
+                if (_eventSession != null && !_eventSession.IsDisposed)
+                {
+                    return;
+                }
+                _eventSubscription?.Dispose();
+                _eventSubscription = null;
+                _eventSession = null;
+                try
+                {
+                    _eventSubscription = session.SubscribeClusterEvents(this);
+                    _eventSession = session;
+                }
+                catch (Exception ex)
+                {
+                    // Events are not a reason to fail connecting; the next session retries.
+                    Logger.Warning("Cluster events are not received through session ({0}): {1}", session.GetHashCode(), ex.Message);
+                }
+            }
+        }
+
+        void IClusterEventListener.OnHostAdded(Host host)
+        {
+            Logger.Info("Host {0} added to the cluster", host.Address);
+            HostAdded?.Invoke(host);
+        }
+
+        void IClusterEventListener.OnHostRemoved(Host host)
+        {
+            Logger.Info("Host {0} removed from the cluster", host.Address);
+            HostRemoved?.Invoke(host);
+        }
+
+        void IClusterEventListener.OnHostUp(Host host)
+        {
+            Logger.Info("Host {0} is now up", host.Address);
+            _metadata.OnHostStateChanged(host, HostsEventArgs.Kind.Up);
+        }
+
+        void IClusterEventListener.OnHostDown(Host host)
+        {
+            Logger.Warning("Host {0} is now down", host.Address);
+            _metadata.OnHostStateChanged(host, HostsEventArgs.Kind.Down);
+        }
+
+        void IClusterEventListener.OnSchemaChanged(SchemaChangedEventArgs args)
+        {
+            _metadata.OnSchemaChanged(args);
+        }
+
         /// <summary>
         /// Creates new session on this cluster, and sets it to default keyspace.
         /// If default keyspace does not exist then it will be created and session will be set to it.
@@ -258,6 +314,12 @@ namespace Cassandra
         /// <inheritdoc />
         public async Task ShutdownAsync(int timeoutMs = Timeout.Infinite)
         {
+            lock (_eventSubscriptionLock)
+            {
+                _eventSubscription?.Dispose();
+                _eventSubscription = null;
+                _eventSession = null;
+            }
             var sessions = _connectedSessions.ClearAndGet();
             try
             {
@@ -137,6 +137,11 @@ namespace Cassandra
         /// </summary>
         public bool AllowBetaProtocolVersions { get; }
 
+        /// <summary>
+        /// <see cref="Builder.WithStatementInTraces"/>
+        /// </summary>
+        public bool IncludeStatementInTraces { get; }
+
         /// <summary>
         /// The key is the execution profile name and the value is the IRequestOptions instance
         /// built from the execution profile with that key.
@@ -199,7 +204,8 @@ namespace Cassandra
                                TypeSerializerDefinitions typeSerializerDefinitions,
                                bool? keepContactPointsUnresolved,
                                bool? allowBetaProtocolVersions,
-                               IRequestTracker requestTracker = null)
+                               IRequestTracker requestTracker = null,
+                               bool includeStatementInTraces = false)
         {
             AddressTranslator = addressTranslator ?? throw new ArgumentNullException(nameof(addressTranslator));
             QueryOptions = queryOptions ?? throw new ArgumentNullException(nameof(queryOptions));
@@ -222,6 +228,7 @@ namespace Cassandra
             TypeSerializers = typeSerializerDefinitions?.Definitions;
             KeepContactPointsUnresolved = keepContactPointsUnresolved ?? false;
             AllowBetaProtocolVersions = allowBetaProtocolVersions ?? false;
+            IncludeStatementInTraces = includeStatementInTraces;
 
             // FIXME
             // ExecutionProfiles = BuildExecutionProfilesDictionary(executionProfiles, RequestOptions);
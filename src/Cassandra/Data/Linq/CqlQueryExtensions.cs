@@ -40,6 +40,10 @@ namespace Cassandra.Data.Linq
             {
                 dst.SetIdempotence(src.IsIdempotent.Value);
             }
+            if (src is Statement srcStatement && dst is Statement dstStatement)
+            {
+                dstStatement.SetRequestTimeoutMillis(srcStatement.RequestTimeoutMillis);
+            }
         }
 
         /// <summary>
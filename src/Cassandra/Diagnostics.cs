@@ -73,6 +73,22 @@ namespace Cassandra
         {
             UseLoggerFactory = true;
             LoggerFactory.AddProvider(provider);
+            RustLogging.EnsureInitialized();
+        }
+
+        /// <summary>
+        /// Changes which log events of the native driver are passed to the <see cref="ILoggerProvider"/> instances
+        /// added with <see cref="AddLoggerProvider(ILoggerProvider)"/>.
+        /// <para>
+        /// The filter uses the <c>RUST_LOG</c> syntax, e.g. <c>info,scylla=debug</c>.
+        /// By default, the <c>RUST_LOG</c> environment variable is used, or <c>info</c> if it is not set.
+        /// </para>
+        /// </summary>
+        /// <param name="filter">The filter of native log events</param>
+        /// <exception cref="RustException">The filter is invalid or native logging is not initialized.</exception>
+        public static void SetNativeLogFilter(string filter)
+        {
+            RustLogging.SetFilter(filter);
         }
     }
 }
@@ -0,0 +1,118 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Runtime.InteropServices;
+using System.Runtime.Loader;
+using System.Threading;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Controls the native runtime that executes the requests of all sessions in the process.
+    /// <para>
+    /// The runtime is started when the first session is created, and shut down when the process exits
+    /// or the driver assembly is unloaded.
+    /// </para>
+    /// </summary>
+    public static class DriverRuntime
+    {
+        /// <summary>
+        /// How long the runtime threads are waited for on shutdown: 5 seconds.
+        /// </summary>
+        private const ulong ShutdownTimeoutMs = 5000;
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException runtime_init(
+            nuint workerThreads,
+            nuint maxBlockingThreads,
+            nuint threadStackSize,
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string threadNamePrefix,
+            [MarshalAs(UnmanagedType.U1)] bool currentThread,
+            IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void runtime_shutdown(ulong timeoutMs);
+
+        private static int _shutdownRegistered;
+
+        /// <summary>
+        /// Starts the native runtime with the provided options.
+        /// <para>
+        /// Must be called before the first session is created, as the runtime can be configured only once.
+        /// Otherwise, the runtime is started with the default options.
+        /// </para>
+        /// </summary>
+        /// <exception cref="RustException">The runtime is already running or cannot be started.</exception>
+        public static void Configure(DriverRuntimeOptions options)
+        {
+            if (options == null)
+            {
+                throw new ArgumentNullException(nameof(options));
+            }
+            RustLogging.EnsureInitialized();
+            RustSpans.EnsureInitialized();
+            EnsureShutdownOnExit();
+
+            unsafe
+            {
+                var res = runtime_init(
+                    (nuint)options.WorkerThreads,
+                    (nuint)options.MaxBlockingThreads,
+                    (nuint)options.ThreadStackSize,
+                    options.ThreadNamePrefix,
+                    options.UseSingleThread,
+                    (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                try
+                {
+                    RustBridge.ThrowIfException(ref res);
+                }
+                finally
+                {
+                    RustBridge.FreeExceptionHandle(ref res);
+                }
+            }
+        }
+
+        /// <summary>
+        /// Makes sure the runtime is shut down when the process exits or the driver assembly is unloaded,
+        /// so that its threads do not outlive the managed callbacks they invoke.
+        /// Called before the runtime is started.
+        /// </summary>
+        internal static void EnsureShutdownOnExit()
+        {
+            if (Interlocked.Exchange(ref _shutdownRegistered, 1) == 1)
+            {
+                return;
+            }
+            AppDomain.CurrentDomain.ProcessExit += (_, _) => Shutdown();
+            var loadContext = AssemblyLoadContext.GetLoadContext(typeof(DriverRuntime).Assembly);
+            if (loadContext != null)
+            {
+                loadContext.Unloading += _ => Shutdown();
+            }
+        }
+
+        /// <summary>
+        /// Shuts down the runtime. Requests issued afterwards fail with <see cref="AlreadyShutdownException"/>.
+        /// Sessions should be shut down before, as requests still in flight are dropped.
+        /// </summary>
+        internal static void Shutdown()
+        {
+            runtime_shutdown(ShutdownTimeoutMs);
+        }
+    }
+}
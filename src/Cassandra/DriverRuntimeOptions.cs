@@ -0,0 +1,128 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Options of the native runtime that executes the requests of all sessions in the process.
+    /// Passed to <see cref="DriverRuntime.Configure(DriverRuntimeOptions)"/>.
+    /// <para>Options left at zero or <c>null</c> keep the defaults of the native runtime.</para>
+    /// </summary>
+    public class DriverRuntimeOptions
+    {
+        private int _workerThreads;
+        private int _maxBlockingThreads;
+        private int _threadStackSize;
+        private string _threadNamePrefix;
+        private bool _useSingleThread;
+
+        /// <summary>
+        /// Gets the number of threads executing requests. Defaults to the number of CPU cores.
+        /// </summary>
+        public int WorkerThreads
+        {
+            get { return _workerThreads; }
+        }
+
+        /// <summary>
+        /// Gets the maximum number of threads used for blocking operations, e.g. DNS resolution. Defaults to 512.
+        /// </summary>
+        public int MaxBlockingThreads
+        {
+            get { return _maxBlockingThreads; }
+        }
+
+        /// <summary>
+        /// Gets the stack size of the runtime threads in bytes. Defaults to 2 MiB.
+        /// </summary>
+        public int ThreadStackSize
+        {
+            get { return _threadStackSize; }
+        }
+
+        /// <summary>
+        /// Gets the prefix of the names of the runtime threads, which are named <c>&lt;prefix&gt;-&lt;n&gt;</c>.
+        /// </summary>
+        public string ThreadNamePrefix
+        {
+            get { return _threadNamePrefix; }
+        }
+
+        /// <summary>
+        /// Determines if all requests are executed by a single thread, in which case <see cref="WorkerThreads"/> is ignored.
+        /// </summary>
+        public bool UseSingleThread
+        {
+            get { return _useSingleThread; }
+        }
+
+        /// <summary>
+        /// Sets the number of threads executing requests.
+        /// </summary>
+        public DriverRuntimeOptions SetWorkerThreads(int workerThreads)
+        {
+            _workerThreads = NonNegative(workerThreads, nameof(workerThreads));
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the maximum number of threads used for blocking operations.
+        /// </summary>
+        public DriverRuntimeOptions SetMaxBlockingThreads(int maxBlockingThreads)
+        {
+            _maxBlockingThreads = NonNegative(maxBlockingThreads, nameof(maxBlockingThreads));
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the stack size of the runtime threads in bytes.
+        /// </summary>
+        public DriverRuntimeOptions SetThreadStackSize(int threadStackSize)
+        {
+            _threadStackSize = NonNegative(threadStackSize, nameof(threadStackSize));
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the prefix of the names of the runtime threads.
+        /// </summary>
+        public DriverRuntimeOptions SetThreadNamePrefix(string threadNamePrefix)
+        {
+            _threadNamePrefix = threadNamePrefix;
+            return this;
+        }
+
+        /// <summary>
+        /// Sets whether all requests are executed by a single thread.
+        /// </summary>
+        public DriverRuntimeOptions SetUseSingleThread(bool useSingleThread)
+        {
+            _useSingleThread = useSingleThread;
+            return this;
+        }
+
+        private static int NonNegative(int value, string paramName)
+        {
+            if (value < 0)
+            {
+                throw new ArgumentOutOfRangeException(paramName, value, "Value must not be negative");
+            }
+            return value;
+        }
+    }
+}
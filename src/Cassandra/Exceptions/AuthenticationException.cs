@@ -14,7 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 
 namespace Cassandra
 {
@@ -38,5 +41,15 @@ namespace Cassandra
         {
             Host = host;
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr AuthenticationExceptionFromRust(FFIString message)
+        {
+            string messageStr = message.ToManagedString();
+            var exception = new AuthenticationException(messageStr);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
@@ -0,0 +1,44 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Exception thrown when a keyspace cannot be used, e.g. because it does not exist
+    /// or its name is not valid.
+    /// </summary>
+    public class InvalidKeyspaceException : InvalidQueryException
+    {
+        public InvalidKeyspaceException(string message) : base(message)
+        { }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr InvalidKeyspaceExceptionFromRust(FFIString message)
+        {
+            string msg = message.ToManagedString();
+
+            var exception = new InvalidKeyspaceException(msg);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            IntPtr handlePtr = GCHandle.ToIntPtr(handle);
+            return handlePtr;
+        }
+    }
+}
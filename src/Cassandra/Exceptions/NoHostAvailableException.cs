@@ -101,12 +101,44 @@ namespace Cassandra
             return builder.ToString();
         }
 
+        /// <summary>
+        /// Error of a single host, passed from Rust.
+        /// Must match the layout of the Rust `BridgedHostError` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedHostError
+        {
+            internal readonly FFIString address;
+            internal readonly ushort port;
+            internal readonly IntPtr exception;
+        }
+
+        /// <summary>
+        /// Creates the exception from the errors of the hosts tried, or from the message if no host was tried.
+        /// Takes ownership of the handles of the host exceptions.
+        /// </summary>
         [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
-        internal static IntPtr NoHostAvailableExceptionFromRust(FFIString message)
+        internal static unsafe IntPtr NoHostAvailableExceptionFromRust(FFIString message, IntPtr hostErrors, nuint hostErrorsLen)
         {
-            string msg = message.ToManagedString();
-            var exception = new NoHostAvailableException(msg);
-            
+            var entries = (BridgedHostError*)hostErrors;
+            var errors = new Dictionary<IPEndPoint, Exception>((int)hostErrorsLen);
+            for (nuint i = 0; i < hostErrorsLen; i++)
+            {
+                var entry = entries[i];
+                var exHandle = GCHandle.FromIntPtr(entry.exception);
+                var hostException = exHandle.Target as Exception;
+                exHandle.Free();
+                // Throwing across the FFI boundary is UB, so an address that does not parse is skipped.
+                if (IPAddress.TryParse(entry.address.ToManagedString(), out var address))
+                {
+                    errors[new IPEndPoint(address, entry.port)] = hostException;
+                }
+            }
+
+            var exception = errors.Count == 0
+                ? new NoHostAvailableException(message.ToManagedString())
+                : new NoHostAvailableException(errors);
+
             GCHandle handle = GCHandle.Alloc(exception);
             IntPtr handlePtr = GCHandle.ToIntPtr(handle);
             return handlePtr;
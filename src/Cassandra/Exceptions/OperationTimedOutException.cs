@@ -27,8 +27,20 @@ namespace Cassandra
     /// </summary>
     public class OperationTimedOutException : DriverException
     {
+        /// <summary>
+        /// Gets the host that did not reply, or null if the request was not waiting for a single host,
+        /// e.g. if it timed out before being sent to any host.
+        /// </summary>
+        public IPEndPoint Address { get; }
+
         public OperationTimedOutException(IPEndPoint address, int timeout) :
             base($"The host {address} did not reply before timeout {timeout}ms")
+        {
+            Address = address;
+        }
+
+        internal OperationTimedOutException(int timeout) :
+            base($"No host replied before timeout {timeout}ms")
         {
         }
 
@@ -40,8 +52,15 @@ namespace Cassandra
             Exception exception;
             try
             {
-                addr = IPEndPoint.Parse(addressString);
-                exception = new OperationTimedOutException(addr, timeout);
+                if (addressString.Length == 0)
+                {
+                    exception = new OperationTimedOutException(timeout);
+                }
+                else
+                {
+                    addr = IPEndPoint.Parse(addressString);
+                    exception = new OperationTimedOutException(addr, timeout);
+                }
             }
             catch (FormatException)
             {
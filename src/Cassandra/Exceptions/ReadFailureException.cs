@@ -14,9 +14,12 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using System.Text;
 
 namespace Cassandra
@@ -121,5 +124,14 @@ namespace Cassandra
 
             return message.Append(")").ToString();
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr ReadFailureExceptionFromRust(short consistency, int received, int required, int failures, byte dataPresent)
+        {
+            var exception = new ReadFailureException((ConsistencyLevel)consistency, received, required, dataPresent != 0, failures);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
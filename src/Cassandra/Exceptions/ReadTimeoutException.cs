@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     /// <summary>
@@ -46,5 +50,14 @@ namespace Cassandra
             }
             return "timeout while waiting for repair of inconsistent replica";
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr ReadTimeoutExceptionFromRust(short consistency, int received, int required, byte dataPresent)
+        {
+            var exception = new ReadTimeoutException((ConsistencyLevel)consistency, received, required, dataPresent != 0);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
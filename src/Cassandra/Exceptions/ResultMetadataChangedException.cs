@@ -0,0 +1,45 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Runtime.InteropServices;
+using System.Runtime.CompilerServices;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Exception thrown when the columns of a result change while its pages are being fetched,
+    /// e.g. because the table was altered in the middle of paging.
+    /// The rows of the following pages cannot be read with the columns of the <see cref="RowSet"/>.
+    /// </summary>
+    public class ResultMetadataChangedException : DriverException
+    {
+        public ResultMetadataChangedException(string message) : base(message, null)
+        { }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr ResultMetadataChangedExceptionFromRust(FFIString message)
+        {
+            string msg = message.ToManagedString();
+
+            var exception = new ResultMetadataChangedException(msg);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            IntPtr handlePtr = GCHandle.ToIntPtr(handle);
+            return handlePtr;
+        }
+    }
+}
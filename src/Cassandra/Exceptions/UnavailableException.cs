@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     /// <summary>
@@ -46,5 +50,14 @@ namespace Cassandra
             RequiredReplicas = required;
             AliveReplicas = alive;
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr UnavailableExceptionFromRust(short consistency, int required, int alive)
+        {
+            var exception = new UnavailableException((ConsistencyLevel)consistency, required, alive);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
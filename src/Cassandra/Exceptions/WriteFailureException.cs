@@ -14,9 +14,12 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 
 namespace Cassandra
 {
@@ -107,5 +110,14 @@ namespace Cassandra
         {
             Reasons = reasons ?? DefaultReasons;
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr WriteFailureExceptionFromRust(short consistency, int received, int required, int failures, FFIString writeType)
+        {
+            var exception = new WriteFailureException((ConsistencyLevel)consistency, received, required, writeType.ToManagedString(), failures);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
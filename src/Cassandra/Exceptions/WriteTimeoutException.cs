@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     /// <summary>
@@ -53,5 +57,14 @@ namespace Cassandra
             var message = writeType == BatchLogWriteType ? BatchLogMessage : QueryMessage;
             return string.Format(message, consistency.ToString().ToUpperInvariant(), received, required);
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr WriteTimeoutExceptionFromRust(short consistency, int received, int required, FFIString writeType)
+        {
+            var exception = new WriteTimeoutException((ConsistencyLevel)consistency, received, required, writeType.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
@@ -31,9 +31,11 @@ namespace Cassandra
         /// </summary>
         public bool IsUp
         {
-            get { throw new NotImplementedException(); }
+            get { return _isUp; }
         }
 
+        private readonly bool _isUp;
+
         /// <summary>
         /// This property is going to be removed in future versions, use <see cref="IsUp"/> instead.
         /// Used to determines if the host can be considered as UP
@@ -84,6 +86,38 @@ namespace Cassandra
         public Host(IPEndPoint address, IReconnectionPolicy reconnectionPolicy)
         {
             // FIXME
+            Address = address;
+        }
+
+        /// <summary>
+        /// Creates a host as seen in a snapshot of the cluster state.
+        /// </summary>
+        internal Host(IPEndPoint address, Guid hostId, string datacenter, string rack, Version cassandraVersion, bool isUp)
+        {
+            Address = address;
+            HostId = hostId;
+            Datacenter = datacenter;
+            Rack = rack;
+            CassandraVersion = cassandraVersion;
+            _isUp = isUp;
+        }
+
+        /// <summary>
+        /// Parses the <c>release_version</c> of a node, e.g. <c>3.0.8</c> or <c>6.0.0-SNAPSHOT</c>.
+        /// Returns null if it is empty or not a version.
+        /// </summary>
+        internal static Version ParseReleaseVersion(string releaseVersion)
+        {
+            if (string.IsNullOrEmpty(releaseVersion))
+            {
+                return null;
+            }
+            var separatorIndex = releaseVersion.IndexOf('-');
+            if (separatorIndex >= 0)
+            {
+                releaseVersion = releaseVersion.Substring(0, separatorIndex);
+            }
+            return Version.TryParse(releaseVersion, out var version) ? version : null;
         }
 
         /// <summary>
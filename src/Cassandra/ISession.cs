@@ -17,6 +17,7 @@
 using System;
 using System.Collections.Generic;
 using System.Net;
+using System.Threading;
 using System.Threading.Tasks;
 using Cassandra.Metrics;
 
@@ -196,6 +197,24 @@ namespace Cassandra
         /// <returns>A task representing the asynchronous operation.</returns>
         Task<RowSet> ExecuteAsync(IStatement statement, string executionProfileName);
 
+        /// <summary>
+        /// Executes a query asynchronously. The request is cancelled once <paramref name="cancellationToken"/> is triggered.
+        /// </summary>
+        /// <param name="statement">The statement to execute (simple, bound or batch statement)</param>
+        /// <param name="cancellationToken">Token cancelling the request.</param>
+        /// <returns>A task representing the asynchronous operation.</returns>
+        Task<RowSet> ExecuteAsync(IStatement statement, CancellationToken cancellationToken);
+
+        /// <summary>
+        /// Executes a query asynchronously with the provided execution profile.
+        /// The request is cancelled once <paramref name="cancellationToken"/> is triggered.
+        /// </summary>
+        /// <param name="statement">The statement to execute (simple, bound or batch statement)</param>
+        /// <param name="executionProfileName">ExecutionProfile name to be used while executing the statement.</param>
+        /// <param name="cancellationToken">Token cancelling the request.</param>
+        /// <returns>A task representing the asynchronous operation.</returns>
+        Task<RowSet> ExecuteAsync(IStatement statement, string executionProfileName, CancellationToken cancellationToken);
+
         /// <summary>
         /// Prepares the provided query string.
         /// </summary>
@@ -26,6 +26,8 @@ namespace Cassandra
 {
     public class KeyspaceMetadata
     {
+        private readonly Metadata _parent;
+
         /// <summary>
         ///  Gets the name of this keyspace.
         /// </summary>
@@ -62,6 +64,11 @@ namespace Cassandra
         /// </summary>
         public string GraphEngine { get; }
 
+        /// <summary>
+        /// Returns the replication options as reported by the server, including non numeric ones.
+        /// </summary>
+        internal IDictionary<string, string> ReplicationOptions { get; }
+
         internal KeyspaceMetadata(
             Metadata parent,
             string name,
@@ -71,7 +78,25 @@ namespace Cassandra
             string graphEngine,
             bool isVirtual = false)
         {
-            throw new NotImplementedException("TODO: implement KeyspaceMetadata");
+            _parent = parent;
+            Name = name;
+            DurableWrites = durableWrites;
+            StrategyClass = strategyClass;
+            ReplicationOptions = replicationOptions;
+            GraphEngine = graphEngine;
+            IsVirtual = isVirtual;
+            if (replicationOptions != null)
+            {
+                var replication = new Dictionary<string, int>();
+                foreach (var option in replicationOptions)
+                {
+                    if (int.TryParse(option.Value, out var replicationFactor))
+                    {
+                        replication[option.Key] = replicationFactor;
+                    }
+                }
+                Replication = replication;
+            }
         }
 
         /// <summary>
@@ -82,12 +107,12 @@ namespace Cassandra
         ///  exists, <c>null</c> otherwise.</returns>
         public TableMetadata GetTableMetadata(string tableName)
         {
-            throw new NotImplementedException("TODO: implement TableMetadata");
+            return _parent.GetTable(Name, tableName);
         }
 
         internal Task<TableMetadata> GetTableMetadataAsync(string tableName)
         {
-            throw new NotImplementedException("TODO: implement TableMetadata");
+            return Task.FromResult(GetTableMetadata(tableName));
         }
 
         /// <summary>
@@ -98,7 +123,7 @@ namespace Cassandra
         ///  exists, <c>null</c> otherwise.</returns>
         public MaterializedViewMetadata GetMaterializedViewMetadata(string viewName)
         {
-            throw new NotImplementedException("TODO: implement MaterializedViewMetadata");
+            return _parent.GetMaterializedView(Name, viewName);
         }
 
         /// <summary>
@@ -140,7 +165,7 @@ namespace Cassandra
         ///  keyspace.</returns>
         public IEnumerable<TableMetadata> GetTablesMetadata()
         {
-            throw new NotImplementedException("TODO: implement TableMetadata");
+            return GetTablesNames().Select(GetTableMetadata).Where(table => table != null);
         }
 
 
@@ -152,7 +177,7 @@ namespace Cassandra
         ///  keyspace tables names.</returns>
         public ICollection<string> GetTablesNames()
         {
-            throw new NotImplementedException("TODO: implement KeyspaceMetadata");
+            return _parent.GetTables(Name);
         }
 
         /// <summary>
@@ -188,15 +213,14 @@ namespace Cassandra
             sb.Append("CREATE KEYSPACE ").Append(CqlQueryTools.QuoteIdentifier(Name)).Append(" WITH ");
             sb.Append("REPLICATION = { 'class' : '").Append(StrategyClass).Append("'");
 
-            /* FIXME: reimplement this using Rust wrapper.
-            foreach (var rep in [] ReplicationOptions)
+            foreach (var rep in ReplicationOptions ?? new Dictionary<string, string>())
             {
                 if (rep.Key == "class")
                 {
                     continue;
                 }
                 sb.Append(", '").Append(rep.Key).Append("': '").Append(rep.Value).Append("'");
-            } */
+            }
             sb.Append(" } AND DURABLE_WRITES = ").Append(DurableWrites);
             sb.Append(";");
             return sb.ToString();
@@ -207,7 +231,7 @@ namespace Cassandra
         /// </summary>
         internal UdtColumnInfo GetUdtDefinition(string typeName)
         {
-            throw new NotImplementedException("TODO: implement UdtMetadata");
+            return _parent.GetUdtDefinition(Name, typeName);
         }
 
         /// <summary>
@@ -215,7 +239,7 @@ namespace Cassandra
         /// </summary>
         internal Task<UdtColumnInfo> GetUdtDefinitionAsync(string typeName)
         {
-            throw new NotImplementedException("TODO: implement UdtMetadata");
+            return _parent.GetUdtDefinitionAsync(Name, typeName);
         }
 
         /// <summary>
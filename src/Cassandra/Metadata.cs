@@ -31,11 +31,9 @@ namespace Cassandra
     /// </summary>
     public class Metadata : IDisposable
     {
-#pragma warning disable CS0067
         public event HostsEventHandler HostsEvent;
 
         public event SchemaChangedEventHandler SchemaChangedEvent;
-#pragma warning restore CS0067
 
         /// <summary>
         ///  Returns the name of currently connected cluster.
@@ -53,10 +51,17 @@ namespace Cassandra
         /// </summary>
         internal Configuration Configuration { get; private set; }
 
-        internal Metadata(Configuration configuration)
+        // Sessions of the cluster, from which the cluster state is taken.
+        private readonly IEnumerable<ISession> _sessions;
+
+        internal Metadata(Configuration configuration) : this(configuration, Array.Empty<ISession>())
         {
-            // FIXME:
-            // throw new NotImplementedException();
+        }
+
+        internal Metadata(Configuration configuration, IEnumerable<ISession> sessions)
+        {
+            Configuration = configuration;
+            _sessions = sessions;
         }
 
         public void Dispose()
@@ -64,18 +69,64 @@ namespace Cassandra
             // No-op for now - metadata shutdown not yet implemented
             // throw new NotImplementedException();
         }
+        /// <summary>
+        ///  Returns the known host with the given address, or null if there is none.
+        /// </summary>
         public Host GetHost(IPEndPoint address)
         {
-            throw new NotImplementedException();
+            return AllHosts().FirstOrDefault(host => host.Address.Equals(address));
+        }
+
+        /// <summary>
+        /// Raises <see cref="HostsEvent"/> for a node that went up or down.
+        /// </summary>
+        internal void OnHostStateChanged(Host host, HostsEventArgs.Kind what)
+        {
+            HostsEvent?.Invoke(this, new HostsEventArgs { Address = host.Address, What = what });
+        }
+
+        /// <summary>
+        /// Raises <see cref="SchemaChangedEvent"/>.
+        /// </summary>
+        internal void OnSchemaChanged(SchemaChangedEventArgs args)
+        {
+            SchemaChangedEvent?.Invoke(this, args);
         }
 
         /// <summary>
         ///  Returns all known hosts of this cluster.
+        ///  Hosts are known once a session is connected; until then, the collection is empty.
         /// </summary>
         /// <returns>collection of all known hosts of this cluster.</returns>
         public ICollection<Host> AllHosts()
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetHosts() ?? Array.Empty<Host>();
+            }
+        }
+
+        /// <summary>
+        /// Takes a snapshot of the cluster state from a connected session,
+        /// or returns null if no session is connected.
+        /// </summary>
+        internal ClusterState TakeClusterState()
+        {
+            foreach (var session in _sessions)
+            {
+                if (session is Session connected && !connected.IsDisposed)
+                {
+                    try
+                    {
+                        return connected.GetClusterState();
+                    }
+                    catch (Exception ex) when (ex is AlreadyShutdownException || ex is ObjectDisposedException)
+                    {
+                        // The session was shut down meanwhile; try the next one.
+                    }
+                }
+            }
+            return null;
         }
 
         public IEnumerable<IPEndPoint> AllReplicas()
@@ -88,15 +139,50 @@ namespace Cassandra
 
         /// <summary>
         /// Get the replicas for a given partition key and keyspace
+        /// <para>
+        /// The replicas are computed from the token ring. For keyspaces using tablets, whose replicas
+        /// are placed per table, they may not be the nodes holding the partition:
+        /// use <see cref="GetReplicas(string, string, byte[])"/> for those.
+        /// </para>
         /// </summary>
+        /// <param name="keyspaceName">the keyspace whose replication strategy places the replicas.</param>
+        /// <param name="partitionKey">the serialized routing key of the partition.</param>
+        /// <returns>the replicas, with the shard owning the partition, or -1 for nodes which are not sharded.</returns>
         public ICollection<HostShard> GetReplicas(string keyspaceName, byte[] partitionKey)
         {
-            throw new NotImplementedException();
+            return GetReplicas(keyspaceName, null, partitionKey);
+        }
+
+        /// <summary>
+        /// Get the replicas for a given partition key of a table.
+        /// <para>
+        /// The table only matters for keyspaces using tablets. Their replicas are known once the driver
+        /// has received the tablet of the partition from the cluster, i.e. after a request to it;
+        /// until then, the replicas are computed from the token ring.
+        /// </para>
+        /// </summary>
+        /// <param name="keyspaceName">the keyspace whose replication strategy places the replicas.</param>
+        /// <param name="tableName">the table of the partition.</param>
+        /// <param name="partitionKey">the serialized routing key of the partition.</param>
+        /// <returns>the replicas, with the shard owning the partition, or -1 for nodes which are not sharded.</returns>
+        public ICollection<HostShard> GetReplicas(string keyspaceName, string tableName, byte[] partitionKey)
+        {
+            if (partitionKey == null)
+            {
+                throw new ArgumentNullException(nameof(partitionKey));
+            }
+            using (var state = TakeClusterState())
+            {
+                return state?.GetReplicas(keyspaceName, tableName, partitionKey) ?? Array.Empty<HostShard>();
+            }
         }
 
+        /// <summary>
+        /// Get the node owning the token of a given partition key, regardless of the replication.
+        /// </summary>
         public ICollection<HostShard> GetReplicas(byte[] partitionKey)
         {
-            throw new NotImplementedException();
+            return GetReplicas(null, partitionKey);
         }
 
         /// <summary>
@@ -108,7 +194,10 @@ namespace Cassandra
         ///  <c>* keyspace</c> is not a known keyspace.</returns>
         public KeyspaceMetadata GetKeyspace(string keyspace)
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetKeyspace(this, keyspace);
+            }
         }
 
         /// <summary>
@@ -117,7 +206,10 @@ namespace Cassandra
         /// <returns>a collection of all defined keyspaces names.</returns>
         public ICollection<string> GetKeyspaces()
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetKeyspaceNames() ?? Array.Empty<string>();
+            }
         }
 
         /// <summary>
@@ -129,7 +221,10 @@ namespace Cassandra
         ///  keyspace.</returns>
         public ICollection<string> GetTables(string keyspace)
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetTableNames(keyspace) ?? Array.Empty<string>();
+            }
         }
 
         /// <summary>
@@ -140,7 +235,10 @@ namespace Cassandra
         /// <returns>a TableMetadata for the specified table in the specified keyspace.</returns>
         public TableMetadata GetTable(string keyspace, string tableName)
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetTable(keyspace, tableName);
+            }
         }
 
         /// <summary>
@@ -151,7 +249,10 @@ namespace Cassandra
         /// <returns>a MaterializedViewMetadata for the view in the specified keyspace.</returns>
         public MaterializedViewMetadata GetMaterializedView(string keyspace, string name)
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetMaterializedView(keyspace, name);
+            }
         }
 
         /// <summary>
@@ -159,7 +260,10 @@ namespace Cassandra
         /// </summary>
         public UdtColumnInfo GetUdtDefinition(string keyspace, string typeName)
         {
-            throw new NotImplementedException();
+            using (var state = TakeClusterState())
+            {
+                return state?.GetUdtDefinition(keyspace, typeName);
+            }
         }
 
         /// <summary>
@@ -167,7 +271,8 @@ namespace Cassandra
         /// </summary>
         public Task<UdtColumnInfo> GetUdtDefinitionAsync(string keyspace, string typeName)
         {
-            throw new NotImplementedException();
+            // The definition is read from a local snapshot of the schema, so there is nothing to wait for.
+            return Task.FromResult(GetUdtDefinition(keyspace, typeName));
         }
 
         /// <summary>
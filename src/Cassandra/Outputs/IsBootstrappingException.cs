@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     public class IsBootstrappingException : QueryValidationException
@@ -21,5 +25,14 @@ namespace Cassandra
         public IsBootstrappingException(string message) : base(message)
         {
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr IsBootstrappingExceptionFromRust(FFIString message)
+        {
+            var exception = new IsBootstrappingException(message.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
\ No newline at end of file
@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     public class OverloadedException : QueryValidationException
@@ -21,5 +25,14 @@ namespace Cassandra
         public OverloadedException(string message) : base(message)
         {
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr OverloadedExceptionFromRust(FFIString message)
+        {
+            var exception = new OverloadedException(message.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
\ No newline at end of file
@@ -14,6 +14,10 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
 namespace Cassandra
 {
     public class ServerErrorException : QueryValidationException
@@ -21,5 +25,14 @@ namespace Cassandra
         public ServerErrorException(string message) : base(message)
         {
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr ServerErrorExceptionFromRust(FFIString message)
+        {
+            var exception = new ServerErrorException(message.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return GCHandle.ToIntPtr(handle);
+        }
     }
 }
\ No newline at end of file
@@ -58,6 +58,11 @@ namespace Cassandra
         /// </summary>
         internal string Username => _username;
 
+        /// <summary>
+        /// Passed to the Rust driver, which performs the SASL PLAIN exchange itself.
+        /// </summary>
+        internal string Password => _password;
+
         /// <summary>
         /// Uses the supplied credentials and the SASL PLAIN mechanism to login to the server.
         /// </summary>
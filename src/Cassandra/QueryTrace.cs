@@ -17,6 +17,7 @@
 using System;
 using System.Collections.Generic;
 using System.Net;
+using System.Runtime.InteropServices;
 using System.Threading.Tasks;
 using Cassandra.Tasks;
 
@@ -33,8 +34,9 @@ namespace Cassandra
     public class QueryTrace
     {
         private readonly object _fetchLock = new object();
-        private readonly Metadata _metadata;
+        private readonly ISession _session;
         private readonly Guid _traceId;
+        private volatile bool _isLoaded;
         private IPAddress _coordinator;
         private int _duration = int.MinValue;
         private List<Event> _events;
@@ -44,6 +46,53 @@ namespace Cassandra
         private IPAddress _clientAddress;
         private readonly int _metadataFetchSyncTimeout;
 
+        /// <summary>
+        /// General fields of a trace. Strings which are missing in the trace are empty, and numbers are -1.
+        /// Must match the layout of the Rust `BridgedTracingSession` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedTracingSession
+        {
+            internal readonly FFIString coordinator;
+            internal readonly FFIString client;
+            internal readonly FFIString request;
+            internal readonly FFIString command;
+            internal readonly int durationUs;
+            internal readonly long startedAtMs;
+        }
+
+        /// <summary>
+        /// A single event of a trace. Strings which are missing in the trace are empty, and numbers are -1.
+        /// Must match the layout of the Rust `BridgedTracingEvent` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedTracingEvent
+        {
+            internal readonly long timestampMs;
+            internal readonly FFIString activity;
+            internal readonly FFIString source;
+            internal readonly int sourceElapsedUs;
+            internal readonly FFIString thread;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void tracing_info_free(IntPtr tracingInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTracingSession tracing_info_get_session(IntPtr tracingInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint tracing_info_get_parameters_count(IntPtr tracingInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void tracing_info_get_parameter(IntPtr tracingInfo, nuint index, out FFIString name, out FFIString value);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint tracing_info_get_events_count(IntPtr tracingInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTracingEvent tracing_info_get_event(IntPtr tracingInfo, nuint index);
+
         /// <summary>
         /// The identifier of this trace.
         /// </summary>
@@ -176,7 +225,7 @@ namespace Cassandra
             //The instance is created before fetching the actual trace metadata
             //The properties will be populated later.
             _traceId = traceId;
-            _metadata = session.Cluster.Metadata;
+            _session = session;
             _metadataFetchSyncTimeout = session.Cluster.Configuration.DefaultRequestOptions.QueryAbortTimeout;
         }
 
@@ -188,14 +237,80 @@ namespace Cassandra
 
         private void MaybeFetchTrace()
         {
-            throw new NotImplementedException();
+            if (_isLoaded)
+            {
+                return;
+            }
+            lock (_fetchLock)
+            {
+                if (_isLoaded)
+                {
+                    return;
+                }
+                TaskHelper.WaitToComplete(LoadAsync(), _metadataFetchSyncTimeout);
+            }
+        }
+
+        /// <summary>
+        /// Retrieves the trace from the <c>system_traces</c> keyspace. Fails with <see cref="TraceRetrievalException"/>
+        /// if the trace is not complete after the configured number of attempts.
+        /// </summary>
+        internal async Task<QueryTrace> LoadAsync()
+        {
+            if (!(_session is Session session))
+            {
+                throw new NotSupportedException("Query traces can only be retrieved through a session created by the driver");
+            }
+            IntPtr tracingInfo = await session.GetTracingInfoAsync(_traceId).ConfigureAwait(false);
+            try
+            {
+                Fill(tracingInfo);
+            }
+            finally
+            {
+                tracing_info_free(tracingInfo);
+            }
+            return this;
+        }
+
+        private void Fill(IntPtr tracingInfo)
+        {
+            BridgedTracingSession traceSession = tracing_info_get_session(tracingInfo);
+            _requestType = traceSession.request.ToManagedString();
+            _duration = traceSession.durationUs < 0 ? int.MinValue : traceSession.durationUs;
+            _coordinator = ParseAddress(traceSession.coordinator);
+            _clientAddress = ParseAddress(traceSession.client);
+            _startedAt = traceSession.startedAtMs < 0 ? 0 : DateTimeOffset.FromUnixTimeMilliseconds(traceSession.startedAtMs).ToFileTime();
+
+            var parameters = new Dictionary<string, string>();
+            nuint parametersCount = tracing_info_get_parameters_count(tracingInfo);
+            for (nuint i = 0; i < parametersCount; i++)
+            {
+                tracing_info_get_parameter(tracingInfo, i, out FFIString name, out FFIString value);
+                parameters[name.ToManagedString()] = value.ToManagedString();
+            }
+            _parameters = parameters;
+
+            nuint eventsCount = tracing_info_get_events_count(tracingInfo);
+            var events = new List<Event>((int)eventsCount);
+            for (nuint i = 0; i < eventsCount; i++)
+            {
+                BridgedTracingEvent traceEvent = tracing_info_get_event(tracingInfo, i);
+                var timestamp = traceEvent.timestampMs < 0 ? default : DateTimeOffset.FromUnixTimeMilliseconds(traceEvent.timestampMs);
+                events.Add(new Event(
+                    traceEvent.activity.ToManagedString(),
+                    timestamp,
+                    ParseAddress(traceEvent.source),
+                    traceEvent.sourceElapsedUs,
+                    traceEvent.thread.ToManagedString()));
+            }
+            _events = events;
+            _isLoaded = true;
         }
 
-        internal Task<QueryTrace> LoadAsync()
+        private static IPAddress ParseAddress(FFIString address)
         {
-            // FIXME
-            // return _metadata.GetQueryTraceAsync(this);
-            throw new NotImplementedException();
+            return IPAddress.TryParse(address.ToManagedString(), out IPAddress parsed) ? parsed : null;
         }
 
         /// <summary>
@@ -61,6 +61,8 @@ namespace Cassandra
         /// </summary>
         public IDictionary<string, byte[]> IncomingPayload { get; internal set; }
 
+        private IPEndPoint _queriedHost;
+
         /// <summary>
         /// Retrieves the coordinator that responded to the request
         /// </summary>
@@ -68,6 +70,10 @@ namespace Cassandra
         {
             get
             {
+                if (_queriedHost != null)
+                {
+                    return _queriedHost;
+                }
                 if (TriedHosts == null)
                 {
                     throw new NullReferenceException("Tried host is null");
@@ -76,6 +82,17 @@ namespace Cassandra
             }
         }
 
+        /// <summary>
+        /// Gets the time from sending the request to receiving its response, or null if it is not known.
+        /// For results fetched page by page, it covers the pages fetched when the result set was created.
+        /// </summary>
+        public TimeSpan? Latency { get; internal set; }
+
+        /// <summary>
+        /// Id of the tracing session of the request, if tracing was enabled for it.
+        /// </summary>
+        internal Guid? TracingId { get; set; }
+
         /// <summary>
         /// Gets the trace for the query execution.
         /// </summary>
@@ -113,6 +130,11 @@ namespace Cassandra
             TriedHosts = triedHosts;
         }
 
+        internal void SetQueriedHost(IPEndPoint queriedHost)
+        {
+            _queriedHost = queriedHost;
+        }
+
         internal void SetQueryTrace(QueryTrace queryTrace)
         {
             QueryTrace = queryTrace;
@@ -25,6 +25,7 @@ using System.Threading.Tasks;
 using Cassandra.Tasks;
 using Cassandra.Serialization;
 using System.Linq;
+using System.Net;
 using System.Management;
 
 // ReSharper disable DoNotCallOverridableMethodsInConstructor
@@ -49,7 +50,7 @@ namespace Cassandra
     /// </para>
     /// </summary>
     /// <remarks>Parallel enumerations are supported and thread-safe.</remarks>
-    public class RowSet : SafeHandle, IEnumerable<Row>, IDisposable
+    public class RowSet : SafeHandle, IEnumerable<Row>, IAsyncEnumerable<Row>, IDisposable
     {
         public override bool IsInvalid => handle == IntPtr.Zero;
 
@@ -63,7 +64,43 @@ namespace Cassandra
         unsafe private static extern void row_set_free(IntPtr rowSetPtr);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern RustBridge.FfiException row_set_next_row(IntPtr rowSetPtr, IntPtr deserializeValue, IntPtr columnsPtr, IntPtr valuesPtr, IntPtr serializerPtr, [MarshalAs(UnmanagedType.U1)] out bool hasRow, IntPtr constructorsPtr);
+        unsafe private static extern RustBridge.FfiException row_set_next_page(IntPtr rowSetPtr, out IntPtr page, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void row_set_page_free(IntPtr page);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint row_set_page_get_rows_count(IntPtr page);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint row_set_page_get_columns_count(IntPtr page);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIByteSlice row_set_page_get_data(IntPtr page);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr row_set_page_get_cells(IntPtr page);
+
+        /// <summary>
+        /// Location of a single value within the data of a page.
+        /// Must match the layout of the Rust `BridgedCell` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedCell
+        {
+            internal readonly nuint offset;
+            // -1 for null values.
+            internal readonly nint len;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle row_set_fetch_next_page(Tcb tcb, IntPtr rowSetPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint row_set_buffered_rows_count(IntPtr rowSetPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void empty_bridged_result_free(IntPtr emptyBridgedResult);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern nuint row_set_get_columns_count(IntPtr rowSetPtr);
@@ -71,6 +108,54 @@ namespace Cassandra
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern RustBridge.FfiException row_set_fill_columns_metadata(IntPtr rowSetPtr, IntPtr columnsPtr, IntPtr metadataSetter, IntPtr constructorsPtr);
 
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        unsafe private static extern bool row_set_get_paging_state(IntPtr rowSetPtr, out FFIByteSlice pagingState);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr row_set_get_execution_info(IntPtr rowSetPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void execution_info_free(IntPtr executionInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        unsafe private static extern bool execution_info_get_coordinator(IntPtr executionInfo, out FFIString address, out ushort port);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern long execution_info_get_latency_us(IntPtr executionInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        unsafe private static extern bool execution_info_get_tracing_id(IntPtr executionInfo, byte* tracingId);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint execution_info_get_attempts_count(IntPtr executionInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedAttempt execution_info_get_attempt(IntPtr executionInfo, nuint index);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint execution_info_get_warnings_count(IntPtr executionInfo);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIString execution_info_get_warning(IntPtr executionInfo, nuint index);
+
+        /// <summary>
+        /// A single attempt to send the request to a node.
+        /// Must match the layout of the Rust `BridgedAttempt` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedAttempt
+        {
+            internal readonly FFIString address;
+            internal readonly ushort port;
+            internal readonly byte isSpeculative;
+            internal readonly byte succeeded;
+            internal readonly long latencyUs;
+            internal readonly FFIString error;
+        }
+
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern byte row_set_type_info_get_code(IntPtr typeInfoHandle);
 
@@ -100,23 +185,18 @@ namespace Cassandra
 
         private bool _exhausted = false;
 
+        private byte[] _pagingState;
+        private bool _pagingStateOverridden = false;
+
         /// <summary>
         /// Determines if when dequeuing, it will automatically fetch the following result pages.
         /// </summary>
-        protected internal bool AutoPage
-        {
-            get => throw new NotImplementedException("AutoPage getter is not yet implemented"); // FIXME: bridge with Rust paging.
-            set => throw new NotImplementedException("AutoPage setter is not yet implemented"); // FIXME: bridge with Rust paging.
-        }
+        protected internal bool AutoPage { get; set; }
 
         /// <summary>
         /// Gets or set the internal row list. It contains the rows of the latest query page.
         /// </summary>
-        protected virtual ConcurrentQueue<Row> RowQueue
-        {
-            get => throw new NotImplementedException("RowQueue getter is not yet implemented"); // FIXME: bridge with Rust paging.
-            set => throw new NotImplementedException("RowQueue setter is not yet implemented"); // FIXME: bridge with Rust paging.
-        }
+        protected virtual ConcurrentQueue<Row> RowQueue { get; set; } = new ConcurrentQueue<Row>();
 
         /// <summary>
         /// Gets the execution info of the query
@@ -134,8 +214,25 @@ namespace Cassandra
         /// </summary>
         public virtual byte[] PagingState
         {
-            get => throw new NotImplementedException("PagingState getter is not yet implemented"); // FIXME: bridge with Rust paging state.
-            protected internal set => throw new NotImplementedException("PagingState getter is not yet implemented"); // FIXME: bridge with Rust paging state.;
+            get
+            {
+                if (_pagingStateOverridden)
+                {
+                    return _pagingState;
+                }
+                // Paging state is only available for RowSets of a manually paged statement.
+                if (handle == IntPtr.Zero || !row_set_get_paging_state(handle, out FFIByteSlice pagingState))
+                {
+                    return null;
+                }
+                return pagingState.ToSpan().ToArray();
+            }
+            // Replaces the paging state received from the cluster, e.g. for RowSets built in tests.
+            protected internal set
+            {
+                _pagingState = value;
+                _pagingStateOverridden = true;
+            }
         }
 
         /// <summary>
@@ -145,7 +242,7 @@ namespace Cassandra
         /// <seealso cref="IsFullyFetched"/>
         public virtual bool IsExhausted()
         {
-            return _exhausted;
+            return _exhausted && RowQueue.IsEmpty;
         }
 
         /// <summary>
@@ -160,21 +257,87 @@ namespace Cassandra
         {
             handle = rowSetPtr;
             Columns = ExtractColumnsFromRust(rowSetPtr);
-            Info = new ExecutionInfo();
+            Info = ExtractExecutionInfoFromRust(rowSetPtr);
         }
 
         /// <summary>
-        /// Creates a new instance of RowSet.
+        /// Creates a new empty instance of RowSet, not backed by a Rust result.
         /// </summary>
         public RowSet() : base(IntPtr.Zero, true)
         {
             Info = new ExecutionInfo();
             Columns = new CqlColumn[0];
             AutoPage = true;
+            _exhausted = true;
+        }
+
+        private static ExecutionInfo ExtractExecutionInfoFromRust(IntPtr rowSetPtr)
+        {
+            var info = new ExecutionInfo();
+            IntPtr executionInfo = row_set_get_execution_info(rowSetPtr);
+            try
+            {
+                var triedHosts = new List<IPEndPoint>();
+                nuint attemptsCount = execution_info_get_attempts_count(executionInfo);
+                for (nuint i = 0; i < attemptsCount; i++)
+                {
+                    BridgedAttempt attempt = execution_info_get_attempt(executionInfo, i);
+                    triedHosts.Add(new IPEndPoint(IPAddress.Parse(attempt.address.ToManagedString()), attempt.port));
+                }
+                info.SetTriedHosts(triedHosts);
+
+                if (execution_info_get_coordinator(executionInfo, out FFIString coordinator, out ushort coordinatorPort))
+                {
+                    info.SetQueriedHost(new IPEndPoint(IPAddress.Parse(coordinator.ToManagedString()), coordinatorPort));
+                }
+
+                long latencyUs = execution_info_get_latency_us(executionInfo);
+                if (latencyUs >= 0)
+                {
+                    info.Latency = TimeSpan.FromTicks(latencyUs * TimeSpan.TicksPerMicrosecond);
+                }
+
+                unsafe
+                {
+                    // The tracing id is passed in network byte order, as UUIDs are serialized.
+                    var tracingId = new byte[16];
+                    fixed (byte* tracingIdPtr = tracingId)
+                    {
+                        if (execution_info_get_tracing_id(executionInfo, tracingIdPtr))
+                        {
+                            info.TracingId = new Guid(TypeSerializer.GuidShuffle(tracingId));
+                        }
+                    }
+                }
+
+                nuint warningsCount = execution_info_get_warnings_count(executionInfo);
+                if (warningsCount > 0)
+                {
+                    var warnings = new string[(int)warningsCount];
+                    for (int i = 0; i < warnings.Length; i++)
+                    {
+                        warnings[i] = execution_info_get_warning(executionInfo, (nuint)i).ToManagedString();
+                    }
+                    info.Warnings = warnings;
+                }
+            }
+            finally
+            {
+                execution_info_free(executionInfo);
+            }
+            return info;
+        }
+
+        /// <summary>
+        /// Returns the type code of the type described by a type info handle.
+        /// </summary>
+        internal static ColumnTypeCode GetTypeCode(IntPtr handle)
+        {
+            return (ColumnTypeCode)row_set_type_info_get_code(handle);
         }
 
         // This function is called from UnmanagedCallersOnly context - it must not throw exceptions.
-        private static IColumnInfo BuildTypeInfoFromHandle(IntPtr handle, ColumnTypeCode code)
+        internal static IColumnInfo BuildTypeInfoFromHandle(IntPtr handle, ColumnTypeCode code)
         {
             if (handle == IntPtr.Zero) return null;
             try
@@ -359,109 +522,12 @@ namespace Cassandra
                 return RustBridge.FfiException.Ok();
             }
         }
-#nullable enable
-        private Row? DeserializeRow()
-#nullable disable
-        {
-            object[] values = new object[Columns.Length];
-            var valuesHandle = GCHandle.Alloc(values);
-            IntPtr valuesPtr = GCHandle.ToIntPtr(valuesHandle);
-
-            var columnsHandle = GCHandle.Alloc(Columns);
-            IntPtr columnsPtr = GCHandle.ToIntPtr(columnsHandle);
-
-            // TODO: reuse the serializer instance. Perhaps a static instance? Then no need to pass it around by pointer.
-            IGenericSerializer serializer = (IGenericSerializer)new GenericSerializer();
-            var serializerHandle = GCHandle.Alloc(serializer);
-            IntPtr serializerPtr = GCHandle.ToIntPtr(serializerHandle);
-
-            RustBridge.FfiException res = default;
-            try
-            {
-                unsafe
-                {
-                    res = row_set_next_row(handle, (IntPtr)deserializeValue, columnsPtr, valuesPtr, serializerPtr, out bool hasRow, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
-                    // First, surface any exception and free the underlying handle
-                    RustBridge.ThrowIfException(ref res);
-
-                    if (!hasRow)
-                    {
-                        _exhausted = true;
-                        return null;
-                    }
-                }
-            }
-            finally
-            {
-                // Ensure the exception handle is freed even if an unrelated exception occurs
-                RustBridge.FreeExceptionHandle(ref res);
-                valuesHandle.Free();
-                columnsHandle.Free();
-                serializerHandle.Free();
-            }
-
-            var columnIndexes = new Dictionary<string, int>(StringComparer.Ordinal);
-            for (int i = 0; i < Columns.Length; ++i)
-            {
-                var name = Columns[i].Name;
-                if (string.IsNullOrEmpty(name))
-                    continue;
-                if (!columnIndexes.ContainsKey(name))
-                    columnIndexes[name] = i;
-            }
-
-            return new Row(values, Columns, columnIndexes);
-        }
-
-        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, IntPtr, nuint, IntPtr, FFIByteSlice, RustBridge.FfiException> deserializeValue = &DeserializeValue;
-
-        /// <summary>
-        /// This shall be called by Rust code for each column in a row.
-        /// </summary>
-        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
-        private static RustBridge.FfiException DeserializeValue(
-            IntPtr columnsPtr,
-            IntPtr valuesPtr,
-            nuint valueIndex,
-            IntPtr serializerPtr,
-            FFIByteSlice FFIframeSlice
-        )
-        {
-            try
-            {
-                var valuesHandle = GCHandle.FromIntPtr(valuesPtr);
-                var columnsHandle = GCHandle.FromIntPtr(columnsPtr);
-                var serializerHandle = GCHandle.FromIntPtr(serializerPtr);
-
-                if (valuesHandle.Target is object[] values && columnsHandle.Target is CqlColumn[] columns && serializerHandle.Target is IGenericSerializer serializer)
-                {
-                    CqlColumn column = columns[valueIndex];
-
-                    // TODO: reuse the frameSlice buffer.
-                    var frameSlice = FFIframeSlice.ToSpan().ToArray();
-                    int length = frameSlice.Length;
-                    values[valueIndex] = serializer.Deserialize(ProtocolVersion.V4, frameSlice, 0, length, column.TypeCode, column.TypeInfo);
-                }
-                else
-                {
-                    throw new InvalidOperationException("GCHandle referenced type mismatch.");
-                }
-                return RustBridge.FfiException.Ok();
-            }
-            catch (Exception ex)
-            {
-                Console.Error.WriteLine($"[FFI] DeserializeValue threw exception: {ex}");
-                return RustBridge.FfiException.FromException(ex);
-            }
-        }
-
-
         /// <summary>
         /// Forces the fetching the next page of results for this <see cref="RowSet"/>.
         /// </summary>
         public void FetchMoreResults()
         {
-            throw new NotImplementedException("FetchMoreResults is not yet implemented"); // FIXME: bridge with Rust paging.
+            FetchMoreResultsAsync().GetAwaiter().GetResult();
         }
 
         /// <summary>
@@ -473,7 +539,41 @@ namespace Cassandra
         /// </summary>
         public Task FetchMoreResultsAsync()
         {
-            throw new NotImplementedException("FetchMoreResultsAsync is not yet implemented"); // FIXME: bridge with Rust paging.
+            return FetchMoreResultsAsync(CancellationToken.None);
+        }
+
+        /// <summary>
+        /// Buffers the next page of rows in Rust without blocking the calling thread,
+        /// so that the following rows are deserialized without waiting for the cluster.
+        /// Does nothing if there are still buffered rows.
+        /// </summary>
+        internal async Task FetchMoreResultsAsync(CancellationToken cancellationToken)
+        {
+            if (handle == IntPtr.Zero)
+            {
+                // Empty RowSet, not backed by a Rust result.
+                return;
+            }
+
+            TaskCompletionSource<IntPtr> tcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
+            BridgedTaskHandle taskHandle;
+            bool refAdded = false;
+            try
+            {
+                // Rust clones the RowSet synchronously, so the ref is needed only during the call.
+                DangerousAddRef(ref refAdded);
+                taskHandle = row_set_fetch_next_page(Tcb.WithTcs(tcs), handle);
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+
+            IntPtr emptyBridgedResult = await taskHandle.WaitAsync(tcs.Task, cancellationToken).ConfigureAwait(false);
+            empty_bridged_result_free(emptyBridgedResult);
         }
 
         /// <summary>
@@ -481,7 +581,11 @@ namespace Cassandra
         /// </summary>
         public int GetAvailableWithoutFetching()
         {
-            throw new NotImplementedException("GetAvailableWithoutFetching is not yet implemented"); // FIXME: bridge with Rust paging.
+            if (handle == IntPtr.Zero)
+            {
+                return RowQueue.Count;
+            }
+            return RowQueue.Count + (int)row_set_buffered_rows_count(handle);
         }
 
         /// <summary>
@@ -497,16 +601,20 @@ namespace Cassandra
         /// <inheritdoc />
         public virtual IEnumerator<Row> GetEnumerator()
         {
-            while (!IsExhausted())
+            while (true)
             {
-                Row row = DeserializeRow();
-                if (row == null)
+                if (RowQueue.TryDequeue(out Row row))
+                {
+                    yield return row;
+                    continue;
+                }
+                if (IsExhausted())
+                {
                     yield break;
-
-                yield return row;
+                }
+                // Blocks if the next page has to be fetched.
+                PageNext();
             }
-
-            yield break;
         }
 
         IEnumerator IEnumerable.GetEnumerator()
@@ -514,12 +622,120 @@ namespace Cassandra
             return GetEnumerator();
         }
 
+        /// <summary>
+        /// Enumerates the rows asynchronously: whenever the buffered rows run out, the next page
+        /// is fetched without blocking the calling thread.
+        /// </summary>
+        public virtual async IAsyncEnumerator<Row> GetAsyncEnumerator(CancellationToken cancellationToken = default)
+        {
+            while (true)
+            {
+                if (RowQueue.TryDequeue(out Row row))
+                {
+                    yield return row;
+                    continue;
+                }
+                if (IsExhausted())
+                {
+                    yield break;
+                }
+                if (GetAvailableWithoutFetching() == 0)
+                {
+                    await FetchMoreResultsAsync(cancellationToken).ConfigureAwait(false);
+                }
+                // Takes the buffered rows, or finds out that there are no more rows.
+                PageNext();
+            }
+        }
+
         /// <summary>
         /// Gets the next results and add the rows to the current <see cref="RowSet"/> queue.
         /// </summary>
         protected virtual void PageNext()
         {
-            throw new NotImplementedException("PageNext is not yet implemented"); // FIXME: bridge with Rust paging.
+            if (handle == IntPtr.Zero)
+            {
+                // Empty RowSet, not backed by a Rust result.
+                _exhausted = true;
+                return;
+            }
+
+            IntPtr page = IntPtr.Zero;
+            RustBridge.FfiException res = default;
+            bool refAdded = false;
+            try
+            {
+                DangerousAddRef(ref refAdded);
+                unsafe
+                {
+                    res = row_set_next_page(handle, out page, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                }
+                RustBridge.ThrowIfException(ref res);
+                EnqueuePage(page);
+            }
+            finally
+            {
+                RustBridge.FreeExceptionHandle(ref res);
+                if (page != IntPtr.Zero)
+                {
+                    row_set_page_free(page);
+                }
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Deserializes all rows of a page received from Rust and adds them to the queue.
+        /// A page without rows means that there are no more rows.
+        /// </summary>
+        private void EnqueuePage(IntPtr page)
+        {
+            int rowsCount = (int)row_set_page_get_rows_count(page);
+            if (rowsCount == 0)
+            {
+                _exhausted = true;
+                return;
+            }
+            int columnsCount = (int)row_set_page_get_columns_count(page);
+
+            // The serializer reads values from an array, so the page data is copied once, as a whole.
+            byte[] data = row_set_page_get_data(page).ToSpan().ToArray();
+            ReadOnlySpan<BridgedCell> cells;
+            unsafe
+            {
+                cells = new ReadOnlySpan<BridgedCell>((void*)row_set_page_get_cells(page), rowsCount * columnsCount);
+            }
+
+            var columnIndexes = new Dictionary<string, int>(StringComparer.Ordinal);
+            for (int i = 0; i < Columns.Length; ++i)
+            {
+                var name = Columns[i].Name;
+                if (string.IsNullOrEmpty(name))
+                    continue;
+                if (!columnIndexes.ContainsKey(name))
+                    columnIndexes[name] = i;
+            }
+
+            IGenericSerializer serializer = new GenericSerializer();
+            for (int rowIndex = 0; rowIndex < rowsCount; rowIndex++)
+            {
+                // Null values are left as they are, as the array is initialized with nulls.
+                var values = new object[columnsCount];
+                for (int columnIndex = 0; columnIndex < columnsCount; columnIndex++)
+                {
+                    BridgedCell cell = cells[rowIndex * columnsCount + columnIndex];
+                    if (cell.len < 0)
+                    {
+                        continue;
+                    }
+                    CqlColumn column = Columns[columnIndex];
+                    values[columnIndex] = serializer.Deserialize(ProtocolVersion.V4, data, (int)cell.offset, (int)cell.len, column.TypeCode, column.TypeInfo);
+                }
+                RowQueue.Enqueue(new Row(values, Columns, columnIndexes));
+            }
         }
 
         /// <summary>
@@ -0,0 +1,143 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Lets the Rust driver answer SASL challenges with a custom <see cref="IAuthProvider"/>.
+    /// Rust calls the callbacks below for every new connection that requires authentication.
+    /// </summary>
+    internal static class AuthProviderBridge
+    {
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void auth_response_set_token(IntPtr response, IntPtr token, nuint tokenLen);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void auth_response_set_error(IntPtr response, [MarshalAs(UnmanagedType.LPUTF8Str)] string message);
+
+        /// <summary>
+        /// Must match the layout of the Rust `BridgedAuthenticatorCallbacks` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal readonly struct Callbacks
+        {
+            internal readonly IntPtr provider;
+            internal readonly IntPtr startSession;
+            internal readonly IntPtr evaluateChallenge;
+            internal readonly IntPtr freeAuthenticator;
+            internal readonly IntPtr freeProvider;
+
+            internal Callbacks(IntPtr provider, IntPtr startSession, IntPtr evaluateChallenge, IntPtr freeAuthenticator, IntPtr freeProvider)
+            {
+                this.provider = provider;
+                this.startSession = startSession;
+                this.evaluateChallenge = evaluateChallenge;
+                this.freeAuthenticator = freeAuthenticator;
+                this.freeProvider = freeProvider;
+            }
+        }
+
+        // The delegates are static to ensure 'static lifetime of the function pointers,
+        // as Rust calls them for as long as the session lives.
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, FFIString, IntPtr, IntPtr> startSessionDel = &StartSession;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, FFIByteSlice, IntPtr, void> evaluateChallengeDel = &EvaluateChallenge;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, void> freeHandleDel = &FreeHandle;
+
+        /// <summary>
+        /// Creates the callbacks for the given provider.
+        /// Ownership of the returned provider handle is transferred to Rust, which frees it
+        /// through the callbacks once the session no longer needs it.
+        /// </summary>
+        internal static Callbacks CreateCallbacks(IAuthProvider authProvider)
+        {
+            IntPtr providerPtr = GCHandle.ToIntPtr(GCHandle.Alloc(authProvider));
+            unsafe
+            {
+                return new Callbacks(
+                    providerPtr,
+                    (IntPtr)startSessionDel,
+                    (IntPtr)evaluateChallengeDel,
+                    (IntPtr)freeHandleDel,
+                    (IntPtr)freeHandleDel
+                );
+            }
+        }
+
+        /// <summary>
+        /// Creates an authenticator for a new connection and writes its initial response.
+        /// Returns a GCHandle to the authenticator, or IntPtr.Zero after setting an error on the response.
+        /// </summary>
+        // Exceptions must not cross the FFI boundary, so all of them are reported through the response.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static IntPtr StartSession(IntPtr providerPtr, FFIString authenticatorName, IntPtr response)
+        {
+            try
+            {
+                var provider = (IAuthProvider)GCHandle.FromIntPtr(providerPtr).Target;
+                if (provider is IAuthProviderNamed namedProvider)
+                {
+                    namedProvider.SetName(authenticatorName.ToManagedString());
+                }
+
+                // The Rust driver does not tell which node the connection is opened to.
+                IAuthenticator authenticator = provider.NewAuthenticator(null);
+                if (authenticator == null)
+                {
+                    auth_response_set_error(response, $"{provider.GetType().Name} returned no authenticator");
+                    return IntPtr.Zero;
+                }
+
+                SetToken(response, authenticator.InitialResponse());
+                return GCHandle.ToIntPtr(GCHandle.Alloc(authenticator));
+            }
+            catch (Exception ex)
+            {
+                auth_response_set_error(response, ex.Message);
+                return IntPtr.Zero;
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void EvaluateChallenge(IntPtr authenticatorPtr, FFIByteSlice challenge, IntPtr response)
+        {
+            try
+            {
+                var authenticator = (IAuthenticator)GCHandle.FromIntPtr(authenticatorPtr).Target;
+                SetToken(response, authenticator.EvaluateChallenge(challenge.ToSpan().ToArray()));
+            }
+            catch (Exception ex)
+            {
+                auth_response_set_error(response, ex.Message);
+            }
+        }
+
+        // Frees handles to both providers and authenticators.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void FreeHandle(IntPtr handlePtr)
+        {
+            try
+            {
+                GCHandle.FromIntPtr(handlePtr).Free();
+            }
+            catch (Exception ex)
+            {
+                Console.Error.WriteLine($"[FFI] Freeing auth handle threw exception: {ex}");
+            }
+        }
+
+        // A null token is left unset, which Rust treats as an empty response.
+        private static unsafe void SetToken(IntPtr response, byte[] token)
+        {
+            if (token == null)
+            {
+                return;
+            }
+            // Rust copies the token before returning, so pinning it for the call is enough.
+            fixed (byte* tokenPtr = token)
+            {
+                auth_response_set_token(response, (IntPtr)tokenPtr, (nuint)token.Length);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Handle to a task spawned by Rust, returned by every function taking a <see cref="Tcb"/>.
+    /// Allows cancelling the task, e.g. when a <see cref="CancellationToken"/> is triggered.
+    /// Releasing the handle does not affect the task.
+    /// </summary>
+    internal sealed class BridgedTaskHandle : SafeHandle
+    {
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void bridged_task_cancel(IntPtr handle);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void bridged_task_handle_free(IntPtr handle);
+
+        // Used by the marshaller when the handle is returned from Rust.
+        private BridgedTaskHandle() : base(IntPtr.Zero, true)
+        { }
+
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        protected override bool ReleaseHandle()
+        {
+            bridged_task_handle_free(handle);
+            return true;
+        }
+
+        /// <summary>
+        /// Cancels the task. The task fails with <see cref="OperationCanceledException"/>,
+        /// unless it has already completed.
+        /// </summary>
+        internal void Cancel()
+        {
+            bool refAdded = false;
+            try
+            {
+                DangerousAddRef(ref refAdded);
+                bridged_task_cancel(handle);
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Waits for the task to complete, cancelling it once <paramref name="cancellationToken"/> is triggered.
+        /// <paramref name="task"/> must be the task of the <see cref="TaskCompletionSource{TResult}"/>
+        /// passed to Rust together with this handle. The handle is released once the task completes.
+        /// </summary>
+        internal async Task<IntPtr> WaitAsync(Task<IntPtr> task, CancellationToken cancellationToken)
+        {
+            using (this)
+            using (cancellationToken.Register(Cancel))
+            {
+                return await task.ConfigureAwait(false);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static IntPtr OperationCanceledExceptionFromRust(FFIString message)
+        {
+            string msg = message.ToManagedString();
+
+            var exception = new OperationCanceledException(msg);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            IntPtr handlePtr = GCHandle.ToIntPtr(handle);
+            return handlePtr;
+        }
+    }
+}
@@ -0,0 +1,209 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Receives the node and schema changes reported by the Rust driver.
+    /// </summary>
+    internal interface IClusterEventListener
+    {
+        void OnHostAdded(Host host);
+
+        void OnHostRemoved(Host host);
+
+        void OnHostUp(Host host);
+
+        void OnHostDown(Host host);
+
+        void OnSchemaChanged(SchemaChangedEventArgs args);
+    }
+
+    /// <summary>
+    /// Lets the Rust driver report cluster events to an <see cref="IClusterEventListener"/>.
+    /// Rust calls the callbacks below from its runtime threads, one event at a time.
+    /// </summary>
+    internal static class ClusterEventBridge
+    {
+        private static readonly Logger Logger = new Logger(typeof(ClusterEventBridge));
+
+        // Values of the schema change target passed from Rust.
+        private const byte TargetKeyspace = 0;
+        private const byte TargetTable = 1;
+        private const byte TargetType = 2;
+        private const byte TargetMaterializedView = 3;
+
+        /// <summary>
+        /// Must match the layout of the Rust `BridgedClusterEventCallbacks` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal readonly struct Callbacks
+        {
+            internal readonly IntPtr listener;
+            internal readonly IntPtr nodeAdded;
+            internal readonly IntPtr nodeRemoved;
+            internal readonly IntPtr nodeUp;
+            internal readonly IntPtr nodeDown;
+            internal readonly IntPtr schemaChanged;
+            internal readonly IntPtr freeListener;
+
+            internal Callbacks(IntPtr listener, IntPtr nodeAdded, IntPtr nodeRemoved, IntPtr nodeUp, IntPtr nodeDown, IntPtr schemaChanged, IntPtr freeListener)
+            {
+                this.listener = listener;
+                this.nodeAdded = nodeAdded;
+                this.nodeRemoved = nodeRemoved;
+                this.nodeUp = nodeUp;
+                this.nodeDown = nodeDown;
+                this.schemaChanged = schemaChanged;
+                this.freeListener = freeListener;
+            }
+        }
+
+        // The delegates are static to ensure 'static lifetime of the function pointers,
+        // as Rust calls them for as long as the subscription lives.
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, ClusterState.BridgedNode, void> nodeAddedDel = &NodeAdded;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, ClusterState.BridgedNode, void> nodeRemovedDel = &NodeRemoved;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, ClusterState.BridgedNode, void> nodeUpDel = &NodeUp;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, ClusterState.BridgedNode, void> nodeDownDel = &NodeDown;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, byte, byte, FFIString, FFIString, void> schemaChangedDel = &SchemaChanged;
+        unsafe readonly static delegate* unmanaged[Cdecl]<IntPtr, void> freeListenerDel = &FreeListener;
+
+        /// <summary>
+        /// Creates the callbacks for the given listener.
+        /// Ownership of the returned listener handle is transferred to Rust, which frees it
+        /// through the callbacks once the subscription ends, or if subscribing fails.
+        /// </summary>
+        internal static Callbacks CreateCallbacks(IClusterEventListener listener)
+        {
+            IntPtr listenerPtr = GCHandle.ToIntPtr(GCHandle.Alloc(listener));
+            unsafe
+            {
+                return new Callbacks(
+                    listenerPtr,
+                    (IntPtr)nodeAddedDel,
+                    (IntPtr)nodeRemovedDel,
+                    (IntPtr)nodeUpDel,
+                    (IntPtr)nodeDownDel,
+                    (IntPtr)schemaChangedDel,
+                    (IntPtr)freeListenerDel
+                );
+            }
+        }
+
+        /// <summary>
+        /// Builds the event arguments of a schema change, as passed from Rust.
+        /// Returns null for an unknown target.
+        /// </summary>
+        internal static SchemaChangedEventArgs ToSchemaChangedEventArgs(byte change, byte target, string keyspace, string name)
+        {
+            var args = new SchemaChangedEventArgs
+            {
+                Keyspace = keyspace,
+                What = (SchemaChangedEventArgs.Kind)change
+            };
+            switch (target)
+            {
+                case TargetKeyspace:
+                    return args;
+                case TargetTable:
+                case TargetMaterializedView:
+                    args.Table = name;
+                    return args;
+                case TargetType:
+                    args.Type = name;
+                    return args;
+                default:
+                    return null;
+            }
+        }
+
+        // Exceptions must not cross the FFI boundary, so the ones thrown by event handlers are logged.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void NodeAdded(IntPtr listenerPtr, ClusterState.BridgedNode node)
+        {
+            try
+            {
+                GetListener(listenerPtr).OnHostAdded(ClusterState.ToHost(node));
+            }
+            catch (Exception ex)
+            {
+                Logger.Error("Handling of an added node threw exception", ex);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void NodeRemoved(IntPtr listenerPtr, ClusterState.BridgedNode node)
+        {
+            try
+            {
+                GetListener(listenerPtr).OnHostRemoved(ClusterState.ToHost(node));
+            }
+            catch (Exception ex)
+            {
+                Logger.Error("Handling of a removed node threw exception", ex);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void NodeUp(IntPtr listenerPtr, ClusterState.BridgedNode node)
+        {
+            try
+            {
+                GetListener(listenerPtr).OnHostUp(ClusterState.ToHost(node));
+            }
+            catch (Exception ex)
+            {
+                Logger.Error("Handling of a node going up threw exception", ex);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void NodeDown(IntPtr listenerPtr, ClusterState.BridgedNode node)
+        {
+            try
+            {
+                GetListener(listenerPtr).OnHostDown(ClusterState.ToHost(node));
+            }
+            catch (Exception ex)
+            {
+                Logger.Error("Handling of a node going down threw exception", ex);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void SchemaChanged(IntPtr listenerPtr, byte change, byte target, FFIString keyspace, FFIString name)
+        {
+            try
+            {
+                var args = ToSchemaChangedEventArgs(change, target, keyspace.ToManagedString(), name.ToManagedString());
+                if (args != null)
+                {
+                    GetListener(listenerPtr).OnSchemaChanged(args);
+                }
+            }
+            catch (Exception ex)
+            {
+                Logger.Error("Handling of a schema change threw exception", ex);
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void FreeListener(IntPtr listenerPtr)
+        {
+            try
+            {
+                GCHandle.FromIntPtr(listenerPtr).Free();
+            }
+            catch (Exception ex)
+            {
+                Console.Error.WriteLine($"[FFI] Freeing cluster event listener handle threw exception: {ex}");
+            }
+        }
+
+        private static IClusterEventListener GetListener(IntPtr listenerPtr)
+        {
+            return (IClusterEventListener)GCHandle.FromIntPtr(listenerPtr).Target;
+        }
+    }
+}
@@ -0,0 +1,28 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Subscription of an <see cref="IClusterEventListener"/> to the cluster events seen by a session.
+    /// Disposing it ends the subscription; it also ends when the session is shut down.
+    /// </summary>
+    internal sealed class ClusterEventSubscription : SafeHandle
+    {
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        internal ClusterEventSubscription(IntPtr subscriptionPtr) : base(IntPtr.Zero, true)
+        {
+            handle = subscriptionPtr;
+        }
+
+        protected override bool ReleaseHandle()
+        {
+            cluster_event_subscription_free(handle);
+            return true;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void cluster_event_subscription_free(IntPtr subscription);
+    }
+}
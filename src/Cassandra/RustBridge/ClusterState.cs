@@ -0,0 +1,503 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+using System.Runtime.InteropServices;
+using Cassandra.Serialization;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Snapshot of the cluster topology and schema, as known by a session when it was taken.
+    /// It does not change afterwards; it must be taken again to observe changes.
+    /// </summary>
+    internal sealed class ClusterState : SafeHandle
+    {
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        protected override bool ReleaseHandle()
+        {
+            cluster_state_free(handle);
+            return true;
+        }
+
+        /// <summary>
+        /// A single node of the cluster.
+        /// Must match the layout of the Rust `BridgedNode` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct BridgedNode
+        {
+            internal FFIString address;
+            internal ushort port;
+            // Host id (a UUID, in network byte order).
+            internal fixed byte hostId[16];
+            internal FFIString datacenter;
+            internal FFIString rack;
+            internal ushort shardCount;
+            internal byte isUp;
+            internal byte isEnabled;
+            internal FFIString releaseVersion;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void cluster_state_free(IntPtr state);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint cluster_state_get_nodes_count(IntPtr state);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedNode cluster_state_get_node(IntPtr state, nuint index);
+
+        /// <summary>
+        /// Replication strategy of a keyspace.
+        /// Must match the layout of the Rust `BridgedReplicationStrategy` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedReplicationStrategy
+        {
+            // 0 - SimpleStrategy, 1 - NetworkTopologyStrategy, 2 - LocalStrategy, 3 - other.
+            internal readonly byte kind;
+            internal readonly FFIString strategyClass;
+            internal readonly nuint replicationFactor;
+        }
+
+        /// <summary>
+        /// A column of a table or a materialized view.
+        /// Must match the layout of the Rust `BridgedColumn` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedColumn
+        {
+            internal readonly FFIString name;
+            // 0 - regular, 1 - static, 2 - clustering key, 3 - partition key.
+            internal readonly byte kind;
+            internal readonly byte typeCode;
+            internal readonly IntPtr typeInfoHandle;
+            internal readonly byte isFrozen;
+        }
+
+        /// <summary>
+        /// A single replica of a partition.
+        /// Must match the layout of the Rust `BridgedReplica` struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private unsafe struct BridgedReplica
+        {
+            internal FFIString address;
+            internal ushort port;
+            internal fixed byte hostId[16];
+            // -1 if the node is not sharded.
+            internal long shard;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException cluster_state_get_replicas_for_routing_key(
+            IntPtr state,
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string keyspace,
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string table,
+            byte* routingKey,
+            nuint routingKeyLen,
+            out IntPtr replicas,
+            IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void replicas_free(IntPtr replicas);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint replicas_get_count(IntPtr replicas);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedReplica replicas_get(IntPtr replicas, nuint index);
+
+        private const byte SimpleStrategy = 0;
+        private const byte NetworkTopologyStrategy = 1;
+        private const byte OtherStrategy = 3;
+
+        private const byte StaticColumn = 1;
+        private const byte ClusteringColumn = 2;
+        private const byte PartitionKeyColumn = 3;
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint cluster_state_get_keyspaces_count(IntPtr state);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr cluster_state_get_keyspace(IntPtr state, nuint index, out FFIString name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr cluster_state_find_keyspace(IntPtr state, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedReplicationStrategy keyspace_get_strategy(IntPtr keyspace);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint keyspace_get_strategy_options_count(IntPtr keyspace);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void keyspace_get_datacenter_replication_factor(IntPtr keyspace, nuint index, out FFIString datacenter, out nuint replicationFactor);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void keyspace_get_strategy_option(IntPtr keyspace, nuint index, out FFIString name, out FFIString value);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint keyspace_get_tables_count(IntPtr keyspace);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr keyspace_get_table(IntPtr keyspace, nuint index, out FFIString name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr keyspace_find_table(IntPtr keyspace, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint table_get_partition_key_count(IntPtr table);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIString table_get_partition_key(IntPtr table, nuint index);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint table_get_clustering_key_count(IntPtr table);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIString table_get_clustering_key(IntPtr table, nuint index);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint table_get_columns_count(IntPtr table);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedColumn table_get_column(IntPtr table, nuint index);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr keyspace_find_view(IntPtr keyspace, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr view_get_table(IntPtr view);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr keyspace_find_udt(IntPtr keyspace, [MarshalAs(UnmanagedType.LPUTF8Str)] string name);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern nuint udt_get_field_count(IntPtr udt);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void udt_get_field(IntPtr udt, nuint index, out FFIString fieldName, out IntPtr fieldTypeHandle);
+
+        internal ClusterState(IntPtr statePtr) : base(IntPtr.Zero, true)
+        {
+            handle = statePtr;
+        }
+
+        /// <summary>
+        /// Returns the nodes of the cluster.
+        /// </summary>
+        internal Host[] GetHosts()
+        {
+            return Read(state =>
+            {
+                var hosts = new Host[(int)cluster_state_get_nodes_count(state)];
+                for (int i = 0; i < hosts.Length; i++)
+                {
+                    hosts[i] = ToHost(cluster_state_get_node(state, (nuint)i));
+                }
+                return hosts;
+            });
+        }
+
+        /// <summary>
+        /// Returns the replicas owning the partition with the given routing key, in the order
+        /// preferred by the replication strategy of <paramref name="keyspace"/>.
+        /// If <paramref name="keyspace"/> is null, returns only the node owning the token.
+        /// <paramref name="table"/> only matters for keyspaces using tablets, whose replicas are placed per table.
+        /// If it is null, the replicas are computed from the token ring, as for other keyspaces.
+        /// </summary>
+        internal HostShard[] GetReplicas(string keyspace, string table, byte[] routingKey)
+        {
+            return Read(state =>
+            {
+                IntPtr replicas;
+                unsafe
+                {
+                    fixed (byte* routingKeyPtr = routingKey)
+                    {
+                        var res = cluster_state_get_replicas_for_routing_key(
+                            state, keyspace, table, routingKeyPtr, (nuint)routingKey.Length, out replicas,
+                            (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                        try
+                        {
+                            RustBridge.ThrowIfException(ref res);
+                        }
+                        finally
+                        {
+                            RustBridge.FreeExceptionHandle(ref res);
+                        }
+                    }
+                }
+                try
+                {
+                    var hosts = GetHosts().ToDictionary(host => host.HostId);
+                    var result = new HostShard[(int)replicas_get_count(replicas)];
+                    for (int i = 0; i < result.Length; i++)
+                    {
+                        BridgedReplica replica = replicas_get(replicas, (nuint)i);
+                        Guid hostId = ReadHostId(replica);
+                        if (!hosts.TryGetValue(hostId, out Host host))
+                        {
+                            // Replicas are computed from this snapshot, so this only happens if the node has no address.
+                            host = new Host(
+                                new IPEndPoint(IPAddress.Parse(replica.address.ToManagedString()), replica.port),
+                                hostId, null, null, null, false);
+                        }
+                        result[i] = new HostShard(host, (int)replica.shard);
+                    }
+                    return result;
+                }
+                finally
+                {
+                    replicas_free(replicas);
+                }
+            });
+        }
+
+        /// <summary>
+        /// Returns the names of all keyspaces.
+        /// </summary>
+        internal string[] GetKeyspaceNames()
+        {
+            return Read(state =>
+            {
+                var names = new string[(int)cluster_state_get_keyspaces_count(state)];
+                for (int i = 0; i < names.Length; i++)
+                {
+                    cluster_state_get_keyspace(state, (nuint)i, out FFIString name);
+                    names[i] = name.ToManagedString();
+                }
+                return names;
+            });
+        }
+
+        /// <summary>
+        /// Returns the keyspace named <paramref name="name"/>, or null if there is no such keyspace.
+        /// </summary>
+        internal KeyspaceMetadata GetKeyspace(Metadata parent, string name)
+        {
+            return Read(state =>
+            {
+                IntPtr keyspace = cluster_state_find_keyspace(state, name);
+                if (keyspace == IntPtr.Zero)
+                {
+                    return null;
+                }
+                BridgedReplicationStrategy strategy = keyspace_get_strategy(keyspace);
+                var replicationOptions = new Dictionary<string, string>();
+                switch (strategy.kind)
+                {
+                    case SimpleStrategy:
+                        replicationOptions["replication_factor"] = strategy.replicationFactor.ToString();
+                        break;
+                    case NetworkTopologyStrategy:
+                        for (nuint i = 0; i < keyspace_get_strategy_options_count(keyspace); i++)
+                        {
+                            keyspace_get_datacenter_replication_factor(keyspace, i, out FFIString datacenter, out nuint replicationFactor);
+                            replicationOptions[datacenter.ToManagedString()] = replicationFactor.ToString();
+                        }
+                        break;
+                    case OtherStrategy:
+                        for (nuint i = 0; i < keyspace_get_strategy_options_count(keyspace); i++)
+                        {
+                            keyspace_get_strategy_option(keyspace, i, out FFIString optionName, out FFIString optionValue);
+                            replicationOptions[optionName.ToManagedString()] = optionValue.ToManagedString();
+                        }
+                        break;
+                }
+                // Durable writes are not part of the driver's schema metadata; they are enabled unless disabled explicitly.
+                return new KeyspaceMetadata(parent, name, true, strategy.strategyClass.ToManagedString(), replicationOptions, null);
+            });
+        }
+
+        /// <summary>
+        /// Returns the names of the tables of a keyspace, or null if there is no such keyspace.
+        /// </summary>
+        internal string[] GetTableNames(string keyspaceName)
+        {
+            return Read(state =>
+            {
+                IntPtr keyspace = cluster_state_find_keyspace(state, keyspaceName);
+                if (keyspace == IntPtr.Zero)
+                {
+                    return null;
+                }
+                var names = new string[(int)keyspace_get_tables_count(keyspace)];
+                for (int i = 0; i < names.Length; i++)
+                {
+                    keyspace_get_table(keyspace, (nuint)i, out FFIString name);
+                    names[i] = name.ToManagedString();
+                }
+                return names;
+            });
+        }
+
+        /// <summary>
+        /// Returns the table, or null if there is no such keyspace or table.
+        /// </summary>
+        internal TableMetadata GetTable(string keyspaceName, string tableName)
+        {
+            return Read(state =>
+            {
+                IntPtr table = FindInKeyspace(state, keyspaceName, keyspace => keyspace_find_table(keyspace, tableName));
+                if (table == IntPtr.Zero)
+                {
+                    return null;
+                }
+                var metadata = new TableMetadata(tableName, null);
+                FillColumns(metadata, table, keyspaceName, tableName);
+                return metadata;
+            });
+        }
+
+        /// <summary>
+        /// Returns the materialized view, or null if there is no such keyspace or view.
+        /// </summary>
+        internal MaterializedViewMetadata GetMaterializedView(string keyspaceName, string viewName)
+        {
+            return Read(state =>
+            {
+                IntPtr view = FindInKeyspace(state, keyspaceName, keyspace => keyspace_find_view(keyspace, viewName));
+                if (view == IntPtr.Zero)
+                {
+                    return null;
+                }
+                // The where clause is not part of the driver's schema metadata.
+                var metadata = new MaterializedViewMetadata(viewName, null);
+                FillColumns(metadata, view_get_table(view), keyspaceName, viewName);
+                return metadata;
+            });
+        }
+
+        /// <summary>
+        /// Returns the definition of a user defined type, or null if there is no such keyspace or type.
+        /// </summary>
+        internal UdtColumnInfo GetUdtDefinition(string keyspaceName, string typeName)
+        {
+            return Read(state =>
+            {
+                IntPtr udt = FindInKeyspace(state, keyspaceName, keyspace => keyspace_find_udt(keyspace, typeName));
+                if (udt == IntPtr.Zero)
+                {
+                    return null;
+                }
+                var udtInfo = new UdtColumnInfo(keyspaceName + "." + typeName);
+                nuint fieldsCount = udt_get_field_count(udt);
+                for (nuint i = 0; i < fieldsCount; i++)
+                {
+                    udt_get_field(udt, i, out FFIString fieldName, out IntPtr fieldTypeHandle);
+                    var fieldTypeCode = RowSet.GetTypeCode(fieldTypeHandle);
+                    udtInfo.Fields.Add(new ColumnDesc
+                    {
+                        Name = fieldName.ToManagedString(),
+                        TypeCode = fieldTypeCode,
+                        TypeInfo = RowSet.BuildTypeInfoFromHandle(fieldTypeHandle, fieldTypeCode),
+                    });
+                }
+                return udtInfo;
+            });
+        }
+
+        private static IntPtr FindInKeyspace(IntPtr state, string keyspaceName, Func<IntPtr, IntPtr> find)
+        {
+            IntPtr keyspace = cluster_state_find_keyspace(state, keyspaceName);
+            return keyspace == IntPtr.Zero ? IntPtr.Zero : find(keyspace);
+        }
+
+        private static void FillColumns(DataCollectionMetadata metadata, IntPtr table, string keyspaceName, string tableName)
+        {
+            var columns = new Dictionary<string, TableColumn>();
+            nuint columnsCount = table_get_columns_count(table);
+            for (nuint i = 0; i < columnsCount; i++)
+            {
+                BridgedColumn bridgedColumn = table_get_column(table, i);
+                var typeCode = (ColumnTypeCode)bridgedColumn.typeCode;
+                var column = new TableColumn
+                {
+                    Keyspace = keyspaceName,
+                    Table = tableName,
+                    Name = bridgedColumn.name.ToManagedString(),
+                    TypeCode = typeCode,
+                    TypeInfo = RowSet.BuildTypeInfoFromHandle(bridgedColumn.typeInfoHandle, typeCode),
+                    IsStatic = bridgedColumn.kind == StaticColumn,
+                    IsFrozen = bridgedColumn.isFrozen != 0,
+                    KeyType = bridgedColumn.kind switch
+                    {
+                        PartitionKeyColumn => KeyType.Partition,
+                        ClusteringColumn => KeyType.Clustering,
+                        _ => KeyType.None,
+                    },
+                };
+                columns[column.Name] = column;
+            }
+
+            var partitionKeys = new TableColumn[(int)table_get_partition_key_count(table)];
+            for (int i = 0; i < partitionKeys.Length; i++)
+            {
+                partitionKeys[i] = columns[table_get_partition_key(table, (nuint)i).ToManagedString()];
+            }
+            // The clustering order is not part of the driver's schema metadata.
+            var clusteringKeys = new Tuple<TableColumn, DataCollectionMetadata.SortOrder>[(int)table_get_clustering_key_count(table)];
+            for (int i = 0; i < clusteringKeys.Length; i++)
+            {
+                var column = columns[table_get_clustering_key(table, (nuint)i).ToManagedString()];
+                clusteringKeys[i] = Tuple.Create(column, DataCollectionMetadata.SortOrder.Ascending);
+            }
+            metadata.SetValues(columns, partitionKeys, clusteringKeys, null);
+        }
+
+        /// <summary>
+        /// Reads the snapshot, keeping it alive meanwhile, as all handles obtained from it borrow from it.
+        /// </summary>
+        private T Read<T>(Func<IntPtr, T> read)
+        {
+            bool refAdded = false;
+            try
+            {
+                DangerousAddRef(ref refAdded);
+                return read(handle);
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
+        private static unsafe Guid ReadHostId(BridgedReplica replica)
+        {
+            var hostId = new byte[16];
+            for (int i = 0; i < hostId.Length; i++)
+            {
+                hostId[i] = replica.hostId[i];
+            }
+            return new Guid(TypeSerializer.GuidShuffle(hostId));
+        }
+
+        internal static unsafe Host ToHost(BridgedNode node)
+        {
+            var hostId = new byte[16];
+            for (int i = 0; i < hostId.Length; i++)
+            {
+                hostId[i] = node.hostId[i];
+            }
+            return new Host(
+                new IPEndPoint(IPAddress.Parse(node.address.ToManagedString()), node.port),
+                new Guid(TypeSerializer.GuidShuffle(hostId)),
+                EmptyToNull(node.datacenter.ToManagedString()),
+                EmptyToNull(node.rack.ToManagedString()),
+                Host.ParseReleaseVersion(node.releaseVersion.ToManagedString()),
+                node.isUp != 0);
+        }
+
+        private static string EmptyToNull(string value)
+        {
+            return value.Length == 0 ? null : value;
+        }
+    }
+}
@@ -172,20 +172,34 @@ namespace Cassandra
         // Exception constructors passed to Rust
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, IntPtr> AlreadyExistsConstructorPtr = &AlreadyExistsException.AlreadyExistsExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> AlreadyShutdownExceptionConstructorPtr = &AlreadyShutdownException.AlreadyShutdownExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> AuthenticationExceptionConstructorPtr = &AuthenticationException.AuthenticationExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> DeserializationExceptionConstructorPtr = &DeserializationException.DeserializationExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> FunctionFailureExceptionConstructorPtr = &FunctionFailureException.FunctionFailureExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> InvalidConfigurationInQueryExceptionConstructorPtr = &InvalidConfigurationInQueryException.InvalidConfigurationInQueryExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> InvalidContactPointExceptionConstructorPtr = &InvalidContactPointException.InvalidContactPointExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> InvalidKeyspaceExceptionConstructorPtr = &InvalidKeyspaceException.InvalidKeyspaceExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> InvalidQueryConstructorPtr = &InvalidQueryException.InvalidQueryExceptionFromRust;
-        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> NoHostAvailableExceptionConstructorPtr = &NoHostAvailableException.NoHostAvailableExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> IsBootstrappingExceptionConstructorPtr = &IsBootstrappingException.IsBootstrappingExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr, nuint, IntPtr> NoHostAvailableExceptionConstructorPtr = &NoHostAvailableException.NoHostAvailableExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> OperationCanceledExceptionConstructorPtr = &BridgedTaskHandle.OperationCanceledExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, int, IntPtr> OperationTimedOutExceptionConstructorPtr = &OperationTimedOutException.OperationTimedOutExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> OverloadedExceptionConstructorPtr = &OverloadedException.OverloadedExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIByteSlice, IntPtr> PreparedQueryNotFoundExceptionConstructorPtr = &PreparedQueryNotFoundException.PreparedQueryNotFoundExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<short, int, int, int, byte, IntPtr> ReadFailureExceptionConstructorPtr = &ReadFailureException.ReadFailureExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<short, int, int, byte, IntPtr> ReadTimeoutExceptionConstructorPtr = &ReadTimeoutException.ReadTimeoutExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> RequestInvalidExceptionConstructorPtr = &RequestInvalidException.RequestInvalidExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> ResultMetadataChangedExceptionConstructorPtr = &ResultMetadataChangedException.ResultMetadataChangedExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> RustExceptionConstructorPtr = &RustException.RustExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> SerializationExceptionConstructorPtr = &SerializationException.SerializationExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> ServerErrorExceptionConstructorPtr = &ServerErrorException.ServerErrorExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> SyntaxErrorExceptionConstructorPtr = &SyntaxError.SyntaxErrorFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> TlsExceptionConstructorPtr = &TlsException.TlsExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> TraceRetrievalExceptionConstructorPtr = &TraceRetrievalException.TraceRetrievalExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> TruncateExceptionConstructorPtr = &TruncateException.TruncateExceptionFromRust;
         unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, IntPtr> UnauthorizedExceptionConstructorPtr = &UnauthorizedException.UnauthorizedExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<short, int, int, IntPtr> UnavailableExceptionConstructorPtr = &UnavailableException.UnavailableExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<short, int, int, int, FFIString, IntPtr> WriteFailureExceptionConstructorPtr = &WriteFailureException.WriteFailureExceptionFromRust;
+        unsafe readonly static delegate* unmanaged[Cdecl]<short, int, int, FFIString, IntPtr> WriteTimeoutExceptionConstructorPtr = &WriteTimeoutException.WriteTimeoutExceptionFromRust;
 
         /// <summary>
         /// Table of exception constructors passed to Rust via TCB.
@@ -198,55 +212,97 @@ namespace Cassandra
         {
             internal readonly IntPtr already_exists_constructor;
             internal readonly IntPtr already_shutdown_exception_constructor;
+            internal readonly IntPtr authentication_exception_constructor;
             internal readonly IntPtr deserialization_exception_constructor;
             internal readonly IntPtr function_failure_exception_constructor;
             internal readonly IntPtr invalid_configuration_in_query_constructor;
+            internal readonly IntPtr invalid_contact_point_exception_constructor;
+            internal readonly IntPtr invalid_keyspace_exception_constructor;
             internal readonly IntPtr invalid_query_constructor;
+            internal readonly IntPtr is_bootstrapping_exception_constructor;
             internal readonly IntPtr no_host_available_exception_constructor;
+            internal readonly IntPtr operation_canceled_exception_constructor;
             internal readonly IntPtr operation_timed_out_exception_constructor;
+            internal readonly IntPtr overloaded_exception_constructor;
             internal readonly IntPtr prepared_query_not_found_exception_constructor;
+            internal readonly IntPtr read_failure_exception_constructor;
+            internal readonly IntPtr read_timeout_exception_constructor;
             internal readonly IntPtr request_invalid_exception_constructor;
+            internal readonly IntPtr result_metadata_changed_exception_constructor;
             internal readonly IntPtr rust_exception_constructor;
             internal readonly IntPtr serialization_exception_constructor;
+            internal readonly IntPtr server_error_exception_constructor;
             internal readonly IntPtr syntax_error_exception_constructor;
+            internal readonly IntPtr tls_exception_constructor;
             internal readonly IntPtr trace_retrieval_exception_constructor;
             internal readonly IntPtr truncate_exception_constructor;
             internal readonly IntPtr unauthorized_exception_constructor;
+            internal readonly IntPtr unavailable_exception_constructor;
+            internal readonly IntPtr write_failure_exception_constructor;
+            internal readonly IntPtr write_timeout_exception_constructor;
 
             internal Constructors(
                 IntPtr alreadyExistsException,
                 IntPtr alreadyShutdownException,
+                IntPtr authenticationException,
                 IntPtr deserializationException,
                 IntPtr functionFailureException,
                 IntPtr invalidConfigurationInQueryException,
+                IntPtr invalidContactPointException,
+                IntPtr invalidKeyspaceException,
                 IntPtr invalidQueryException,
+                IntPtr isBootstrappingException,
                 IntPtr noHostAvailableException,
+                IntPtr operationCanceledException,
                 IntPtr operationTimedOutException,
+                IntPtr overloadedException,
                 IntPtr preparedQueryNotFoundException,
+                IntPtr readFailureException,
+                IntPtr readTimeoutException,
                 IntPtr requestInvalidException,
+                IntPtr resultMetadataChangedException,
                 IntPtr rustException,
                 IntPtr serializationException,
+                IntPtr serverErrorException,
                 IntPtr syntaxErrorException,
+                IntPtr tlsException,
                 IntPtr traceRetrievalException,
                 IntPtr truncateException,
-                IntPtr unauthorizedException)
+                IntPtr unauthorizedException,
+                IntPtr unavailableException,
+                IntPtr writeFailureException,
+                IntPtr writeTimeoutException)
             {
                 already_exists_constructor = alreadyExistsException;
                 already_shutdown_exception_constructor = alreadyShutdownException;
+                authentication_exception_constructor = authenticationException;
                 deserialization_exception_constructor = deserializationException;
                 function_failure_exception_constructor = functionFailureException;
                 invalid_configuration_in_query_constructor = invalidConfigurationInQueryException;
+                invalid_contact_point_exception_constructor = invalidContactPointException;
+                invalid_keyspace_exception_constructor = invalidKeyspaceException;
                 invalid_query_constructor = invalidQueryException;
+                is_bootstrapping_exception_constructor = isBootstrappingException;
                 no_host_available_exception_constructor = noHostAvailableException;
+                operation_canceled_exception_constructor = operationCanceledException;
                 operation_timed_out_exception_constructor = operationTimedOutException;
+                overloaded_exception_constructor = overloadedException;
                 prepared_query_not_found_exception_constructor = preparedQueryNotFoundException;
+                read_failure_exception_constructor = readFailureException;
+                read_timeout_exception_constructor = readTimeoutException;
                 request_invalid_exception_constructor = requestInvalidException;
+                result_metadata_changed_exception_constructor = resultMetadataChangedException;
                 rust_exception_constructor = rustException;
                 serialization_exception_constructor = serializationException;
+                server_error_exception_constructor = serverErrorException;
                 syntax_error_exception_constructor = syntaxErrorException;
+                tls_exception_constructor = tlsException;
                 trace_retrieval_exception_constructor = traceRetrievalException;
                 truncate_exception_constructor = truncateException;
                 unauthorized_exception_constructor = unauthorizedException;
+                unavailable_exception_constructor = unavailableException;
+                write_failure_exception_constructor = writeFailureException;
+                write_timeout_exception_constructor = writeTimeoutException;
             }
         }
 
@@ -259,20 +315,34 @@ namespace Cassandra
             *ConstructorsPtr = new Constructors(
                 (IntPtr)AlreadyExistsConstructorPtr,
                 (IntPtr)AlreadyShutdownExceptionConstructorPtr,
+                (IntPtr)AuthenticationExceptionConstructorPtr,
                 (IntPtr)DeserializationExceptionConstructorPtr,
                 (IntPtr)FunctionFailureExceptionConstructorPtr,
                 (IntPtr)InvalidConfigurationInQueryExceptionConstructorPtr,
+                (IntPtr)InvalidContactPointExceptionConstructorPtr,
+                (IntPtr)InvalidKeyspaceExceptionConstructorPtr,
                 (IntPtr)InvalidQueryConstructorPtr,
+                (IntPtr)IsBootstrappingExceptionConstructorPtr,
                 (IntPtr)NoHostAvailableExceptionConstructorPtr,
+                (IntPtr)OperationCanceledExceptionConstructorPtr,
                 (IntPtr)OperationTimedOutExceptionConstructorPtr,
+                (IntPtr)OverloadedExceptionConstructorPtr,
                 (IntPtr)PreparedQueryNotFoundExceptionConstructorPtr,
+                (IntPtr)ReadFailureExceptionConstructorPtr,
+                (IntPtr)ReadTimeoutExceptionConstructorPtr,
                 (IntPtr)RequestInvalidExceptionConstructorPtr,
+                (IntPtr)ResultMetadataChangedExceptionConstructorPtr,
                 (IntPtr)RustExceptionConstructorPtr,
                 (IntPtr)SerializationExceptionConstructorPtr,
+                (IntPtr)ServerErrorExceptionConstructorPtr,
                 (IntPtr)SyntaxErrorExceptionConstructorPtr,
+                (IntPtr)TlsExceptionConstructorPtr,
                 (IntPtr)TraceRetrievalExceptionConstructorPtr,
                 (IntPtr)TruncateExceptionConstructorPtr,
-                (IntPtr)UnauthorizedExceptionConstructorPtr
+                (IntPtr)UnauthorizedExceptionConstructorPtr,
+                (IntPtr)UnavailableExceptionConstructorPtr,
+                (IntPtr)WriteFailureExceptionConstructorPtr,
+                (IntPtr)WriteTimeoutExceptionConstructorPtr
             );
         }
     }
@@ -370,7 +440,15 @@ namespace Cassandra
                             // Fallback to a generic RustException if no exception was passed.
                             exception = new RustException("Unknown error from Rust");
                         }
-                        tcs.SetException(exception);
+                        if (exception is OperationCanceledException)
+                        {
+                            // Cancelled through the task's BridgedTaskHandle.
+                            tcs.SetCanceled();
+                        }
+                        else
+                        {
+                            tcs.SetException(exception);
+                        }
                     }
                     finally
                     {
@@ -0,0 +1,173 @@
+using System;
+using System.Collections.Concurrent;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Threading;
+using Microsoft.Extensions.Logging;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Forwards the log events of the Rust driver to the <see cref="ILoggerFactory"/> of <see cref="Diagnostics"/>.
+    /// <para>
+    /// Rust logging can be initialized only once, and only before the native runtime starts;
+    /// afterwards, Rust logs go to stdout, filtered by the <c>RUST_LOG</c> environment variable.
+    /// </para>
+    /// </summary>
+    internal static class RustLogging
+    {
+        /// <summary>
+        /// Filter used unless the <c>RUST_LOG</c> environment variable is set.
+        /// Events below info level are frequent enough to slow down every request, so they are opt-in.
+        /// </summary>
+        private const string DefaultFilter = "info";
+
+        private static readonly Logger Logger = new Logger(typeof(RustLogging));
+
+        private static readonly ConcurrentDictionary<string, ILogger> Loggers = new ConcurrentDictionary<string, ILogger>();
+
+        private static int _initialized;
+
+        /// <summary>
+        /// A structured field of a log event, other than its message.
+        /// All changes to this struct must be mirrored in Rust code in the exact same order.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedLogField
+        {
+            internal readonly FFIString name;
+            internal readonly FFIString value;
+        }
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException logging_init(
+            IntPtr callback,
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string filter,
+            IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException logging_set_filter(
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string filter,
+            IntPtr constructorsPtr);
+
+        // Static to ensure 'static lifetime of the function pointer, as Rust calls it until the process exits.
+        unsafe readonly static delegate* unmanaged[Cdecl]<byte, FFIString, FFIString, IntPtr, nuint, void> LogDel = &Log;
+
+        /// <summary>
+        /// Starts forwarding Rust logs to the logger factory, if the driver logs through it.
+        /// Has no effect once called, or if the driver logs through <see cref="System.Diagnostics.Trace"/>.
+        /// </summary>
+        internal static void EnsureInitialized()
+        {
+            if (!Diagnostics.UseLoggerFactory || Interlocked.Exchange(ref _initialized, 1) == 1)
+            {
+                return;
+            }
+
+            var filter = Environment.GetEnvironmentVariable("RUST_LOG") ?? DefaultFilter;
+            unsafe
+            {
+                var res = logging_init((IntPtr)LogDel, filter, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                try
+                {
+                    RustBridge.ThrowIfException(ref res);
+                }
+                catch (RustException ex)
+                {
+                    // Logging is not a reason to fail; Rust logs keep going to stdout.
+                    Logger.Warning("Rust driver logs are not forwarded to the logger factory: {0}", ex.Message);
+                }
+                finally
+                {
+                    RustBridge.FreeExceptionHandle(ref res);
+                }
+            }
+        }
+
+        /// <summary>
+        /// Changes which Rust log events are emitted, using the <c>RUST_LOG</c> syntax (e.g. <c>info,scylla=debug</c>).
+        /// </summary>
+        internal static void SetFilter(string filter)
+        {
+            if (filter == null)
+            {
+                throw new ArgumentNullException(nameof(filter));
+            }
+            unsafe
+            {
+                var res = logging_set_filter(filter, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                try
+                {
+                    RustBridge.ThrowIfException(ref res);
+                }
+                finally
+                {
+                    RustBridge.FreeExceptionHandle(ref res);
+                }
+            }
+        }
+
+        /// <summary>
+        /// Maps the level passed from Rust: 0 - trace, 1 - debug, 2 - info, 3 - warn, 4 - error.
+        /// </summary>
+        internal static LogLevel ToLogLevel(byte level)
+        {
+            return level switch
+            {
+                0 => LogLevel.Trace,
+                1 => LogLevel.Debug,
+                2 => LogLevel.Information,
+                3 => LogLevel.Warning,
+                _ => LogLevel.Error,
+            };
+        }
+
+        /// <summary>
+        /// Appends the fields to the message as <c>name=value</c> pairs.
+        /// </summary>
+        internal static string FormatMessage(string message, ReadOnlySpan<(string Name, string Value)> fields)
+        {
+            if (fields.IsEmpty)
+            {
+                return message;
+            }
+            var sb = new StringBuilder(message);
+            foreach (var (name, value) in fields)
+            {
+                sb.Append(' ').Append(name).Append('=').Append(value);
+            }
+            return sb.ToString();
+        }
+
+        /// <summary>
+        /// Called by Rust for every log event, from any thread. Must not throw.
+        /// </summary>
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static unsafe void Log(byte level, FFIString target, FFIString message, IntPtr fields, nuint fieldsCount)
+        {
+            try
+            {
+                var logLevel = ToLogLevel(level);
+                var logger = Loggers.GetOrAdd(target.ToManagedString(), static category => Diagnostics.LoggerFactory.CreateLogger(category));
+                if (!logger.IsEnabled(logLevel))
+                {
+                    return;
+                }
+
+                var bridgedFields = new ReadOnlySpan<BridgedLogField>((void*)fields, (int)fieldsCount);
+                var recorded = new (string Name, string Value)[bridgedFields.Length];
+                for (var i = 0; i < bridgedFields.Length; i++)
+                {
+                    recorded[i] = (bridgedFields[i].name.ToManagedString(), bridgedFields[i].value.ToManagedString());
+                }
+                // The message is passed as the state, so that braces in it are not parsed as a message template.
+                logger.Log(logLevel, 0, FormatMessage(message.ToManagedString(), recorded), null, static (state, _) => state);
+            }
+            catch
+            {
+                // Throwing across the FFI boundary is UB, and a failing logger must not break requests.
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Threading;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Reports the spans of operations executed by the Rust driver as activities,
+    /// children of the <see cref="Activity"/> that was current when each operation started.
+    /// <para>
+    /// The activities are created once the operations finish, so they are never current themselves.
+    /// Like Rust logs, the spans are subject to the native log filter, see <see cref="Diagnostics.SetNativeLogFilter"/>.
+    /// </para>
+    /// </summary>
+    internal static class RustSpans
+    {
+        /// <summary>
+        /// Same name as the activity source of the Cassandra.OpenTelemetry extension,
+        /// so that applications subscribed to the driver's activities receive these as well.
+        /// </summary>
+        internal const string ActivitySourceName = "Cassandra.OpenTelemetry";
+
+        internal static readonly ActivitySource Source = new ActivitySource(ActivitySourceName);
+
+        private static int _initialized;
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void span_export_init(IntPtr callback);
+
+        // Static to ensure 'static lifetime of the function pointer, as Rust calls it until the process exits.
+        unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, long, long, byte, IntPtr, nuint, void> SpanEndedDel = &SpanEnded;
+
+        /// <summary>
+        /// A span attribute, laid out as the Rust `BridgedLogField`.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct BridgedAttribute
+        {
+            internal readonly FFIString name;
+            internal readonly FFIString value;
+        }
+
+        /// <summary>
+        /// Starts receiving the spans of the Rust driver. Has no effect once called.
+        /// </summary>
+        internal static void EnsureInitialized()
+        {
+            if (Interlocked.Exchange(ref _initialized, 1) == 1)
+            {
+                return;
+            }
+            unsafe
+            {
+                span_export_init((IntPtr)SpanEndedDel);
+            }
+        }
+
+        /// <summary>
+        /// Reports a finished span as a stopped activity, if anyone listens to <see cref="Source"/>.
+        /// Spans with a parent which is not a valid W3C <c>traceparent</c> are ignored.
+        /// </summary>
+        internal static void Report(
+            string name,
+            string traceParent,
+            DateTime startTimeUtc,
+            DateTime endTimeUtc,
+            bool isError,
+            IEnumerable<KeyValuePair<string, string>> attributes)
+        {
+            if (!ActivityContext.TryParse(traceParent, null, out ActivityContext parentContext))
+            {
+                return;
+            }
+            using Activity activity = Source.CreateActivity(name, ActivityKind.Client, parentContext);
+            if (activity == null)
+            {
+                return;
+            }
+
+            activity.SetStartTime(startTimeUtc);
+            foreach (var attribute in attributes)
+            {
+                activity.SetTag(attribute.Key, attribute.Value);
+            }
+            if (isError)
+            {
+                activity.SetStatus(ActivityStatusCode.Error);
+            }
+            // Starting makes the activity current on this thread until it is stopped right away.
+            activity.Start();
+            activity.SetEndTime(endTimeUtc);
+            activity.Stop();
+        }
+
+        private static DateTime FromUnixMicroseconds(long microseconds)
+        {
+            return DateTime.UnixEpoch.AddTicks(microseconds * TimeSpan.TicksPerMicrosecond);
+        }
+
+        /// <summary>
+        /// Called by Rust for every finished span with a C# parent, from any thread. Must not throw.
+        /// </summary>
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static unsafe void SpanEnded(FFIString name, FFIString parent, long startUs, long endUs, byte isError, IntPtr attributes, nuint attributesCount)
+        {
+            try
+            {
+                var bridgedAttributes = new ReadOnlySpan<BridgedAttribute>((void*)attributes, (int)attributesCount);
+                var recorded = new KeyValuePair<string, string>[bridgedAttributes.Length];
+                for (var i = 0; i < bridgedAttributes.Length; i++)
+                {
+                    recorded[i] = new KeyValuePair<string, string>(bridgedAttributes[i].name.ToManagedString(), bridgedAttributes[i].value.ToManagedString());
+                }
+                Report(
+                    name.ToManagedString(),
+                    parent.ToManagedString(),
+                    FromUnixMicroseconds(startUs),
+                    FromUnixMicroseconds(endUs),
+                    isError != 0,
+                    recorded);
+            }
+            catch
+            {
+                // Throwing across the FFI boundary is UB, and a failing listener must not break requests.
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+using System.Net.Sockets;
+using System.Runtime.InteropServices;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using System.Text;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Owns a native BridgedSessionConfig, filled from the <see cref="Configuration"/>
+    /// of a cluster before a session is created.
+    /// The native config is consumed by <see cref="TakeNativeHandle"/>; otherwise it is freed on release.
+    /// </summary>
+    internal sealed class SessionConfig : SafeHandle
+    {
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern IntPtr session_config_new();
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_free(IntPtr config);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_add_contact_point(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string contactPoint);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_keyspace(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string keyspace, [MarshalAs(UnmanagedType.U1)] bool caseSensitive);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_connection_timeout(IntPtr config, ulong timeoutMs);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_request_timeout(IntPtr config, ulong timeoutMs);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_load_balancing(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string localDc, [MarshalAs(UnmanagedType.U1)] bool tokenAware);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException session_config_set_compression(IntPtr config, byte compression, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException session_config_set_pool_size(IntPtr config, nuint size, [MarshalAs(UnmanagedType.U1)] bool perShard, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_credentials(IntPtr config, [MarshalAs(UnmanagedType.LPUTF8Str)] string username, [MarshalAs(UnmanagedType.LPUTF8Str)] string password);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException session_config_set_tls(
+            IntPtr config,
+            FFIByteSlice caCertsPem,
+            FFIByteSlice clientCertPem,
+            FFIByteSlice clientKeyPem,
+            [MarshalAs(UnmanagedType.U1)] bool verifyHostname,
+            [MarshalAs(UnmanagedType.LPUTF8Str)] string serverName,
+            IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_include_statement_in_spans(IntPtr config, [MarshalAs(UnmanagedType.U1)] bool includeStatement);
+
+        /// <summary>
+        /// Note: This method transfers ownership of the provider handle inside callbacks to native code.
+        /// </summary>
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_config_set_authenticator(IntPtr config, AuthProviderBridge.Callbacks callbacks);
+
+        private SessionConfig() : base(IntPtr.Zero, true)
+        {
+            handle = session_config_new();
+        }
+
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        protected override bool ReleaseHandle()
+        {
+            session_config_free(handle);
+            return true;
+        }
+
+        /// <summary>
+        /// Builds the native config from the cluster configuration.
+        /// Options the Rust driver cannot express are ignored and keep the Rust driver defaults.
+        /// </summary>
+        internal static SessionConfig FromConfiguration(Configuration configuration, IEnumerable<string> contactPoints, string keyspace)
+        {
+            var config = new SessionConfig();
+            try
+            {
+                config.Fill(configuration, contactPoints, keyspace);
+                return config;
+            }
+            catch
+            {
+                config.Dispose();
+                throw;
+            }
+        }
+
+        /// <summary>
+        /// Transfers ownership of the native config to the caller, e.g. to session_create_with_config.
+        /// The handle must not be used afterwards.
+        /// </summary>
+        internal IntPtr TakeNativeHandle()
+        {
+            IntPtr ptr = handle;
+            SetHandleAsInvalid();
+            return ptr;
+        }
+
+        private void Fill(Configuration configuration, IEnumerable<string> contactPoints, string keyspace)
+        {
+            int port = configuration.ProtocolOptions.Port;
+            foreach (string contactPoint in contactPoints)
+            {
+                session_config_add_contact_point(handle, WithPort(contactPoint, port));
+            }
+
+            if (!string.IsNullOrEmpty(keyspace))
+            {
+                // Keyspace names passed to Connect are case-sensitive.
+                session_config_set_keyspace(handle, keyspace, true);
+            }
+
+            session_config_set_connection_timeout(handle, (ulong)configuration.SocketOptions.ConnectTimeoutMillis);
+            // QueryAbortTimeout is the only client-wide limit on the duration of a whole request.
+            // Per-host read timeouts have no counterpart in the Rust driver.
+            session_config_set_request_timeout(handle, (ulong)Math.Max(0, configuration.ClientOptions.QueryAbortTimeout));
+
+            SetLoadBalancing(configuration.Policies.LoadBalancingPolicy);
+
+            byte compression = configuration.ProtocolOptions.Compression switch
+            {
+                CompressionType.NoCompression => 0,
+                CompressionType.Snappy => 1,
+                CompressionType.LZ4 => 2,
+                _ => throw new NotSupportedException($"Compression {configuration.ProtocolOptions.Compression} is not supported"),
+            };
+            unsafe
+            {
+                ThrowIfException(session_config_set_compression(handle, compression, (IntPtr)RustBridgeGlobals.ConstructorsPtr));
+            }
+
+            session_config_set_include_statement_in_spans(handle, configuration.IncludeStatementInTraces);
+
+            SetAuthProvider(configuration.AuthProvider);
+
+            SetTls(configuration.ProtocolOptions.SslOptions, contactPoints);
+
+            // Pooling options are null unless set explicitly through the Builder.
+            PoolingOptions pooling = configuration.PoolingOptions;
+            if (pooling != null)
+            {
+                int coreConnections = pooling.GetCoreConnectionsPerHost(HostDistance.Local);
+                if (coreConnections > 0)
+                {
+                    // Shard-aware pools open the core connections to every shard of a host.
+                    unsafe
+                    {
+                        ThrowIfException(session_config_set_pool_size(handle, (nuint)coreConnections, !pooling.GetDisableShardAwareness(), (IntPtr)RustBridgeGlobals.ConstructorsPtr));
+                    }
+                }
+            }
+        }
+
+        // Throws the exception returned by a setter that validates its arguments.
+        /// <summary>
+        /// Appends the configured port to a contact point without an explicit one.
+        /// Only <c>host:port</c> and <c>[v6]:port</c> carry a port; bare IPv6 addresses are bracketed.
+        /// </summary>
+        internal static string WithPort(string contactPoint, int port)
+        {
+            if (contactPoint.StartsWith('['))
+            {
+                return contactPoint.Contains("]:") ? contactPoint : $"{contactPoint}:{port}";
+            }
+            if (IPAddress.TryParse(contactPoint, out IPAddress address) && address.AddressFamily == AddressFamily.InterNetworkV6)
+            {
+                return $"[{contactPoint}]:{port}";
+            }
+            return contactPoint.Contains(':') ? contactPoint : $"{contactPoint}:{port}";
+        }
+
+        private static void ThrowIfException(RustBridge.FfiException res)
+        {
+            try
+            {
+                RustBridge.ThrowIfException(ref res);
+            }
+            finally
+            {
+                RustBridge.FreeExceptionHandle(ref res);
+            }
+        }
+
+        private void SetAuthProvider(IAuthProvider authProvider)
+        {
+            switch (authProvider)
+            {
+                case null:
+                case NoneAuthProvider:
+                    return;
+                case PlainTextAuthProvider p:
+                    // Handled natively by the Rust driver, without calling back into C#.
+                    session_config_set_credentials(handle, p.Username, p.Password);
+                    return;
+                default:
+                    session_config_set_authenticator(handle, AuthProviderBridge.CreateCallbacks(authProvider));
+                    return;
+            }
+        }
+
+        // Maps the SSL options onto the TLS config of the Rust driver.
+        // Server certificates are verified against the trusted root certificates of the system,
+        // as SslStream does by default. A custom RemoteCertValidationCallback cannot be invoked
+        // by the Rust driver, so with one set only the certificate chain is verified.
+        // The first contact point given as a host name is sent as SNI and server certificates are
+        // verified against it; without one they are verified against the addresses of the nodes.
+        // SslProtocol and CheckCertificateRevocation have no counterpart in the Rust driver.
+        private void SetTls(SSLOptions sslOptions, IEnumerable<string> contactPoints)
+        {
+            if (sslOptions == null)
+            {
+                return;
+            }
+
+            byte[] caCertsPem = Encoding.ASCII.GetBytes(ExportTrustedRootsPem());
+            (byte[] clientCertPem, byte[] clientKeyPem) = ExportClientIdentityPem(sslOptions.CertificateCollection);
+            string serverName = contactPoints
+                .Select(contactPoint => contactPoint.Count(c => c == ':') == 1 ? contactPoint.Substring(0, contactPoint.IndexOf(':')) : contactPoint)
+                .FirstOrDefault(host => Uri.CheckHostName(host) == UriHostNameType.Dns);
+
+            unsafe
+            {
+                fixed (byte* caPtr = caCertsPem, certPtr = clientCertPem, keyPtr = clientKeyPem)
+                {
+                    ThrowIfException(session_config_set_tls(
+                        handle,
+                        new FFIByteSlice((IntPtr)caPtr, (nuint)caCertsPem.Length),
+                        new FFIByteSlice((IntPtr)certPtr, (nuint)clientCertPem.Length),
+                        new FFIByteSlice((IntPtr)keyPtr, (nuint)clientKeyPem.Length),
+                        sslOptions.UsesDefaultCertValidation,
+                        serverName,
+                        (IntPtr)RustBridgeGlobals.ConstructorsPtr));
+                }
+            }
+        }
+
+        private static string ExportTrustedRootsPem()
+        {
+            var pem = new StringBuilder();
+            foreach (StoreLocation location in new[] { StoreLocation.CurrentUser, StoreLocation.LocalMachine })
+            {
+                try
+                {
+                    using var store = new X509Store(StoreName.Root, location);
+                    store.Open(OpenFlags.ReadOnly | OpenFlags.OpenExistingOnly);
+                    foreach (X509Certificate2 certificate in store.Certificates)
+                    {
+                        pem.AppendLine(certificate.ExportCertificatePem());
+                    }
+                }
+                catch (CryptographicException)
+                {
+                    // The store does not exist on this platform.
+                }
+            }
+            return pem.ToString();
+        }
+
+        // Uses the first client certificate with a private key, as the Rust driver supports a single one.
+        private static (byte[] certPem, byte[] keyPem) ExportClientIdentityPem(X509CertificateCollection certificates)
+        {
+            foreach (X509Certificate certificate in certificates ?? new X509CertificateCollection())
+            {
+                if (certificate is not X509Certificate2 { HasPrivateKey: true } cert)
+                {
+                    continue;
+                }
+                using AsymmetricAlgorithm key = (AsymmetricAlgorithm)cert.GetRSAPrivateKey() ?? cert.GetECDsaPrivateKey();
+                if (key != null)
+                {
+                    return (Encoding.ASCII.GetBytes(cert.ExportCertificatePem()), Encoding.ASCII.GetBytes(key.ExportPkcs8PrivateKeyPem()));
+                }
+            }
+            return (Array.Empty<byte>(), Array.Empty<byte>());
+        }
+
+        // Maps the built-in load balancing policies onto the default Rust policy.
+        // Custom policies cannot be bridged, so they keep the Rust driver defaults.
+        private void SetLoadBalancing(ILoadBalancingPolicy policy)
+        {
+            bool tokenAware = false;
+            while (true)
+            {
+                switch (policy)
+                {
+                    case DefaultLoadBalancingPolicy p:
+                        policy = p.ChildPolicy;
+                        continue;
+                    case TokenAwarePolicy p:
+                        tokenAware = true;
+                        policy = p.ChildPolicy;
+                        continue;
+                    case DCAwareRoundRobinPolicy p:
+                        session_config_set_load_balancing(handle, p.LocalDc, tokenAware);
+                        return;
+                    case RoundRobinPolicy:
+                        session_config_set_load_balancing(handle, null, tokenAware);
+                        return;
+                    default:
+                        return;
+                }
+            }
+        }
+    }
+}
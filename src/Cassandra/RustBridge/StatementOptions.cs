@@ -0,0 +1,52 @@
+using System;
+using System.Runtime.InteropServices;
+using Cassandra.Data.Linq;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Per-statement execution options passed by value to the Rust execute functions.
+    /// Mirrors BridgedStatementOptions on the Rust side - field order must match exactly.
+    /// Unset options are encoded so that Rust keeps the defaults of the execution profile.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal readonly struct StatementOptions
+    {
+        internal readonly short consistency;
+        internal readonly short serial_consistency;
+        internal readonly int page_size;
+        internal readonly long timestamp;
+        internal readonly int request_timeout_ms;
+        // Bools are passed as bytes to keep the struct blittable.
+        internal readonly byte has_timestamp;
+        internal readonly byte is_idempotent;
+        internal readonly byte is_tracing;
+
+        private StatementOptions(IStatement statement, QueryOptions queryOptions)
+        {
+            consistency = statement.ConsistencyLevel.HasValue ? (short)statement.ConsistencyLevel.Value : (short)-1;
+            // ConsistencyLevel.Any is the C# driver's marker for "serial consistency not set".
+            serial_consistency = statement.SerialConsistencyLevel == ConsistencyLevel.Any
+                ? (short)-1
+                : (short)statement.SerialConsistencyLevel;
+            page_size = statement.PageSize;
+            has_timestamp = statement.Timestamp.HasValue ? (byte)1 : (byte)0;
+            timestamp = statement.Timestamp.HasValue
+                ? (statement.Timestamp.Value - CqlQueryTools.UnixStart).Ticks / 10
+                : 0;
+            // ReadTimeoutMillis limits the wait for a single host, which has no counterpart in the
+            // Rust driver - only the timeout of the whole request is passed.
+            request_timeout_ms = statement is Statement s ? Math.Max(0, s.RequestTimeoutMillis) : 0;
+            is_idempotent = (statement.IsIdempotent ?? queryOptions.GetDefaultIdempotence()) ? (byte)1 : (byte)0;
+            is_tracing = statement.IsTracing ? (byte)1 : (byte)0;
+        }
+
+        /// <summary>
+        /// Options of the statement, with unset idempotence taken from <paramref name="queryOptions"/>.
+        /// </summary>
+        internal static StatementOptions FromStatement(IStatement statement, QueryOptions queryOptions)
+        {
+            return new StatementOptions(statement, queryOptions);
+        }
+    }
+}
@@ -74,6 +74,14 @@ namespace Cassandra
             get { return _certificateCollection; }
         }
 
+        /// <summary>
+        /// Whether certificates are validated by the default callback, which rejects any policy error.
+        /// </summary>
+        internal bool UsesDefaultCertValidation
+        {
+            get { return _remoteCertValidationCallback == ValidateServerCertificate; }
+        }
+
         /// <summary>
         ///  Creates SSLOptions with default values.   
         /// </summary>
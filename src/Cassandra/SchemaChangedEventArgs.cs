@@ -30,5 +30,10 @@ namespace Cassandra
         public string Keyspace;
         public string Table;
         public Kind What;
+
+        /// <summary>
+        /// Name of the user defined type, if a type was changed.
+        /// </summary>
+        public string Type;
     }
 }
\ No newline at end of file
@@ -16,6 +16,7 @@
 
 using System;
 using System.Collections.Generic;
+using System.Diagnostics;
 using System.Linq;
 using System.Net;
 using System.Runtime.InteropServices;
@@ -40,11 +41,15 @@ namespace Cassandra
             return true;
         }
 
+        /// <summary>
+        /// Creates a session from the given config.
+        /// Note: This method transfers ownership of the config to native code, it must not be freed afterwards.
+        /// </summary>
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_create(Tcb tcb, [MarshalAs(UnmanagedType.LPUTF8Str)] string uri);
+        unsafe private static extern BridgedTaskHandle session_create_with_config(Tcb tcb, IntPtr config);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_shutdown(Tcb tcb, IntPtr session);
+        unsafe private static extern BridgedTaskHandle session_shutdown(Tcb tcb, IntPtr session);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern void empty_bridged_result_free(IntPtr phantomResult);
@@ -53,7 +58,7 @@ namespace Cassandra
         unsafe private static extern void session_free(IntPtr session);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_query(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement);
+        unsafe private static extern BridgedTaskHandle session_query(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
 
         /// <summary>
         /// Executes a query with already-serialized values.
@@ -63,16 +68,74 @@ namespace Cassandra
         /// and to free the memory.
         /// </summary>
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_query_with_values(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr valuesPtr);
+        unsafe private static extern BridgedTaskHandle session_query_with_values(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr valuesPtr, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_prepare(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_query_bound(Tcb tcb, IntPtr session, IntPtr preparedStatement, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        /// <summary>
+        /// Executes a prepared statement with already-serialized values.
+        /// 
+        /// Note: This method transfers ownership of valuesPtr to native code, thus invalidating the SerializedValues instance after use.
+        /// </summary>
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_query_bound_with_values(Tcb tcb, IntPtr session, IntPtr preparedStatement, IntPtr valuesPtr, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        /// <summary>
+        /// Executes a single page of a query, starting from the given paging state.
+        /// Note: This method transfers ownership of valuesPtr (which may be null) to native code.
+        /// </summary>
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_query_single_page(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr valuesPtr, FFIByteSlice pagingState, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        /// <summary>
+        /// Executes a single page of a prepared statement, starting from the given paging state.
+        /// Note: This method transfers ownership of valuesPtr (which may be null) to native code.
+        /// </summary>
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_query_bound_single_page(Tcb tcb, IntPtr session, IntPtr preparedStatement, IntPtr valuesPtr, FFIByteSlice pagingState, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException session_get_cluster_state(IntPtr session, out IntPtr state, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException session_subscribe_cluster_events(IntPtr session, ClusterEventBridge.Callbacks callbacks, out IntPtr subscription, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern BridgedTaskHandle session_get_tracing_info(Tcb tcb, IntPtr session, byte* tracingId);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern RustBridge.FfiException batch_new(byte batchType, out IntPtr batch, IntPtr constructorsPtr);
+
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void batch_free(IntPtr batch);
+
+        /// <summary>
+        /// Adds an unprepared statement to the batch.
+        /// Note: This method transfers ownership of valuesPtr (which may be null) to native code.
+        /// </summary>
+        [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void batch_add_statement(IntPtr batch, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr valuesPtr);
 
+        /// <summary>
+        /// Adds a prepared statement to the batch.
+        /// Note: This method transfers ownership of valuesPtr (which may be null) to native code.
+        /// </summary>
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_prepare(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement);
+        unsafe private static extern void batch_add_prepared(IntPtr batch, IntPtr preparedStatement, IntPtr valuesPtr);
 
+        /// <summary>
+        /// Executes a batch.
+        /// Note: This method transfers ownership of the batch to native code, it must not be freed afterwards.
+        /// </summary>
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_query_bound(Tcb tcb, IntPtr session, IntPtr preparedStatement);
+        unsafe private static extern BridgedTaskHandle session_batch(Tcb tcb, IntPtr session, IntPtr batch, StatementOptions options, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
 
         [DllImport("csharp_wrapper", CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern void session_use_keyspace(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string keyspace, [MarshalAs(UnmanagedType.U1)] bool isCaseSensitive);
+        unsafe private static extern BridgedTaskHandle session_use_keyspace(Tcb tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string keyspace, [MarshalAs(UnmanagedType.U1)] bool isCaseSensitive, [MarshalAs(UnmanagedType.LPUTF8Str)] string traceParent);
 
         private static readonly Logger Logger = new Logger(typeof(Session));
         private readonly ICluster _cluster;
@@ -124,9 +187,17 @@ namespace Cassandra
 
         static internal async Task<ISession> CreateAsync(
             ICluster cluster,
-            string contactPointUris,
+            IEnumerable<string> contactPoints,
             string keyspace)
         {
+            // Throws before anything is passed to native code if the configuration is invalid.
+            SessionConfig config = SessionConfig.FromConfiguration(cluster.Configuration, contactPoints, keyspace);
+
+            // The native runtime is started by the first session, so logging must be initialized before.
+            RustLogging.EnsureInitialized();
+            RustSpans.EnsureInitialized();
+            DriverRuntime.EnsureShutdownOnExit();
+
             /*
              * TaskCompletionSource is a way to programatically control a Task.
              * We create one here and pass it to Rust code, which will complete it.
@@ -143,36 +214,12 @@ namespace Cassandra
             // in a way that Rust can call it.
             // So we pass a pointer to the method and Rust code will call it via that pointer.
             // This is a common pattern to call C# code from native code ("reversed P/Invoke").
-            session_create(tcb, contactPointUris);
-
-            IntPtr sessionPtr = await tcs.Task.ConfigureAwait(false);
-            var session = new Session(cluster, keyspace, sessionPtr);
+            // If a keyspace was specified, the Rust driver switches to it before completing the task,
+            // failing with InvalidKeyspaceException if it does not exist.
+            BridgedTaskHandle taskHandle = session_create_with_config(tcb, config.TakeNativeHandle());
 
-            // If a keyspace was specified, validate it exists by executing USE statement
-            // This should throw InvalidQueryException if keyspace doesn't exist.
-            if (!string.IsNullOrEmpty(keyspace))
-            {
-                try
-                {
-                    await session.ExecuteAsync(new SimpleStatement(CqlQueryTools.GetUseKeyspaceCql(keyspace)));
-                }
-                // TO DO: Catch more specific exception from Rust driver when keyspace does not exist.
-                catch (Exception)
-                {
-                    // If validation fails, instantly dispose the session to avoid connection pool errors.
-                    try
-                    {
-                        session.Dispose();
-                    }
-                    catch (Exception ex)
-                    {
-                        Session.Logger.Error($"Failed to dispose session during keyspace validation cleanup: {ex}");
-                    }
-                    throw;
-                }
-            }
-
-            return session;
+            IntPtr sessionPtr = await taskHandle.WaitAsync(tcs.Task, CancellationToken.None).ConfigureAwait(false);
+            return new Session(cluster, keyspace, sessionPtr);
         }
 
         /// <inheritdoc />
@@ -264,8 +311,8 @@ namespace Cassandra
             // waits for all ongoing queries to complete, and blocks future queries.
             TaskCompletionSource<IntPtr> tcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
             Tcb tcb = Tcb.WithTcs(tcs);
-            session_shutdown(tcb, handle);
-            IntPtr emptyBridgedResult = await tcs.Task.ConfigureAwait(false);
+            BridgedTaskHandle taskHandle = session_shutdown(tcb, handle);
+            IntPtr emptyBridgedResult = await taskHandle.WaitAsync(tcs.Task, CancellationToken.None).ConfigureAwait(false);
 
             // Free the empty bridged result returned by shutdown
             empty_bridged_result_free(emptyBridgedResult);
@@ -339,6 +386,18 @@ namespace Cassandra
 
         /// <inheritdoc />
         public Task<RowSet> ExecuteAsync(IStatement statement, string executionProfileName)
+        {
+            return ExecuteAsync(statement, executionProfileName, CancellationToken.None);
+        }
+
+        /// <inheritdoc />
+        public Task<RowSet> ExecuteAsync(IStatement statement, CancellationToken cancellationToken)
+        {
+            return ExecuteAsync(statement, Configuration.DefaultExecutionProfileName, cancellationToken);
+        }
+
+        /// <inheritdoc />
+        public Task<RowSet> ExecuteAsync(IStatement statement, string executionProfileName, CancellationToken cancellationToken)
         {
             bool refAdded = false;
             try 
@@ -365,8 +424,13 @@ namespace Cassandra
 
                         TaskCompletionSource<IntPtr> tcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
                         Tcb tcb = Tcb.WithTcs(tcs);
+                        BridgedTaskHandle taskHandle;
 
-                    if (queryValues.Length == 0)
+                    if (IsManuallyPaged(s) && !isUseStatement)
+                    {
+                        taskHandle = QuerySinglePage(tcb, handle, queryString, SerializeOptionalValues(queryValues), s.PagingState, StatementOptions.FromStatement(s, Configuration.QueryOptions));
+                    }
+                    else if (queryValues.Length == 0)
                     {
                         // Use session_use_keyspace for USE statements and session_query for other statements.
                         // TODO: perform whole logic related to USE statements on the Rust side.
@@ -374,42 +438,47 @@ namespace Cassandra
                         {
                             // For USE statements, call the dedicated use_keyspace method
                             // case_sensitive = true to respect the exact casing provided.
-                            session_use_keyspace(tcb, handle, newKeyspace, true);
+                            taskHandle = session_use_keyspace(tcb, handle, newKeyspace, true, CurrentTraceParent());
                         }
                         else
                         {
-                            session_query(tcb, handle, queryString);
+                            taskHandle = session_query(tcb, handle, queryString, StatementOptions.FromStatement(s, Configuration.QueryOptions), CurrentTraceParent());
                         }
                     }
                     else
                     {
                         //TODO: abstract value serialization and the Rust-native function out of here
-                        session_query_with_values(
+                        taskHandle = session_query_with_values(
                             tcb,
                             handle,
                             queryString,
-                            SerializationHandler.InitializeSerializedValues(queryValues).TakeNativeHandle()
+                            SerializationHandler.InitializeSerializedValues(queryValues).TakeNativeHandle(),
+                            StatementOptions.FromStatement(s, Configuration.QueryOptions),
+                            CurrentTraceParent()
                         );
                     }
 
-                        return tcs.Task.ContinueWith(t =>
+                        // GetResult() rethrows the exception of the request, e.g. OperationCanceledException,
+                        // instead of wrapping it in an AggregateException.
+                        return taskHandle.WaitAsync(tcs.Task, cancellationToken).ContinueWith(t =>
                         {
-                            IntPtr rowSetPtr = t.Result;
-                            var rowSet = new RowSet(rowSetPtr);
-
                             // TODO: Fix this logic once we have proper USE statement handling in the driver. Make sure no race conditions occur when updating the keyspace
                             if (isUseStatement)
                             {
+                                // USE statements resolve to an empty result instead of a RowSet.
+                                empty_bridged_result_free(t.GetAwaiter().GetResult());
                                 _keyspace = newKeyspace;
+                                return new RowSet();
                             }
 
-                            return rowSet;
+                            IntPtr rowSetPtr = t.GetAwaiter().GetResult();
+                            return CreateRowSet(rowSetPtr);
                         }, TaskContinuationOptions.ExecuteSynchronously);
 
                     case BoundStatement bs:
-                        // Only support bound statements without values for now.
                         TaskCompletionSource<IntPtr> boundTcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
                         Tcb boundTcb = Tcb.WithTcs(boundTcs);
+                        BridgedTaskHandle boundTaskHandle;
 
                         // The managed PreparedStatement object (and the BoundStatement that
                         // references it) is rooted here by the local variable `bs`. Because there's an
@@ -419,23 +488,46 @@ namespace Cassandra
                         IntPtr queryPrepared = bs.PreparedStatement.DangerousGetHandle();
                         object[] queryValuesBound = bs.QueryValues ?? [];
 
-                        if (queryValuesBound.Length == 0)
+                        if (IsManuallyPaged(bs))
+                        {
+                            boundTaskHandle = QueryBoundSinglePage(boundTcb, handle, queryPrepared, SerializeOptionalValues(queryValuesBound), bs.PagingState, StatementOptions.FromStatement(bs, Configuration.QueryOptions));
+                        }
+                        else if (queryValuesBound.Length == 0)
                         {
-                            session_query_bound(boundTcb, handle, queryPrepared);
+                            boundTaskHandle = session_query_bound(boundTcb, handle, queryPrepared, StatementOptions.FromStatement(bs, Configuration.QueryOptions), CurrentTraceParent());
                         }
                         else
                         {
-                            throw new NotImplementedException("Bound statements with values are not yet supported");
+                            boundTaskHandle = session_query_bound_with_values(
+                                boundTcb,
+                                handle,
+                                queryPrepared,
+                                SerializationHandler.InitializeSerializedValues(queryValuesBound).TakeNativeHandle(),
+                                StatementOptions.FromStatement(bs, Configuration.QueryOptions),
+                                CurrentTraceParent()
+                            );
                         }
 
-                        return boundTcs.Task.ContinueWith(t =>
+                        return boundTaskHandle.WaitAsync(boundTcs.Task, cancellationToken).ContinueWith(t =>
                         {
-                            IntPtr rowSetPtr = t.Result;
-                            return new RowSet(rowSetPtr);
+                            IntPtr rowSetPtr = t.GetAwaiter().GetResult();
+                            return CreateRowSet(rowSetPtr);
                         }, TaskContinuationOptions.ExecuteSynchronously);
 
                     case BatchStatement s:
-                        throw new NotImplementedException("Batches are not yet supported");
+                        IntPtr batchPtr = BuildNativeBatch(s);
+
+                        TaskCompletionSource<IntPtr> batchTcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
+                        Tcb batchTcb = Tcb.WithTcs(batchTcs);
+
+                        // The batch is consumed by session_batch, so it must not be freed here.
+                        BridgedTaskHandle batchTaskHandle = session_batch(batchTcb, handle, batchPtr, StatementOptions.FromStatement(s, Configuration.QueryOptions), CurrentTraceParent());
+
+                        return batchTaskHandle.WaitAsync(batchTcs.Task, cancellationToken).ContinueWith(t =>
+                        {
+                            IntPtr rowSetPtr = t.GetAwaiter().GetResult();
+                            return CreateRowSet(rowSetPtr);
+                        }, TaskContinuationOptions.ExecuteSynchronously);
 
                     default:
                         throw new ArgumentException("Unsupported statement type");
@@ -533,9 +625,9 @@ namespace Cassandra
                 TaskCompletionSource<IntPtr> tcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
                 Tcb tcb = Tcb.WithTcs(tcs);
 
-                session_prepare(tcb, handle, cqlQuery);
+                BridgedTaskHandle taskHandle = session_prepare(tcb, handle, cqlQuery, CurrentTraceParent());
 
-                return tcs.Task.ContinueWith(t =>
+                return taskHandle.WaitAsync(tcs.Task, CancellationToken.None).ContinueWith(t =>
                 {
                     IntPtr preparedStatementPtr = t.Result;
                     // FIXME: Bridge with Rust to get variables metadata.
@@ -553,6 +645,125 @@ namespace Cassandra
             }
         }
 
+        /// <summary>
+        /// Wraps the result of a request, attaching its query trace if tracing was enabled for it.
+        /// </summary>
+        private RowSet CreateRowSet(IntPtr rowSetPtr)
+        {
+            var rs = new RowSet(rowSetPtr);
+            if (rs.Info.TracingId is Guid tracingId)
+            {
+                rs.Info.SetQueryTrace(new QueryTrace(tracingId, this));
+            }
+            return rs;
+        }
+
+        /// <summary>
+        /// Takes a snapshot of the cluster state, as currently known by the session.
+        /// Throws <see cref="AlreadyShutdownException"/> if the session is shut down.
+        /// </summary>
+        internal ClusterState GetClusterState()
+        {
+            bool refAdded = false;
+            try
+            {
+                DangerousAddRef(ref refAdded);
+                unsafe
+                {
+                    var res = session_get_cluster_state(handle, out IntPtr statePtr, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                    try
+                    {
+                        RustBridge.ThrowIfException(ref res);
+                    }
+                    finally
+                    {
+                        RustBridge.FreeExceptionHandle(ref res);
+                    }
+                    return new ClusterState(statePtr);
+                }
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Subscribes the listener to the node and schema changes seen by the session,
+        /// until the returned subscription is disposed or the session is shut down.
+        /// Throws <see cref="AlreadyShutdownException"/> if the session is shut down.
+        /// </summary>
+        internal ClusterEventSubscription SubscribeClusterEvents(IClusterEventListener listener)
+        {
+            bool refAdded = false;
+            try
+            {
+                DangerousAddRef(ref refAdded);
+                unsafe
+                {
+                    // Rust owns the listener handle from now on, and frees it even if subscribing fails.
+                    var res = session_subscribe_cluster_events(
+                        handle,
+                        ClusterEventBridge.CreateCallbacks(listener),
+                        out IntPtr subscriptionPtr,
+                        (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                    try
+                    {
+                        RustBridge.ThrowIfException(ref res);
+                    }
+                    finally
+                    {
+                        RustBridge.FreeExceptionHandle(ref res);
+                    }
+                    return new ClusterEventSubscription(subscriptionPtr);
+                }
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Retrieves the trace of a request executed with tracing enabled.
+        /// The returned tracing info must be freed with <c>tracing_info_free</c>.
+        /// </summary>
+        internal Task<IntPtr> GetTracingInfoAsync(Guid tracingId)
+        {
+            bool refAdded = false;
+            try
+            {
+                // See ExecuteAsync - the ref is only needed for the duration of the P/Invoke.
+                DangerousAddRef(ref refAdded);
+
+                TaskCompletionSource<IntPtr> tcs = new(TaskCreationOptions.RunContinuationsAsynchronously);
+                Tcb tcb = Tcb.WithTcs(tcs);
+                BridgedTaskHandle taskHandle;
+                unsafe
+                {
+                    // The tracing id is passed in network byte order, as UUIDs are serialized.
+                    fixed (byte* tracingIdPtr = TypeSerializer.GuidShuffle(tracingId.ToByteArray()))
+                    {
+                        taskHandle = session_get_tracing_info(tcb, handle, tracingIdPtr);
+                    }
+                }
+                return taskHandle.WaitAsync(tcs.Task, CancellationToken.None);
+            }
+            finally
+            {
+                if (refAdded)
+                {
+                    DangerousRelease();
+                }
+            }
+        }
+
         public void WaitForSchemaAgreement(RowSet rs)
         {
             // Deprecated and implemented as no-op.
@@ -580,6 +791,94 @@ namespace Cassandra
             return profile;
         }
 
+        // Builds a native batch from the statements of the BatchStatement.
+        // The returned pointer is owned by the caller and must be passed to session_batch.
+        private static IntPtr BuildNativeBatch(BatchStatement batch)
+        {
+            IntPtr batchPtr;
+            unsafe
+            {
+                var res = batch_new((byte)batch.BatchType, out batchPtr, (IntPtr)RustBridgeGlobals.ConstructorsPtr);
+                try
+                {
+                    RustBridge.ThrowIfException(ref res);
+                }
+                finally
+                {
+                    RustBridge.FreeExceptionHandle(ref res);
+                }
+            }
+            try
+            {
+                foreach (Statement query in batch.Queries)
+                {
+                    object[] values = query.QueryValues ?? [];
+                    switch (query)
+                    {
+                        case BoundStatement bs:
+                            // The PreparedStatement is rooted by `batch` for the duration of this call,
+                            // and Rust clones the underlying Arc synchronously.
+                            batch_add_prepared(batchPtr, bs.PreparedStatement.DangerousGetHandle(), SerializeOptionalValues(values));
+                            break;
+                        case RegularStatement rs:
+                            batch_add_statement(batchPtr, rs.QueryString, SerializeOptionalValues(values));
+                            break;
+                        default:
+                            throw new ArgumentException("Unsupported statement type in batch");
+                    }
+                }
+                return batchPtr;
+            }
+            catch
+            {
+                batch_free(batchPtr);
+                throw;
+            }
+        }
+
+        // Serializes the values, returning a null pointer if there are none.
+        private static IntPtr SerializeOptionalValues(object[] values)
+        {
+            return values.Length == 0
+                ? IntPtr.Zero
+                : SerializationHandler.InitializeSerializedValues(values).TakeNativeHandle();
+        }
+
+        // W3C traceparent of the current Activity, so that the spans of the request
+        // on the Rust side become its children. Null if there is no W3C Activity.
+        private static string CurrentTraceParent()
+        {
+            Activity activity = Activity.Current;
+            return activity?.IdFormat == ActivityIdFormat.W3C ? activity.Id : null;
+        }
+
+        // Statements which disable automatic paging or resume from a paging state
+        // are executed one page at a time.
+        private static bool IsManuallyPaged(IStatement statement)
+        {
+            return !statement.AutoPage || statement.PagingState != null;
+        }
+
+        private static unsafe BridgedTaskHandle QuerySinglePage(Tcb tcb, IntPtr session, string query, IntPtr valuesPtr, byte[] pagingState, StatementOptions options)
+        {
+            // A null paging state is passed as a null pointer, which means the first page.
+            fixed (byte* pagingStatePtr = pagingState)
+            {
+                var pagingStateSlice = new FFIByteSlice((IntPtr)pagingStatePtr, (nuint)(pagingState?.Length ?? 0));
+                return session_query_single_page(tcb, session, query, valuesPtr, pagingStateSlice, options, CurrentTraceParent());
+            }
+        }
+
+        private static unsafe BridgedTaskHandle QueryBoundSinglePage(Tcb tcb, IntPtr session, IntPtr preparedStatement, IntPtr valuesPtr, byte[] pagingState, StatementOptions options)
+        {
+            // A null paging state is passed as a null pointer, which means the first page.
+            fixed (byte* pagingStatePtr = pagingState)
+            {
+                var pagingStateSlice = new FFIByteSlice((IntPtr)pagingStatePtr, (nuint)(pagingState?.Length ?? 0));
+                return session_query_bound_single_page(tcb, session, preparedStatement, valuesPtr, pagingStateSlice, options, CurrentTraceParent());
+            }
+        }
+
         // TODO: Remove this method once we have proper USE statement handling in the driver.
         // Checks if a query is a USE statement and extracts the keyspace name.
         // Returns true if the query is a USE statement, false otherwise.
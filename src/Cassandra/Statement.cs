@@ -62,6 +62,12 @@ namespace Cassandra
         /// <inheritdoc />
         public int ReadTimeoutMillis { get; private set; }
 
+        /// <summary>
+        /// Gets the client-side timeout of the whole request in milliseconds, including retries and
+        /// speculative executions. Zero when not set, in which case the session default is used.
+        /// </summary>
+        public int RequestTimeoutMillis { get; private set; }
+
         /// <inheritdoc />
         public IRetryPolicy RetryPolicy { get; private set; }
 
@@ -213,6 +219,20 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Overrides the client-side timeout of the whole request for this statement,
+        /// <see cref="ClientOptions.QueryAbortTimeout"/> by default.
+        /// Unlike <see cref="SetReadTimeoutMillis"/>, it limits the time spent on all hosts tried.
+        /// </summary>
+        /// <param name="timeout">
+        /// Timeout in milliseconds. If the value is not greater than zero, the session default is used.
+        /// </param>
+        public IStatement SetRequestTimeoutMillis(int timeout)
+        {
+            RequestTimeoutMillis = timeout;
+            return this;
+        }
+
         /// <inheritdoc />
         public IStatement SetConsistencyLevel(ConsistencyLevel? consistency)
         {